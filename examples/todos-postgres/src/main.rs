@@ -58,7 +58,7 @@ RETURNING id
 }
 
 async fn complete_todo(mut pool: &PgPool, id: i64) -> anyhow::Result<bool> {
-    let rows_affected = sqlx::query!(
+    let result = sqlx::query!(
         r#"
 UPDATE todos
 SET done = TRUE
@@ -69,7 +69,7 @@ WHERE id = $1
     .execute(&mut pool)
     .await?;
 
-    Ok(rows_affected > 0)
+    Ok(result.rows_affected() > 0)
 }
 
 async fn list_todos(pool: &mut PgPool) -> anyhow::Result<()> {
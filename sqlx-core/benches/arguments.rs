@@ -0,0 +1,141 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use sqlx_core::arguments::Arguments;
+use sqlx_core::database::Database;
+use sqlx_core::encode::Encode;
+use sqlx_core::mysql::MySql;
+use sqlx_core::postgres::Postgres;
+use sqlx_core::types::Type;
+
+// A wide insert; 500 rows of (INT, TEXT, BIGINT NULL, DOUBLE)
+const ROWS: usize = 500;
+
+// Counts the allocations (including reallocations) made while binding, and their bytes
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(new_size, Ordering::Relaxed);
+
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn rows() -> Vec<(i32, String, Option<i64>, f64)> {
+    (0..ROWS)
+        .map(|i| {
+            let bigint = if i % 3 == 0 { None } else { Some(i as i64) };
+
+            (i as i32, format!("name #{}", i), bigint, i as f64 * 0.5)
+        })
+        .collect()
+}
+
+fn bind_all<DB>(rows: &[(i32, String, Option<i64>, f64)], reserve: bool) -> DB::Arguments
+where
+    DB: Database,
+    i32: Type<DB> + Encode<DB>,
+    String: Type<DB> + Encode<DB>,
+    Option<i64>: Type<DB> + Encode<DB>,
+    f64: Type<DB> + Encode<DB>,
+{
+    let mut arguments = DB::Arguments::default();
+
+    if reserve {
+        let size = rows
+            .iter()
+            .map(|(a, b, c, d)| a.size_hint() + b.size_hint() + c.size_hint() + d.size_hint())
+            .sum();
+
+        arguments.reserve(rows.len() * 4, size);
+    }
+
+    for (a, b, c, d) in rows {
+        arguments.add(a);
+        arguments.add(b);
+        arguments.add(c);
+        arguments.add(d);
+    }
+
+    arguments
+}
+
+// Prints the number of allocations and bytes allocated for each bound value
+fn report_allocations<F, T>(name: &str, bind: F)
+where
+    F: FnOnce() -> T,
+{
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+
+    let arguments = bind();
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+    let bytes = BYTES.load(Ordering::Relaxed) - bytes;
+
+    drop(arguments);
+
+    let binds = (ROWS * 4) as f64;
+
+    println!(
+        "{}: {} allocations ({:.3} per bind), {} bytes ({:.1} per bind)",
+        name,
+        allocations,
+        allocations as f64 / binds,
+        bytes,
+        bytes as f64 / binds
+    );
+}
+
+fn bench_arguments(c: &mut Criterion) {
+    let rows = rows();
+
+    report_allocations("postgres: add", || bind_all::<Postgres>(&rows, false));
+    report_allocations("postgres: reserve + add", || {
+        bind_all::<Postgres>(&rows, true)
+    });
+    report_allocations("mysql: add", || bind_all::<MySql>(&rows, false));
+    report_allocations("mysql: reserve + add", || bind_all::<MySql>(&rows, true));
+
+    // `add` reserves room for each value from its `size_hint`; reserving the total
+    // up front (as `query!` does) avoids growing the buffer at all
+    c.bench_function("postgres: add", |b| {
+        b.iter(|| bind_all::<Postgres>(black_box(&rows), false))
+    });
+
+    c.bench_function("postgres: reserve + add", |b| {
+        b.iter(|| bind_all::<Postgres>(black_box(&rows), true))
+    });
+
+    c.bench_function("mysql: add", |b| {
+        b.iter(|| bind_all::<MySql>(black_box(&rows), false))
+    });
+
+    c.bench_function("mysql: reserve + add", |b| {
+        b.iter(|| bind_all::<MySql>(black_box(&rows), true))
+    });
+}
+
+criterion_group!(benches, bench_arguments);
+criterion_main!(benches);
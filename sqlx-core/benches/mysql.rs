@@ -0,0 +1,59 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use sqlx_core::decode::Decode;
+use sqlx_core::mysql::{MySql, MySqlValue};
+
+// A wide text result; 500 rows of 16 TEXT columns of 64 bytes each
+const ROWS: usize = 500;
+const COLUMNS: usize = 16;
+
+fn values() -> Vec<Vec<u8>> {
+    (0..ROWS * COLUMNS)
+        .map(|i| format!("{:064}", i).into_bytes())
+        .collect()
+}
+
+fn bench_decode(c: &mut Criterion) {
+    let values = values();
+
+    // `&str` borrows from the row buffer; `String` copies every value into a new allocation
+    c.bench_function("mysql: decode &str", |b| {
+        b.iter(|| {
+            for value in black_box(&values) {
+                let s = <&str as Decode<MySql>>::decode(Some(MySqlValue::Text(value))).unwrap();
+                black_box(s);
+            }
+        })
+    });
+
+    c.bench_function("mysql: decode String", |b| {
+        b.iter(|| {
+            for value in black_box(&values) {
+                let s = <String as Decode<MySql>>::decode(Some(MySqlValue::Text(value))).unwrap();
+                black_box(s);
+            }
+        })
+    });
+
+    c.bench_function("mysql: decode &[u8]", |b| {
+        b.iter(|| {
+            for value in black_box(&values) {
+                let s = <&[u8] as Decode<MySql>>::decode(Some(MySqlValue::Binary(value))).unwrap();
+                black_box(s);
+            }
+        })
+    });
+
+    c.bench_function("mysql: decode Vec<u8>", |b| {
+        b.iter(|| {
+            for value in black_box(&values) {
+                let s =
+                    <Vec<u8> as Decode<MySql>>::decode(Some(MySqlValue::Binary(value))).unwrap();
+                black_box(s);
+            }
+        })
+    });
+}
+
+criterion_group!(benches, bench_decode);
+criterion_main!(benches);
@@ -0,0 +1,44 @@
+use async_std::task::block_on;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use sqlx_core::connection::Connect;
+use sqlx_core::cursor::Cursor;
+use sqlx_core::sqlite::{Sqlite, SqliteConnection};
+
+const QUERY: &str = "SELECT ?1 + 1, ?1 * 2, 'value #' || ?1";
+
+fn connect(capacity: usize) -> SqliteConnection {
+    let mut conn = block_on(SqliteConnection::connect("sqlite::memory:")).unwrap();
+    conn.statement_cache_capacity(capacity);
+
+    conn
+}
+
+fn run(conn: &mut SqliteConnection, i: i32) {
+    block_on(async {
+        let mut cursor = sqlx_core::query::query::<Sqlite>(QUERY).bind(i).fetch(conn);
+
+        while let Some(row) = cursor.next().await.unwrap() {
+            black_box(row);
+        }
+    })
+}
+
+fn bench_statement_cache(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sqlite statement");
+
+    let mut cached = connect(100);
+    group.bench_function("cached", |b| {
+        b.iter(|| run(&mut cached, black_box(10)));
+    });
+
+    let mut uncached = connect(0);
+    group.bench_function("uncached", |b| {
+        b.iter(|| run(&mut uncached, black_box(10)));
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_statement_cache);
+criterion_main!(benches);
@@ -1,7 +1,7 @@
 //! Traits for passing arguments to SQL queries.
 
 use crate::database::Database;
-use crate::encode::Encode;
+use crate::encode::{DynEncode, Encode};
 use crate::types::Type;
 
 /// A tuple of arguments to be sent to the database.
@@ -17,4 +17,10 @@ pub trait Arguments: Send + Sized + Default + 'static {
     where
         T: Type<Self::Database>,
         T: Encode<Self::Database>;
+
+    /// Add a value whose type is only known at runtime to the end of the arguments.
+    ///
+    /// This encodes through dynamic dispatch; prefer [`add`](Arguments::add) when the type
+    /// of the value is known.
+    fn add_dyn(&mut self, value: &dyn DynEncode<Self::Database>);
 }
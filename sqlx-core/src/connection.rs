@@ -26,11 +26,82 @@ where
         Box::pin(Transaction::new(0, self))
     }
 
+    /// Runs `f` within a transaction, committing it if `f` returns `Ok` and rolling it back
+    /// if `f` returns `Err`.
+    ///
+    /// On success, returns the connection along with the value returned by `f`. On failure,
+    /// the connection is dropped after the rollback; a [PoolConnection] is returned to its pool.
+    /// If `f` panics, the transaction is rolled back when it is dropped.
+    ///
+    /// Calling this on a [`Transaction`] (or [`Transaction::transaction`] from within `f`)
+    /// uses a save point instead.
+    ///
+    /// ```rust,ignore
+    /// let (conn, id) = conn
+    ///     .transaction(|tx| Box::pin(async move {
+    ///         let (id,): (i32,) = sqlx::query_as("INSERT INTO users (name) VALUES ($1) RETURNING id")
+    ///             .bind("alice")
+    ///             .fetch_one(&mut *tx)
+    ///             .await?;
+    ///
+    ///         Ok::<_, sqlx::Error>(id)
+    ///     }))
+    ///     .await?;
+    /// ```
+    fn transaction<F, R, E>(self, f: F) -> BoxFuture<'static, Result<(Self, R), E>>
+    where
+        Self: Sized,
+        F: for<'t> FnOnce(&'t mut Transaction<Self>) -> BoxFuture<'t, Result<R, E>>
+            + Send
+            + 'static,
+        R: Send + 'static,
+        E: From<crate::Error> + Send + 'static,
+    {
+        Box::pin(async move {
+            let mut tx = self.begin().await?;
+
+            match f(&mut tx).await {
+                Ok(value) => Ok((tx.commit().await?, value)),
+
+                Err(err) => {
+                    // the error from `f` is more useful than one from the rollback
+                    let _ = tx.rollback().await;
+
+                    Err(err)
+                }
+            }
+        })
+    }
+
     /// Close this database connection.
     fn close(self) -> BoxFuture<'static, crate::Result<()>>;
 
     /// Verifies a connection to the database is still alive.
     fn ping(&mut self) -> BoxFuture<crate::Result<()>>;
+
+    /// Resets the session state of this connection, rolling back any open transaction and
+    /// clearing session variables, temporary tables and prepared statements.
+    ///
+    /// For Postgres this runs `DISCARD ALL` and for MySQL it sends `COM_RESET_CONNECTION`;
+    /// either way, the statements cached by the connection are dropped along with the
+    /// rest of the session. Connections without session state to reset do nothing.
+    fn reset(&mut self) -> BoxFuture<'_, crate::Result<()>> {
+        Box::pin(async { Ok(()) })
+    }
+
+    /// Resets the session state of this connection by executing `query`, in place of the
+    /// reset done by [`reset`](Connection::reset).
+    ///
+    /// Any transaction left open is rolled back before `query` is executed. For Postgres, the
+    /// prepared statements are deallocated afterwards and dropped from the statement cache, so
+    /// `query` may deallocate them itself, e.g. with `DISCARD ALL`.
+    fn reset_with<'c>(&'c mut self, query: &'c str) -> BoxFuture<'c, crate::Result<()>> {
+        Box::pin(async move {
+            self.execute(query).await?;
+
+            Ok(())
+        })
+    }
 }
 
 /// Represents a type that can directly establish a new connection.
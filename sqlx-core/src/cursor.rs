@@ -1,8 +1,13 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
 use futures_core::future::BoxFuture;
+use futures_core::stream::{BoxStream, Stream};
 
 use crate::database::{Database, HasRow};
 use crate::executor::Execute;
 use crate::pool::Pool;
+use crate::query::TryMapRow;
 
 /// Represents a result set, which is generated by executing a query against the database.
 ///
@@ -37,4 +42,48 @@ where
     fn next<'cur>(
         &'cur mut self,
     ) -> BoxFuture<'cur, crate::Result<Option<<Self::Database as HasRow<'cur>>::Row>>>;
+
+    /// Consume this cursor, returning a [`RowStream`] of the rows mapped by `mapper`.
+    ///
+    /// ```rust,ignore
+    /// let mut ids = conn
+    ///     .fetch("SELECT id FROM users")
+    ///     .into_stream(|row: PgRow| row.try_get::<i32, _>(0));
+    ///
+    /// while let Some(id) = ids.try_next().await? {
+    ///     // ...
+    /// }
+    /// ```
+    fn into_stream<F, O>(mut self, mut mapper: F) -> RowStream<'c, O>
+    where
+        Self: Sized + 'c,
+        F: TryMapRow<Self::Database, Output = O> + Send + 'c,
+        O: Send + Unpin + 'c,
+    {
+        RowStream {
+            inner: Box::pin(async_stream::try_stream! {
+                while let Some(row) = self.next().await? {
+                    let mapped = mapper.try_map_row(row)?;
+                    yield mapped;
+                }
+            }),
+        }
+    }
+}
+
+/// A [`Stream`] over the rows of a [`Cursor`]. Returned by [`Cursor::into_stream`].
+///
+/// A row borrows the cursor until the cursor is advanced so a cursor can not be a
+/// `Stream` of rows itself; instead, each row is mapped to an owned value before the
+/// next row is fetched.
+pub struct RowStream<'c, T> {
+    inner: BoxStream<'c, crate::Result<T>>,
+}
+
+impl<T> Stream for RowStream<'_, T> {
+    type Item = crate::Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
 }
@@ -1,5 +1,9 @@
 //! Types and traits for decoding values from the database.
 
+use std::num::{
+    NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8,
+};
+
 use crate::database::HasRawValue;
 
 /// Decode a single value from the database.
@@ -10,3 +14,84 @@ where
 {
     fn decode(value: DB::RawValue) -> crate::Result<Self>;
 }
+
+// A boxed string or byte slice is allocated at exactly its length; unlike `String` or
+// `Vec<u8>`, it keeps no spare capacity around for values that are held onto
+
+impl<'de, DB> Decode<'de, DB> for Box<str>
+where
+    DB: HasRawValue<'de>,
+    &'de str: Decode<'de, DB>,
+{
+    fn decode(value: DB::RawValue) -> crate::Result<Self> {
+        <&'de str as Decode<DB>>::decode(value).map(Box::from)
+    }
+}
+
+impl<'de, DB> Decode<'de, DB> for Box<[u8]>
+where
+    DB: HasRawValue<'de>,
+    Vec<u8>: Decode<'de, DB>,
+{
+    fn decode(value: DB::RawValue) -> crate::Result<Self> {
+        <Vec<u8> as Decode<DB>>::decode(value).map(Vec::into_boxed_slice)
+    }
+}
+
+// Non-zero integers decode from the integer they wrap; a zero is an error rather than a panic
+// as it can only come from a column that is not constrained to be non-zero
+
+macro_rules! impl_decode_for_non_zero {
+    ($($ty:ident($int:ty)),*) => {
+        $(
+            impl<'de, DB> Decode<'de, DB> for $ty
+            where
+                DB: HasRawValue<'de>,
+                $int: Decode<'de, DB>,
+            {
+                fn decode(value: DB::RawValue) -> crate::Result<Self> {
+                    $ty::new(<$int as Decode<DB>>::decode(value)?).ok_or_else(|| {
+                        crate::Error::Decode(
+                            concat!("zero cannot be decoded as ", stringify!($ty)).into(),
+                        )
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_decode_for_non_zero!(
+    NonZeroI8(i8),
+    NonZeroI16(i16),
+    NonZeroI32(i32),
+    NonZeroI64(i64),
+    NonZeroU8(u8),
+    NonZeroU16(u16),
+    NonZeroU32(u32),
+    NonZeroU64(u64)
+);
+
+// Decodes a `char` from a string that must hold exactly one character.
+#[allow(dead_code)]
+pub(crate) fn decode_char(s: &str) -> crate::Result<char> {
+    let mut chars = s.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+
+        _ => Err(crate::Error::Decode(
+            format!("expected a single character, got {:?}", s).into(),
+        )),
+    }
+}
+
+#[test]
+fn test_decode_char() {
+    assert_eq!(decode_char("a").unwrap(), 'a');
+    assert_eq!(decode_char("é").unwrap(), 'é');
+    assert_eq!(decode_char("漢").unwrap(), '漢');
+
+    assert!(decode_char("").is_err());
+    assert!(decode_char("ab").is_err());
+}
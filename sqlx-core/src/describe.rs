@@ -6,6 +6,14 @@ use crate::database::Database;
 
 /// The return type of [Executor::describe].
 #[non_exhaustive]
+#[cfg_attr(feature = "offline", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "offline",
+    serde(bound(
+        serialize = "DB::TypeInfo: serde::Serialize, Column<DB>: serde::Serialize",
+        deserialize = "DB::TypeInfo: serde::de::DeserializeOwned, Column<DB>: serde::de::DeserializeOwned"
+    ))
+)]
 pub struct Describe<DB>
 where
     DB: Database + ?Sized,
@@ -33,6 +41,14 @@ where
 
 /// A single column of a result set.
 #[non_exhaustive]
+#[cfg_attr(feature = "offline", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "offline",
+    serde(bound(
+        serialize = "DB::TableId: serde::Serialize, DB::TypeInfo: serde::Serialize",
+        deserialize = "DB::TableId: serde::de::DeserializeOwned, DB::TypeInfo: serde::de::DeserializeOwned"
+    ))
+)]
 pub struct Column<DB>
 where
     DB: Database + ?Sized,
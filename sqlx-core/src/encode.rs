@@ -3,6 +3,10 @@
 use crate::database::Database;
 use crate::types::Type;
 use std::mem;
+use std::num::{
+    NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8,
+};
+use std::sync::Arc;
 
 /// The return type of [Encode::encode].
 pub enum IsNull {
@@ -32,6 +36,24 @@ where
     fn size_hint(&self) -> usize {
         mem::size_of_val(self)
     }
+
+    /// Writes the value of `self` into `buf` in the text format of the database, for
+    /// parameters that are bound as text instead of in the format written by
+    /// [encode](Encode::encode).
+    ///
+    /// Returns `None`, having written nothing, if the type has no text encoding.
+    fn encode_text(&self, _buf: &mut DB::RawBuffer) -> Option<IsNull> {
+        None
+    }
+
+    /// Returns an error if `self` cannot be encoded, such as an integer that is out of range
+    /// for the type it is sent as.
+    ///
+    /// This is called when the value is bound, and [encode](Encode::encode) is not called if
+    /// it fails; the error is returned when the query is executed.
+    fn check_encode(&self) -> crate::Result<()> {
+        Ok(())
+    }
 }
 
 impl<T: ?Sized, DB> Encode<DB> for &'_ T
@@ -51,8 +73,52 @@ where
     fn size_hint(&self) -> usize {
         (*self).size_hint()
     }
+
+    fn encode_text(&self, buf: &mut DB::RawBuffer) -> Option<IsNull> {
+        (*self).encode_text(buf)
+    }
+
+    fn check_encode(&self) -> crate::Result<()> {
+        (*self).check_encode()
+    }
+}
+
+// Owned smart pointers encode exactly like the value they point to
+
+macro_rules! impl_encode_for_smart_pointer {
+    ($ptr:ident) => {
+        impl<T: ?Sized, DB> Encode<DB> for $ptr<T>
+        where
+            DB: Database,
+            T: Type<DB>,
+            T: Encode<DB>,
+        {
+            fn encode(&self, buf: &mut DB::RawBuffer) {
+                (**self).encode(buf)
+            }
+
+            fn encode_nullable(&self, buf: &mut DB::RawBuffer) -> IsNull {
+                (**self).encode_nullable(buf)
+            }
+
+            fn size_hint(&self) -> usize {
+                (**self).size_hint()
+            }
+
+            fn encode_text(&self, buf: &mut DB::RawBuffer) -> Option<IsNull> {
+                (**self).encode_text(buf)
+            }
+
+            fn check_encode(&self) -> crate::Result<()> {
+                (**self).check_encode()
+            }
+        }
+    };
 }
 
+impl_encode_for_smart_pointer!(Box);
+impl_encode_for_smart_pointer!(Arc);
+
 impl<T, DB> Encode<DB> for Option<T>
 where
     DB: Database,
@@ -66,9 +132,8 @@ where
 
     fn encode_nullable(&self, buf: &mut DB::RawBuffer) -> IsNull {
         if let Some(self_) = self {
-            self_.encode(buf);
-
-            IsNull::No
+            // Forward to the inner [encode_nullable] so `Some(None)` is also NULL
+            self_.encode_nullable(buf)
         } else {
             IsNull::Yes
         }
@@ -77,4 +142,104 @@ where
     fn size_hint(&self) -> usize {
         self.as_ref().map_or(0, Encode::size_hint)
     }
+
+    fn encode_text(&self, buf: &mut DB::RawBuffer) -> Option<IsNull> {
+        if let Some(self_) = self {
+            self_.encode_text(buf)
+        } else {
+            // NULL is sent the same way in either format
+            Some(IsNull::Yes)
+        }
+    }
+
+    fn check_encode(&self) -> crate::Result<()> {
+        self.as_ref().map_or(Ok(()), Encode::check_encode)
+    }
+}
+
+// Non-zero integers encode exactly like the integer they wrap
+
+macro_rules! impl_encode_for_non_zero {
+    ($($ty:ident($int:ty)),*) => {
+        $(
+            impl<DB> Encode<DB> for $ty
+            where
+                DB: Database,
+                $int: Type<DB>,
+                $int: Encode<DB>,
+            {
+                fn encode(&self, buf: &mut DB::RawBuffer) {
+                    self.get().encode(buf)
+                }
+
+                fn size_hint(&self) -> usize {
+                    self.get().size_hint()
+                }
+
+                fn encode_text(&self, buf: &mut DB::RawBuffer) -> Option<IsNull> {
+                    self.get().encode_text(buf)
+                }
+
+                fn check_encode(&self) -> crate::Result<()> {
+                    self.get().check_encode()
+                }
+            }
+        )*
+    };
+}
+
+impl_encode_for_non_zero!(
+    NonZeroI8(i8),
+    NonZeroI16(i16),
+    NonZeroI32(i32),
+    NonZeroI64(i64),
+    NonZeroU8(u8),
+    NonZeroU16(u16),
+    NonZeroU32(u32),
+    NonZeroU64(u64)
+);
+
+/// An object-safe counterpart of [`Encode`] and [`Type`], for binding values whose types are
+/// only known at runtime, e.g., from a `Vec<Box<dyn DynEncode<DB>>>`.
+///
+/// This is implemented for every type that implements both [`Encode`] and [`Type`]; see
+/// [`Arguments::add_dyn`](crate::arguments::Arguments::add_dyn).
+pub trait DynEncode<DB>
+where
+    DB: Database,
+{
+    /// Returns the type information of the value; see [`Type::type_info`].
+    fn type_info_dyn(&self) -> DB::TypeInfo;
+
+    /// Writes the value into `buf`; see [`Encode::encode_nullable`].
+    fn encode_dyn(&self, buf: &mut DB::RawBuffer) -> IsNull;
+
+    /// See [`Encode::size_hint`].
+    fn size_hint_dyn(&self) -> usize;
+
+    /// See [`Encode::check_encode`].
+    fn check_encode_dyn(&self) -> crate::Result<()>;
+}
+
+impl<T: ?Sized, DB> DynEncode<DB> for T
+where
+    DB: Database,
+    T: Type<DB>,
+    T: Encode<DB>,
+{
+    fn type_info_dyn(&self) -> DB::TypeInfo {
+        <T as Type<DB>>::type_info()
+    }
+
+    fn encode_dyn(&self, buf: &mut DB::RawBuffer) -> IsNull {
+        self.encode_nullable(buf)
+    }
+
+    fn size_hint_dyn(&self) -> usize {
+        self.size_hint()
+    }
+
+    fn check_encode_dyn(&self) -> crate::Result<()> {
+        self.check_encode()
+    }
 }
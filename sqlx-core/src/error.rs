@@ -20,6 +20,13 @@ pub enum Error {
     /// An error was returned by the database.
     Database(Box<dyn DatabaseError + Send + Sync>),
 
+    /// A query gave up waiting for a lock held by another transaction because the lock
+    /// timeout of the session expired (SQLSTATE `55P03` in Postgres).
+    ///
+    /// This is otherwise a [database error](Error::Database); see `lock_timeout` in the
+    /// documentation of [`PgConnection`](crate::postgres::PgConnection).
+    LockTimeout(Box<dyn DatabaseError + Send + Sync>),
+
     /// No row was returned during [`Map::fetch_one`] or [`QueryAs::fetch_one`].
     RowNotFound,
 
@@ -29,6 +36,35 @@ pub enum Error {
     /// Column index was out of bounds (e.g., asking for column 4 in a 2-column row).
     ColumnIndexOutOfBounds { index: usize, len: usize },
 
+    /// A row had a different number of columns than the tuple it was decoded into, or fewer
+    /// columns than the fields of the struct it was decoded into.
+    ColumnCountMismatch { expected: usize, found: usize },
+
+    /// The type of a bound argument is not compatible with the type the database expects
+    /// for that parameter. `index` is 1-based, matching the `$N` placeholders.
+    ParameterTypeMismatch {
+        index: usize,
+        expected: Box<str>,
+        found: Box<str>,
+    },
+
+    /// A type given by name was not found in the database.
+    TypeNotFound { type_name: Box<str> },
+
+    /// A query was built with more bind parameters than the database allows.
+    TooManyParameters { len: usize, max: usize },
+
+    /// The arrays bound for the columns of an `unnest(..)` do not all have the same length.
+    ArrayLengthMismatch { expected: usize, found: usize },
+
+    /// A query builder was given nothing to write into a list that SQL requires to have at
+    /// least one item, such as the rows of a `VALUES` list.
+    EmptyList { list: &'static str },
+
+    /// A fragment of SQL given to a query builder has an unterminated quoted string,
+    /// identifier or comment, or contains a bind parameter placeholder.
+    InvalidSqlFragment { sql: Box<str>, reason: &'static str },
+
     /// Unexpected or invalid data was encountered. This would indicate that we received
     /// data that we were not expecting or it was in a format we did not understand. This
     /// generally means either there is a programming error in a SQLx driver or
@@ -50,6 +86,11 @@ pub enum Error {
 
     /// An error occurred decoding data received from the database.
     Decode(Box<dyn StdError + Send + Sync>),
+
+    /// A bound value cannot be encoded for the database, e.g., an integer that is out of
+    /// range for the type it is sent as. Returned when the query is executed, before it is
+    /// sent.
+    Encode(Box<dyn StdError + Send + Sync>),
 }
 
 impl Error {
@@ -69,6 +110,7 @@ impl StdError for Error {
             Error::UrlParse(error) => Some(error),
             Error::PoolTimedOut(Some(error)) => Some(&**error),
             Error::Decode(error) => Some(&**error),
+            Error::Encode(error) => Some(&**error),
             Error::Tls(error) => Some(&**error),
 
             _ => None,
@@ -87,8 +129,12 @@ impl Display for Error {
 
             Error::Decode(error) => write!(f, "{}", error),
 
+            Error::Encode(error) => write!(f, "{}", error),
+
             Error::Database(error) => Display::fmt(error, f),
 
+            Error::LockTimeout(error) => Display::fmt(error, f),
+
             Error::RowNotFound => f.write_str("found no row when we expected at least one"),
 
             Error::ColumnNotFound(ref name) => {
@@ -101,6 +147,44 @@ impl Display for Error {
                 len, index
             ),
 
+            Error::ColumnCountMismatch { expected, found } => write!(
+                f,
+                "mismatched column count: expected {} columns but the row has {}",
+                expected, found
+            ),
+
+            Error::ParameterTypeMismatch {
+                index,
+                ref expected,
+                ref found,
+            } => write!(
+                f,
+                "mismatched types for parameter ${}: the database expects {} but {} was bound",
+                index, expected, found
+            ),
+
+            Error::TypeNotFound { ref type_name } => {
+                write!(f, "type {:?} was not found in the database", type_name)
+            }
+
+            Error::TooManyParameters { len, max } => write!(
+                f,
+                "too many bind parameters: the database allows at most {} but {} were bound",
+                max, len
+            ),
+
+            Error::ArrayLengthMismatch { expected, found } => write!(
+                f,
+                "mismatched array lengths: expected {} elements but {} were bound",
+                expected, found
+            ),
+
+            Error::EmptyList { list } => write!(f, "{} must have at least one item", list),
+
+            Error::InvalidSqlFragment { ref sql, reason } => {
+                write!(f, "invalid SQL fragment {:?}: {}", sql, reason)
+            }
+
             Error::Protocol(ref err) => f.write_str(err),
 
             Error::PoolTimedOut(Some(ref err)) => {
@@ -201,6 +285,11 @@ pub trait DatabaseError: Display + Debug + Send + Sync {
     fn constraint_name(&self) -> Option<&str> {
         None
     }
+
+    /// The 1-based position, in characters, in the query text at which the error occurred.
+    fn position(&self) -> Option<usize> {
+        None
+    }
 }
 
 /// Used by the `protocol_error!()` macro for a lazily evaluated conversion to
@@ -250,3 +339,32 @@ impl Display for UnexpectedNullError {
 }
 
 impl StdError for UnexpectedNullError {}
+
+/// A value could not be decoded into a Rust type that is not compatible with
+/// the SQL type of its column.
+///
+/// Returned from `Row::get` in place of the error from [`Decode`](crate::decode::Decode)
+/// to name the types involved.
+#[derive(Debug)]
+pub struct MismatchedTypeError {
+    pub(crate) rust_type: &'static str,
+    pub(crate) expected: Box<str>,
+    pub(crate) found: Box<str>,
+    pub(crate) source: Box<dyn StdError + Send + Sync>,
+}
+
+impl Display for MismatchedTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "mismatched types; Rust type `{}` (as SQL type {}) is not compatible with SQL type {}: {}",
+            self.rust_type, self.expected, self.found, self.source
+        )
+    }
+}
+
+impl StdError for MismatchedTypeError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&*self.source)
+    }
+}
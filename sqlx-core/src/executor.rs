@@ -1,7 +1,11 @@
 use futures_core::future::BoxFuture;
 
+use crate::cursor::Cursor;
 use crate::database::{Database, HasCursor};
+use crate::decode::Decode;
 use crate::describe::Describe;
+use crate::row::{ColumnIndex, Row};
+use crate::types::Type;
 
 /// A type that contains or can provide a database connection to use for executing queries
 /// against the database.
@@ -13,6 +17,11 @@ use crate::describe::Describe;
 /// Implementations are provided for [`&Pool`](struct.Pool.html),
 /// [`&mut PoolConnection`](struct.PoolConnection.html),
 /// and [`&mut Connection`](trait.Connection.html).
+///
+/// Each query is logged to the `sqlx::query` target at `DEBUG` level once it completes, with
+/// the number of its arguments, the number of rows it returned or affected and the time it
+/// took. The values of the arguments are not logged. Logging is compiled out without the
+/// `log-queries` feature, which is enabled by default.
 pub trait Executor
 where
     Self: Send,
@@ -23,11 +32,11 @@ where
     /// Executes the query for its side-effects and
     /// discarding any potential result rows.
     ///
-    /// Returns the number of rows affected, or 0 if not applicable.
+    /// Returns a [`QueryResult`] with the number of rows affected, or 0 if not applicable.
     fn execute<'e, 'q: 'e, 'c: 'e, E: 'e>(
         &'c mut self,
         query: E,
-    ) -> BoxFuture<'e, crate::Result<u64>>
+    ) -> BoxFuture<'e, crate::Result<QueryResult>>
     where
         E: Execute<'q, Self::Database>;
 
@@ -39,6 +48,35 @@ where
     where
         E: Execute<'q, Self::Database>;
 
+    /// Executes a query for a single value; the first column of the first row.
+    ///
+    /// Returns [`Error::RowNotFound`](enum.Error.html#variant.RowNotFound) if the query
+    /// returns no rows. Any further rows are read and discarded.
+    ///
+    /// ```rust,ignore
+    /// let count: i64 = conn.fetch_scalar("SELECT COUNT(*) FROM users").await?;
+    /// ```
+    fn fetch_scalar<'e, 'q: 'e, T, E>(&'e mut self, query: E) -> BoxFuture<'e, crate::Result<T>>
+    where
+        E: Execute<'q, Self::Database> + 'e,
+        T: Type<Self::Database> + for<'r> Decode<'r, Self::Database> + Send + 'e,
+        usize: ColumnIndex<Self::Database>,
+    {
+        Box::pin(async move {
+            let mut cursor = self.fetch(query);
+
+            let value = match cursor.next().await? {
+                Some(row) => row.try_get(0)?,
+                None => return Err(crate::Error::RowNotFound),
+            };
+
+            // read the remaining rows so the connection is ready for the next query
+            while cursor.next().await?.is_some() {}
+
+            Ok(value)
+        })
+    }
+
     /// Prepare the SQL query and return type information about its parameters
     /// and results.
     ///
@@ -52,6 +90,51 @@ where
         E: Execute<'q, Self::Database>;
 }
 
+/// The result of [`Executor::execute`].
+///
+/// Besides the number of rows affected, which every database reports, this carries the
+/// metadata specific to the database the query ran on; the rest is `None`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueryResult {
+    pub(crate) rows_affected: u64,
+    pub(crate) last_insert_id: Option<u64>,
+    pub(crate) last_insert_rowid: Option<i64>,
+    pub(crate) command_tag: Option<Box<str>>,
+}
+
+impl QueryResult {
+    /// Returns the number of rows inserted, updated or deleted by the query, or 0 if not
+    /// applicable. The counts of a query with several statements are summed.
+    pub fn rows_affected(&self) -> u64 {
+        self.rows_affected
+    }
+
+    /// Returns the `AUTO_INCREMENT` value generated by the last statement (**MySQL** only).
+    ///
+    /// This is 0 if the statement did not generate a value.
+    pub fn last_insert_id(&self) -> Option<u64> {
+        self.last_insert_id
+    }
+
+    /// Returns the rowid of the most recent successful `INSERT` on the connection
+    /// (**SQLite** only).
+    pub fn last_insert_rowid(&self) -> Option<i64> {
+        self.last_insert_rowid
+    }
+
+    /// Returns the command tag of the last statement, e.g., `INSERT 0 1` or `CREATE TABLE`
+    /// (**Postgres** only).
+    pub fn command_tag(&self) -> Option<&str> {
+        self.command_tag.as_deref()
+    }
+}
+
+impl From<QueryResult> for u64 {
+    fn from(result: QueryResult) -> Self {
+        result.rows_affected
+    }
+}
+
 // HACK: Generic Associated Types (GATs) will enable us to rework how the Executor bound is done
 //       in Query to remove the need for this.
 pub trait RefExecutor<'e> {
@@ -95,7 +178,7 @@ where
     fn execute<'e, 'q: 'e, 'c: 'e, E: 'e>(
         &'c mut self,
         query: E,
-    ) -> BoxFuture<'e, crate::Result<u64>>
+    ) -> BoxFuture<'e, crate::Result<QueryResult>>
     where
         E: Execute<'q, Self::Database>,
     {
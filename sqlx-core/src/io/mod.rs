@@ -4,6 +4,7 @@ mod buf_stream;
 mod buf;
 mod buf_mut;
 mod byte_str;
+mod tcp;
 mod tls;
 
 pub use self::{
@@ -0,0 +1,160 @@
+use std::io;
+use std::time::Duration;
+
+use socket2::{SockRef, TcpKeepalive};
+
+use crate::runtime::TcpStream;
+use crate::url::Url;
+
+/// Options for the TCP socket to the database server, taken from the `tcp_nodelay` and
+/// `tcp_keepalive` query parameters of the connection string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TcpOptions {
+    // disables Nagle's algorithm; on by default as most messages are small
+    // and a response is awaited after each of them
+    nodelay: bool,
+
+    // enables keepalive probes once the socket has been idle for this long
+    keepalive: Option<Duration>,
+}
+
+impl Default for TcpOptions {
+    fn default() -> Self {
+        Self {
+            nodelay: true,
+            keepalive: None,
+        }
+    }
+}
+
+impl TcpOptions {
+    pub(crate) fn from_url(url: &Url) -> crate::Result<Self> {
+        let mut options = Self::default();
+
+        if let Some(value) = url.param("tcp_nodelay") {
+            options.nodelay = match &*value {
+                "true" | "on" | "1" => true,
+                "false" | "off" | "0" => false,
+
+                _ => return Err(invalid_param("tcp_nodelay", &value)),
+            };
+        }
+
+        if let Some(value) = url.param("tcp_keepalive") {
+            let secs: u64 = value
+                .parse()
+                .map_err(|_| invalid_param("tcp_keepalive", &value))?;
+
+            // 0 leaves keepalive disabled, as with `libpq`'s `keepalives=0`
+            options.keepalive = if secs == 0 {
+                None
+            } else {
+                Some(Duration::from_secs(secs))
+            };
+        }
+
+        Ok(options)
+    }
+}
+
+fn invalid_param(key: &str, value: &str) -> crate::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("invalid value {:?} for `{}`", value, key),
+    )
+    .into()
+}
+
+/// Connects to the host and port of `url`, configuring the socket as its query parameters ask.
+pub(crate) async fn connect(url: &Url, default_port: u16) -> crate::Result<TcpStream> {
+    let options = TcpOptions::from_url(url)?;
+
+    let stream = TcpStream::connect((url.host(), url.port(default_port))).await?;
+
+    stream.set_nodelay(options.nodelay)?;
+
+    if let Some(keepalive) = options.keepalive {
+        set_keepalive(SockRef::from(&stream), keepalive)?;
+    }
+
+    Ok(stream)
+}
+
+// Enables keepalive probes once the socket has been idle for `keepalive`, repeating them at the
+// same interval on the platforms where it can be set
+fn set_keepalive(socket: SockRef<'_>, keepalive: Duration) -> io::Result<()> {
+    let params = TcpKeepalive::new().with_time(keepalive);
+
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "illumos",
+        target_os = "linux",
+        target_os = "netbsd",
+        target_vendor = "apple",
+        windows,
+    ))]
+    let params = params.with_interval(keepalive);
+
+    socket.set_tcp_keepalive(&params)
+}
+
+#[test]
+fn test_tcp_options_from_url() {
+    use std::convert::TryFrom;
+
+    let options = |url: &str| TcpOptions::from_url(&Url::try_from(url).unwrap());
+
+    assert_eq!(
+        options("postgres://localhost").unwrap(),
+        TcpOptions::default()
+    );
+
+    assert_eq!(
+        options("postgres://localhost?tcp_nodelay=false&tcp_keepalive=30").unwrap(),
+        TcpOptions {
+            nodelay: false,
+            keepalive: Some(Duration::from_secs(30)),
+        }
+    );
+
+    assert_eq!(
+        options("mysql://localhost?tcp_keepalive=0").unwrap(),
+        TcpOptions::default()
+    );
+
+    assert!(options("postgres://localhost?tcp_nodelay=yes").is_err());
+    assert!(options("postgres://localhost?tcp_keepalive=-1").is_err());
+}
+
+#[cfg(feature = "runtime-async-std")]
+#[test]
+fn test_connect_with_tcp_options() -> crate::Result<()> {
+    use std::convert::TryFrom;
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+    let port = listener.local_addr()?.port();
+
+    let url = format!("postgres://127.0.0.1:{}?tcp_nodelay=false", port);
+    let stream = async_std::task::block_on(connect(&Url::try_from(url)?, 5432))?;
+
+    assert!(!stream.nodelay()?);
+    assert!(!SockRef::from(&stream).keepalive()?);
+
+    let url = format!("postgres://127.0.0.1:{}?tcp_keepalive=45", port);
+    let stream = async_std::task::block_on(connect(&Url::try_from(url)?, 5432))?;
+    let socket = SockRef::from(&stream);
+
+    assert!(stream.nodelay()?);
+    assert!(socket.keepalive()?);
+
+    #[cfg(target_os = "linux")]
+    {
+        assert_eq!(socket.keepalive_time()?, Duration::from_secs(45));
+        assert_eq!(socket.keepalive_interval()?, Duration::from_secs(45));
+    }
+
+    Ok(())
+}
@@ -3,6 +3,7 @@ use std::net::Shutdown;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
+use crate::io::tcp;
 use crate::runtime::{AsyncRead, AsyncWrite, TcpStream};
 use crate::url::Url;
 
@@ -22,7 +23,7 @@ enum Inner {
 
 impl MaybeTlsStream {
     pub async fn connect(url: &Url, default_port: u16) -> crate::Result<Self> {
-        let conn = TcpStream::connect((url.host(), url.port(default_port))).await?;
+        let conn = tcp::connect(url, default_port).await?;
         Ok(Self {
             inner: Inner::NotTls(conn),
         })
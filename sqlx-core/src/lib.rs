@@ -25,6 +25,12 @@ pub mod executor;
 pub mod transaction;
 mod url;
 
+#[cfg(all(
+    feature = "log-queries",
+    any(feature = "mysql", feature = "postgres", feature = "sqlite")
+))]
+mod logger;
+
 #[doc(hidden)]
 pub mod runtime;
 
@@ -35,6 +41,7 @@ pub mod describe;
 pub mod encode;
 pub mod pool;
 pub mod query;
+pub mod retry;
 
 #[macro_use]
 pub mod query_as;
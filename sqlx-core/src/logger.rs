@@ -0,0 +1,211 @@
+use std::fmt::{self, Display};
+use std::time::{Duration, Instant};
+
+use log::Level;
+
+const TARGET: &str = "sqlx::query";
+
+// Logs a query to the `sqlx::query` target at `DEBUG` level once it has completed (or has been
+// dropped), with the number of its arguments, the number of rows it returned or affected and
+// the time it took. A query that failed is logged at `WARN` level instead, with its error.
+// The values of the arguments are never logged.
+//
+// A query is only timed if `DEBUG` is enabled for the target when it starts; a failure logged
+// while only `WARN` is enabled leaves out the elapsed time. Without the `log-queries` feature
+// this module is not compiled and the cursors and executors have no logger at all.
+pub(crate) struct QueryLogger<'q> {
+    sql: &'q str,
+    arguments: usize,
+    rows_returned: u64,
+    rows_affected: Option<u64>,
+    start: Option<Instant>,
+}
+
+impl<'q> QueryLogger<'q> {
+    pub(crate) fn new(sql: &'q str, arguments: usize) -> Self {
+        let start = if log::log_enabled!(target: TARGET, Level::Debug) {
+            Some(Instant::now())
+        } else {
+            None
+        };
+
+        Self {
+            sql,
+            arguments,
+            rows_returned: 0,
+            rows_affected: None,
+            start,
+        }
+    }
+
+    pub(crate) fn increment_rows(&mut self) {
+        self.rows_returned += 1;
+    }
+
+    pub(crate) fn set_rows_affected(&mut self, rows: u64) {
+        self.rows_affected = Some(rows);
+    }
+
+    // Logs the query as failed with [error], in place of the record logged on drop
+    pub(crate) fn fail(mut self, error: &crate::Error) {
+        let elapsed = self.start.take().map(|start| start.elapsed());
+
+        log::warn!(
+            target: TARGET,
+            "{}, error: {}",
+            self.record(elapsed),
+            error
+        );
+    }
+
+    fn record(&self, elapsed: Option<Duration>) -> Record<'_, 'q> {
+        Record {
+            logger: self,
+            elapsed,
+        }
+    }
+}
+
+impl Drop for QueryLogger<'_> {
+    fn drop(&mut self) {
+        if let Some(start) = self.start {
+            log::debug!(target: TARGET, "{}", self.record(Some(start.elapsed())));
+        }
+    }
+}
+
+struct Record<'a, 'q> {
+    logger: &'a QueryLogger<'q>,
+    elapsed: Option<Duration>,
+}
+
+impl Display for Record<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let logger = self.logger;
+
+        write!(
+            f,
+            "{}; arguments: {}, ",
+            logger.sql.trim(),
+            logger.arguments
+        )?;
+
+        match logger.rows_affected {
+            Some(rows) => write!(f, "rows affected: {}", rows)?,
+            None => write!(f, "rows returned: {}", logger.rows_returned)?,
+        }
+
+        if let Some(elapsed) = self.elapsed {
+            write!(f, ", elapsed: {:.3?}", elapsed)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_query_logger_record() {
+    let mut logger = QueryLogger::new("\n  SELECT * FROM users WHERE id = $1\n", 1);
+    logger.increment_rows();
+    logger.increment_rows();
+
+    assert_eq!(
+        logger.record(Some(Duration::from_micros(1500))).to_string(),
+        "SELECT * FROM users WHERE id = $1; arguments: 1, rows returned: 2, elapsed: 1.500ms"
+    );
+
+    logger.set_rows_affected(3);
+
+    assert_eq!(
+        logger.record(Some(Duration::from_micros(1500))).to_string(),
+        "SELECT * FROM users WHERE id = $1; arguments: 1, rows affected: 3, elapsed: 1.500ms"
+    );
+
+    assert_eq!(
+        logger.record(None).to_string(),
+        "SELECT * FROM users WHERE id = $1; arguments: 1, rows affected: 3"
+    );
+}
+
+#[test]
+fn test_query_logger_emits_a_record() {
+    use std::sync::Mutex;
+
+    struct Capture(Mutex<Vec<String>>);
+
+    impl log::Log for Capture {
+        fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+            metadata.target() == TARGET
+        }
+
+        fn log(&self, record: &log::Record<'_>) {
+            if self.enabled(record.metadata()) {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push(format!("{} {}", record.level(), record.args()));
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    static CAPTURE: Capture = Capture(Mutex::new(Vec::new()));
+
+    log::set_logger(&CAPTURE).unwrap();
+
+    let max_level = log::max_level();
+    log::set_max_level(log::LevelFilter::Debug);
+
+    let mut logger = QueryLogger::new("UPDATE users SET name = $1", 2);
+    logger.set_rows_affected(1);
+    drop(logger);
+
+    let logger = QueryLogger::new("DELETE FROM users", 0);
+    logger.fail(&crate::Error::PoolClosed);
+
+    // with only `WARN` enabled, queries are not timed and only failures are logged
+    log::set_max_level(log::LevelFilter::Warn);
+
+    let logger = QueryLogger::new("SELECT * FROM users", 0);
+    assert!(logger.start.is_none());
+    drop(logger);
+
+    let logger = QueryLogger::new("TRUNCATE users", 0);
+    logger.fail(&crate::Error::PoolClosed);
+
+    log::set_max_level(max_level);
+
+    // other tests may log queries of their own
+    let records = CAPTURE.0.lock().unwrap();
+    let records: Vec<_> = records
+        .iter()
+        .filter(|record| record.contains(" users"))
+        .collect();
+
+    assert_eq!(records.len(), 3);
+    assert!(
+        records[0].starts_with(
+            "DEBUG UPDATE users SET name = $1; arguments: 2, rows affected: 1, elapsed: "
+        ),
+        "{}",
+        records[0]
+    );
+    assert!(
+        records[1].starts_with("WARN DELETE FROM users; arguments: 0, rows returned: 0, elapsed: "),
+        "{}",
+        records[1]
+    );
+    assert!(
+        records[1].ends_with(&format!(", error: {}", crate::Error::PoolClosed)),
+        "{}",
+        records[1]
+    );
+    assert_eq!(
+        *records[2],
+        format!(
+            "WARN TRUNCATE users; arguments: 0, rows returned: 0, error: {}",
+            crate::Error::PoolClosed
+        )
+    );
+}
@@ -1,5 +1,5 @@
 use crate::arguments::Arguments;
-use crate::encode::{Encode, IsNull};
+use crate::encode::{DynEncode, Encode, IsNull};
 use crate::mysql::types::MySqlTypeInfo;
 use crate::mysql::MySql;
 use crate::types::Type;
@@ -11,6 +11,23 @@ pub struct MySqlArguments {
     pub(crate) null_bitmap: Vec<u8>,
 }
 
+impl MySqlArguments {
+    /// Returns the number of bound arguments.
+    pub fn len(&self) -> usize {
+        self.param_types.len()
+    }
+
+    /// Returns `true` if no arguments have been bound.
+    pub fn is_empty(&self) -> bool {
+        self.param_types.is_empty()
+    }
+
+    /// Returns the types of the bound arguments, in the order they were added.
+    pub fn types(&self) -> &[MySqlTypeInfo] {
+        &self.param_types
+    }
+}
+
 impl Arguments for MySqlArguments {
     type Database = MySql;
 
@@ -30,14 +47,73 @@ impl Arguments for MySqlArguments {
         T: Type<Self::Database>,
         T: Encode<Self::Database>,
     {
-        let type_id = <T as Type<MySql>>::type_info();
+        self.push(<T as Type<MySql>>::type_info(), value.size_hint(), |buf| {
+            value.encode_nullable(buf)
+        });
+    }
+
+    fn add_dyn(&mut self, value: &dyn DynEncode<MySql>) {
+        self.push(value.type_info_dyn(), value.size_hint_dyn(), |buf| {
+            value.encode_dyn(buf)
+        });
+    }
+}
+
+impl MySqlArguments {
+    // Appends a value written by `encode`; `add` passes a closure over the concrete type so
+    // that only `add_dyn` encodes through the vtable
+    fn push<F>(&mut self, type_id: MySqlTypeInfo, size_hint: usize, encode: F)
+    where
+        F: FnOnce(&mut Vec<u8>) -> IsNull,
+    {
         let index = self.param_types.len();
 
         self.param_types.push(type_id);
         self.null_bitmap.resize((index / 8) + 1, 0);
+        self.params.reserve(size_hint);
 
-        if let IsNull::Yes = value.encode_nullable(&mut self.params) {
+        if let IsNull::Yes = encode(&mut self.params) {
             self.null_bitmap[index / 8] |= (1 << index % 8) as u8;
         }
     }
 }
+
+#[test]
+fn test_size_hint_is_a_lower_bound() {
+    fn assert_lower_bound<T: Encode<MySql>>(value: T) {
+        let mut buf = Vec::new();
+        value.encode(&mut buf);
+
+        assert!(value.size_hint() <= buf.len());
+    }
+
+    assert_lower_bound(true);
+    assert_lower_bound(42_i16);
+    assert_lower_bound(42_u32);
+    assert_lower_bound(42_i64);
+    assert_lower_bound(4.2_f64);
+    assert_lower_bound("hello");
+    assert_lower_bound("x".repeat(300));
+    assert_lower_bound(vec![0xDE_u8, 0xAD, 0xBE, 0xEF]);
+    assert_lower_bound(Some(42_i32));
+}
+
+#[test]
+fn test_len_and_types() {
+    use crate::types::TypeInfo;
+
+    let mut args = MySqlArguments::default();
+    assert!(args.is_empty());
+
+    args.add(42_i32);
+    args.add("hello");
+    args.add(None::<bool>);
+
+    assert_eq!(args.len(), 3);
+
+    let names: Vec<_> = args.types().iter().map(|ty| ty.name()).collect();
+    assert_eq!(names, ["INT", "TEXT", "TINY_INT"]);
+
+    // NULL is recorded in the bitmap, not as a type
+    assert_eq!(args.null_bitmap, [0b100]);
+}
@@ -1,4 +1,3 @@
-use std::collections::HashMap;
 use std::sync::Arc;
 
 use futures_core::future::BoxFuture;
@@ -6,16 +5,51 @@ use futures_core::future::BoxFuture;
 use crate::connection::ConnectionSource;
 use crate::cursor::Cursor;
 use crate::executor::Execute;
+#[cfg(feature = "log-queries")]
+use crate::logger::QueryLogger;
 use crate::mysql::protocol::{ColumnCount, ColumnDefinition, Row, Status, TypeId};
-use crate::mysql::{MySql, MySqlArguments, MySqlConnection, MySqlRow};
+use crate::mysql::{MySql, MySqlArguments, MySqlConnection, MySqlRow, MySqlTypeInfo};
 use crate::pool::Pool;
+use crate::row::ColumnNames;
 
 pub struct MySqlCursor<'c, 'q> {
     source: ConnectionSource<'c, MySqlConnection>,
     query: Option<(&'q str, Option<MySqlArguments>)>,
-    column_names: Arc<HashMap<Box<str>, u16>>,
+    column_names: Arc<ColumnNames>,
     column_types: Vec<TypeId>,
+    column_infos: Arc<[MySqlTypeInfo]>,
     binary: bool,
+    // Whether [next] stops at the end of each result set; set by [next_result_set]
+    by_result_set: bool,
+    #[cfg(feature = "log-queries")]
+    logger: Option<QueryLogger<'q>>,
+}
+
+impl<'c, 'q> MySqlCursor<'c, 'q> {
+    /// Advances to the next result set of the query, returning `false` if there are no more.
+    ///
+    /// A query returns several result sets when it holds several statements or calls a
+    /// stored procedure. By default, [`next`] returns the rows of every result set one after
+    /// the other. Once this has been called, [`next`] instead returns `None` at the end of
+    /// each result set, so they can be told apart:
+    ///
+    /// ```rust,ignore
+    /// let mut cursor = conn.fetch("CALL my_procedure()");
+    ///
+    /// while cursor.next_result_set().await? {
+    ///     while let Some(row) = cursor.next().await? {
+    ///         // ..
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// The first call moves to the first result set. Any rows left in the current result set
+    /// are skipped.
+    ///
+    /// [`next`]: crate::cursor::Cursor::next
+    pub fn next_result_set(&mut self) -> BoxFuture<'_, crate::Result<bool>> {
+        Box::pin(next_result_set(self))
+    }
 }
 
 impl<'c, 'q> Cursor<'c, 'q> for MySqlCursor<'c, 'q> {
@@ -31,8 +65,12 @@ impl<'c, 'q> Cursor<'c, 'q> for MySqlCursor<'c, 'q> {
             source: ConnectionSource::Pool(pool.clone()),
             column_names: Arc::default(),
             column_types: Vec::new(),
+            column_infos: Arc::new([] as [MySqlTypeInfo; 0]),
             binary: true,
+            by_result_set: false,
             query: Some(query.into_parts()),
+            #[cfg(feature = "log-queries")]
+            logger: None,
         }
     }
 
@@ -46,8 +84,12 @@ impl<'c, 'q> Cursor<'c, 'q> for MySqlCursor<'c, 'q> {
             source: ConnectionSource::ConnectionRef(conn),
             column_names: Arc::default(),
             column_types: Vec::new(),
+            column_infos: Arc::new([] as [MySqlTypeInfo; 0]),
             binary: true,
+            by_result_set: false,
             query: Some(query.into_parts()),
+            #[cfg(feature = "log-queries")]
+            logger: None,
         }
     }
 
@@ -56,45 +98,142 @@ impl<'c, 'q> Cursor<'c, 'q> for MySqlCursor<'c, 'q> {
     }
 }
 
+// Sends the query and reads the header of its first result set, returning `false` if it
+// has none
+async fn execute<'q>(
+    cursor: &mut MySqlCursor<'_, 'q>,
+    query: &'q str,
+    arguments: Option<MySqlArguments>,
+) -> crate::Result<bool> {
+    #[cfg(feature = "log-queries")]
+    {
+        cursor.logger = Some(QueryLogger::new(
+            query,
+            arguments.as_ref().map_or(0, MySqlArguments::len),
+        ));
+    }
+
+    let conn = cursor.source.resolve().await?;
+    let statement = conn.run(query, arguments).await?;
+
+    // No statement ID = TEXT mode
+    cursor.binary = statement.is_some();
+
+    let has_result_set = read_result_set_header(
+        conn,
+        &mut cursor.column_types,
+        &mut cursor.column_names,
+        &mut cursor.column_infos,
+    )
+    .await?;
+
+    // the query is complete; log it
+    #[cfg(feature = "log-queries")]
+    if !has_result_set {
+        cursor.logger = None;
+    }
+
+    Ok(has_result_set)
+}
+
 async fn next<'a, 'c: 'a, 'q: 'a>(
     cursor: &'a mut MySqlCursor<'c, 'q>,
 ) -> crate::Result<Option<MySqlRow<'a>>> {
-    let mut conn = cursor.source.resolve().await?;
+    let result = match advance(cursor).await {
+        Ok(false) => return Ok(None),
+        Ok(true) => match cursor.source.resolve().await {
+            Ok(conn) => Row::read(
+                conn.stream.packet(),
+                &cursor.column_types,
+                &mut conn.current_row_values,
+                cursor.binary,
+            ),
+            Err(error) => Err(error),
+        },
+        Err(error) => Err(error),
+    };
+
+    match result {
+        Ok(row) => Ok(Some(MySqlRow {
+            row,
+            columns: Arc::clone(&cursor.column_names),
+            types: Arc::clone(&cursor.column_infos),
+        })),
+
+        Err(error) => {
+            #[cfg(feature = "log-queries")]
+            if let Some(logger) = cursor.logger.take() {
+                logger.fail(&error);
+            }
 
+            Err(error)
+        }
+    }
+}
+
+// Reads up to the next row, returning `true` once it is in the packet buffer of the stream or
+// `false` at the end of the query (or of the current result set when stopping at each)
+async fn advance(cursor: &mut MySqlCursor<'_, '_>) -> crate::Result<bool> {
     // The first time [next] is called we need to actually execute our
     // contained query. We guard against this happening on _all_ next calls
     // by using [Option::take] which replaces the potential value in the Option with `None
-    let mut initial = if let Some((query, arguments)) = cursor.query.take() {
-        let statement = conn.run(query, arguments).await?;
+    if let Some((query, arguments)) = cursor.query.take() {
+        if !execute(cursor, query, arguments).await? {
+            return Ok(false);
+        }
+    }
 
-        // No statement ID = TEXT mode
-        cursor.binary = statement.is_some();
+    let conn = cursor.source.resolve().await?;
 
-        true
-    } else {
-        false
-    };
+    if conn.is_ready || conn.more_results {
+        // The query, or the current result set when stopping at each, has been fully read
+        return Ok(false);
+    }
 
     loop {
         let packet_id = conn.stream.receive().await?[0];
 
         match packet_id {
-            // OK or EOF packet
-            0x00 | 0xFE
-                if conn.stream.packet().len() < 0xFF_FF_FF && (packet_id != 0x00 || initial) =>
-            {
+            // EOF packet, or OK packet when EOF is deprecated
+            // A text row can begin with 0xFE (when using text protocol with a field
+            // length > 0xFFFFFF)
+            0xFE if conn.stream.packet().len() < 0xFF_FF_FF => {
                 let status = if let Some(eof) = conn.stream.maybe_handle_eof()? {
                     eof.status
                 } else {
                     conn.stream.handle_ok()?.status
                 };
 
-                if status.contains(Status::SERVER_MORE_RESULTS_EXISTS) {
-                    // There is more to this query
-                    initial = true;
-                } else {
+                if !status.contains(Status::SERVER_MORE_RESULTS_EXISTS) {
                     conn.is_ready = true;
-                    return Ok(None);
+
+                    // the query is complete; log it
+                    #[cfg(feature = "log-queries")]
+                    drop(cursor.logger.take());
+
+                    return Ok(false);
+                }
+
+                if cursor.by_result_set {
+                    // [next_result_set] moves to the next one
+                    conn.more_results = true;
+
+                    return Ok(false);
+                }
+
+                // carry on with the rows of the next result set
+                if !read_result_set_header(
+                    conn,
+                    &mut cursor.column_types,
+                    &mut cursor.column_names,
+                    &mut cursor.column_infos,
+                )
+                .await?
+                {
+                    #[cfg(feature = "log-queries")]
+                    drop(cursor.logger.take());
+
+                    return Ok(false);
                 }
             }
 
@@ -104,7 +243,96 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
                 return conn.stream.handle_err();
             }
 
-            _ if initial => {
+            _ if !cursor.binary || packet_id == 0x00 => {
+                #[cfg(feature = "log-queries")]
+                if let Some(logger) = &mut cursor.logger {
+                    logger.increment_rows();
+                }
+
+                return Ok(true);
+            }
+
+            _ => return conn.stream.handle_unexpected(),
+        }
+    }
+}
+
+async fn next_result_set(cursor: &mut MySqlCursor<'_, '_>) -> crate::Result<bool> {
+    let result = advance_result_set(cursor).await;
+
+    #[cfg(feature = "log-queries")]
+    if let Err(error) = &result {
+        if let Some(logger) = cursor.logger.take() {
+            logger.fail(error);
+        }
+    }
+
+    result
+}
+
+async fn advance_result_set(cursor: &mut MySqlCursor<'_, '_>) -> crate::Result<bool> {
+    cursor.by_result_set = true;
+
+    if let Some((query, arguments)) = cursor.query.take() {
+        return execute(cursor, query, arguments).await;
+    }
+
+    // skip the rest of the current result set
+    while next(cursor).await?.is_some() {}
+
+    let conn = cursor.source.resolve().await?;
+
+    if !conn.more_results {
+        return Ok(false);
+    }
+
+    conn.more_results = false;
+
+    let has_result_set = read_result_set_header(
+        conn,
+        &mut cursor.column_types,
+        &mut cursor.column_names,
+        &mut cursor.column_infos,
+    )
+    .await?;
+
+    #[cfg(feature = "log-queries")]
+    if !has_result_set {
+        cursor.logger = None;
+    }
+
+    Ok(has_result_set)
+}
+
+// Reads the column definitions at the start of the next result set, skipping the OK packets
+// of statements that return no rows. Returns `false` once there are no more result sets.
+async fn read_result_set_header(
+    conn: &mut MySqlConnection,
+    column_types: &mut Vec<TypeId>,
+    column_names: &mut Arc<ColumnNames>,
+    column_infos: &mut Arc<[MySqlTypeInfo]>,
+) -> crate::Result<bool> {
+    loop {
+        let packet_id = conn.stream.receive().await?[0];
+
+        match packet_id {
+            // OK packet
+            0x00 | 0xFE if conn.stream.packet().len() < 0xFF_FF_FF => {
+                let status = conn.stream.handle_ok()?.status;
+
+                if !status.contains(Status::SERVER_MORE_RESULTS_EXISTS) {
+                    conn.is_ready = true;
+                    return Ok(false);
+                }
+            }
+
+            // ERR packet
+            0xFF => {
+                conn.is_ready = true;
+                return conn.stream.handle_err();
+            }
+
+            _ => {
                 // At the start of the results we expect to see a
                 // COLUMN_COUNT followed by N COLUMN_DEF
 
@@ -113,47 +341,32 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
                 // We use these definitions to get the actual column types that is critical
                 // in parsing the rows coming back soon
 
-                cursor.column_types.clear();
-                cursor.column_types.reserve(cc.columns as usize);
+                column_types.clear();
+                column_types.reserve(cc.columns as usize);
 
-                let mut column_names = HashMap::with_capacity(cc.columns as usize);
+                let mut names = Vec::with_capacity(cc.columns as usize);
+                let mut infos = Vec::with_capacity(cc.columns as usize);
 
-                for i in 0..cc.columns {
+                for _ in 0..cc.columns {
                     let column = ColumnDefinition::read(conn.stream.receive().await?)?;
 
-                    cursor.column_types.push(column.type_id);
+                    column_types.push(column.type_id);
+                    infos.push(
+                        MySqlTypeInfo::from_column_def(&column)
+                            .unwrap_or_else(|| MySqlTypeInfo::new(TypeId::NULL)),
+                    );
 
-                    if let Some(name) = column.name() {
-                        column_names.insert(name.to_owned().into_boxed_str(), i as u16);
-                    }
+                    names.push(column.name().map(str::to_owned));
                 }
 
                 if cc.columns > 0 {
                     conn.stream.maybe_receive_eof().await?;
                 }
 
-                cursor.column_names = Arc::new(column_names);
-                initial = false;
-            }
-
-            _ if !cursor.binary || packet_id == 0x00 => {
-                let row = Row::read(
-                    conn.stream.packet(),
-                    &cursor.column_types,
-                    &mut conn.current_row_values,
-                    cursor.binary,
-                )?;
-
-                let row = MySqlRow {
-                    row,
-                    columns: Arc::clone(&cursor.column_names),
-                };
+                *column_names = Arc::new(ColumnNames::new(names.iter().map(Option::as_deref)));
+                *column_infos = Arc::from(infos);
 
-                return Ok(Some(row));
-            }
-
-            _ => {
-                return conn.stream.handle_unexpected();
+                return Ok(true);
             }
         }
     }
@@ -15,6 +15,32 @@ impl Database for MySql {
     type RawBuffer = Vec<u8>;
 }
 
+impl MySql {
+    /// Quotes `ident` so it can be interpolated into a query as a table, column or
+    /// other identifier.
+    ///
+    /// The identifier is wrapped in backticks with any embedded backticks doubled. Returns
+    /// `None` if `ident` contains a NUL character, which MySQL never allows in an identifier.
+    ///
+    /// ```rust
+    /// # use sqlx_core::mysql::MySql;
+    /// assert_eq!(MySql::quote_identifier("my `table`").unwrap(), "`my ``table```");
+    /// ```
+    pub fn quote_identifier(ident: &str) -> Option<String> {
+        if ident.contains('\0') {
+            return None;
+        }
+
+        let mut quoted = String::with_capacity(ident.len() + 2);
+
+        quoted.push('`');
+        quoted.push_str(&ident.replace('`', "``"));
+        quoted.push('`');
+
+        Some(quoted)
+    }
+}
+
 impl<'c> HasRow<'c> for MySql {
     type Database = MySql;
 
@@ -30,3 +56,18 @@ impl<'c, 'q> HasCursor<'c, 'q> for MySql {
 impl<'c> HasRawValue<'c> for MySql {
     type RawValue = Option<super::MySqlValue<'c>>;
 }
+
+#[test]
+fn test_quote_identifier() {
+    assert_eq!(MySql::quote_identifier("users").unwrap(), "`users`");
+    assert_eq!(
+        MySql::quote_identifier("a`; DROP TABLE users; --").unwrap(),
+        "`a``; DROP TABLE users; --`"
+    );
+    assert_eq!(
+        MySql::quote_identifier("say \"hi\"").unwrap(),
+        "`say \"hi\"`"
+    );
+    assert_eq!(MySql::quote_identifier("üñí©ødé").unwrap(), "`üñí©ødé`");
+    assert!(MySql::quote_identifier("a\0b").is_none());
+}
@@ -2,34 +2,62 @@ use futures_core::future::BoxFuture;
 
 use crate::cursor::Cursor;
 use crate::describe::{Column, Describe};
-use crate::executor::{Execute, Executor, RefExecutor};
+use crate::executor::{Execute, Executor, QueryResult, RefExecutor};
+#[cfg(feature = "log-queries")]
+use crate::logger::QueryLogger;
 use crate::mysql::protocol::{
-    self, ColumnDefinition, ComQuery, ComStmtExecute, ComStmtPrepare, ComStmtPrepareOk, FieldFlags,
-    Status,
+    self, ColumnCount, ColumnDefinition, ComQuery, ComStmtClose, ComStmtExecute, ComStmtPrepare,
+    ComStmtPrepareOk, FieldFlags, Status,
 };
 use crate::mysql::{MySql, MySqlArguments, MySqlCursor, MySqlTypeInfo};
 
 impl super::MySqlConnection {
-    async fn wait_until_ready(&mut self) -> crate::Result<()> {
-        if !self.is_ready {
-            loop {
-                let packet_id = self.stream.receive().await?[0];
-                match packet_id {
-                    0xFE if self.stream.packet().len() < 0xFF_FF_FF => {
-                        // OK or EOF packet
-                        self.is_ready = true;
-                        break;
-                    }
+    pub(super) async fn wait_until_ready(&mut self) -> crate::Result<()> {
+        // Whether we are between result sets; otherwise, we are reading the rows of one
+        let mut at_result_set = self.more_results;
 
-                    0xFF => {
-                        // ERR packet
-                        self.is_ready = true;
-                        return self.stream.handle_err();
-                    }
+        self.more_results = false;
 
-                    _ => {
-                        // Something else; skip
-                    }
+        while !self.is_ready {
+            let packet_id = self.stream.receive().await?[0];
+
+            match packet_id {
+                0xFF => {
+                    // ERR packet
+                    self.is_ready = true;
+                    return self.stream.handle_err();
+                }
+
+                0x00 | 0xFE if at_result_set && self.stream.packet().len() < 0xFF_FF_FF => {
+                    // OK packet of a statement without rows
+                    let status = self.stream.handle_ok()?.status;
+
+                    self.is_ready = !status.contains(Status::SERVER_MORE_RESULTS_EXISTS);
+                }
+
+                _ if at_result_set => {
+                    // COLUMN_COUNT; skip the column definitions to reach the rows
+                    let columns = ColumnCount::read(self.stream.packet())?.columns;
+
+                    self.drop_column_defs(columns as usize).await?;
+
+                    at_result_set = false;
+                }
+
+                0xFE if self.stream.packet().len() < 0xFF_FF_FF => {
+                    // EOF packet, or OK packet when EOF is deprecated, ending the rows
+                    let status = if let Some(eof) = self.stream.maybe_handle_eof()? {
+                        eof.status
+                    } else {
+                        self.stream.handle_ok()?.status
+                    };
+
+                    at_result_set = true;
+                    self.is_ready = !status.contains(Status::SERVER_MORE_RESULTS_EXISTS);
+                }
+
+                _ => {
+                    // A row; skip
                 }
             }
         }
@@ -120,8 +148,9 @@ impl super::MySqlConnection {
         }
     }
 
-    async fn affected_rows(&mut self) -> crate::Result<u64> {
+    async fn affected_rows(&mut self) -> crate::Result<QueryResult> {
         let mut rows = 0;
+        let mut last_insert_id = 0;
 
         loop {
             let id = self.stream.receive().await?[0];
@@ -137,6 +166,7 @@ impl super::MySqlConnection {
                         let ok = self.stream.handle_ok()?;
 
                         rows += ok.affected_rows;
+                        last_insert_id = ok.last_insert_id;
                         ok.status
                     };
 
@@ -154,7 +184,11 @@ impl super::MySqlConnection {
             }
         }
 
-        Ok(rows)
+        Ok(QueryResult {
+            rows_affected: rows,
+            last_insert_id: Some(last_insert_id),
+            ..QueryResult::default()
+        })
     }
 
     // method is not named describe to work around an intellijrust bug
@@ -192,6 +226,17 @@ impl super::MySqlConnection {
             self.stream.maybe_receive_eof().await?;
         }
 
+        // the statement was only prepared to be described; the server sends no response
+        // to COM_STMT_CLOSE
+        self.stream
+            .send(
+                ComStmtClose {
+                    statement_id: stmt.statement_id,
+                },
+                true,
+            )
+            .await?;
+
         Ok(Describe {
             param_types: param_types.into_boxed_slice(),
             result_columns: result_columns.into_boxed_slice(),
@@ -205,15 +250,28 @@ impl Executor for super::MySqlConnection {
     fn execute<'e, 'q: 'e, 'c: 'e, E: 'e>(
         &'c mut self,
         query: E,
-    ) -> BoxFuture<'e, crate::Result<u64>>
+    ) -> BoxFuture<'e, crate::Result<QueryResult>>
     where
         E: Execute<'q, Self::Database>,
     {
         Box::pin(async move {
             let (query, arguments) = query.into_parts();
+            #[cfg(feature = "log-queries")]
+            let mut logger =
+                QueryLogger::new(query, arguments.as_ref().map_or(0, MySqlArguments::len));
+
+            let result = match self.run(query, arguments).await {
+                Ok(_) => self.affected_rows().await,
+                Err(error) => Err(error),
+            };
+
+            #[cfg(feature = "log-queries")]
+            match &result {
+                Ok(result) => logger.set_rows_affected(result.rows_affected()),
+                Err(error) => logger.fail(error),
+            }
 
-            self.run(query, arguments).await?;
-            self.affected_rows().await
+            result
         })
     }
 
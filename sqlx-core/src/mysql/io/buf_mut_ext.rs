@@ -58,9 +58,19 @@ impl BufMutExt for Vec<u8> {
     }
 }
 
+/// Returns the number of bytes `put_uint_lenenc` writes for `value`.
+pub fn uint_lenenc_size(value: u64) -> usize {
+    match value {
+        0..=0xFA => 1,
+        0xFB..=0xFF_FF => 3,
+        0x1_00_00..=0xFF_FF_FF => 4,
+        _ => 9,
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{BufMut, BufMutExt};
+    use super::{uint_lenenc_size, BufMut, BufMutExt};
     use byteorder::LittleEndian;
 
     #[test]
@@ -214,4 +224,24 @@ mod tests {
 
         assert_eq!(&buf[..], b"\x0Drandom_string");
     }
+
+    #[test]
+    fn it_sizes_int_lenenc() {
+        for &value in &[
+            0,
+            0xFA,
+            0xFB,
+            0xFF,
+            0x100,
+            0xFF_FF,
+            0x1_00_00,
+            0xFF_FF_FF,
+            u64::MAX,
+        ] {
+            let mut buf = Vec::new();
+            buf.put_uint_lenenc::<LittleEndian, _>(value);
+
+            assert_eq!(uint_lenenc_size(value), buf.len(), "{:#X}", value);
+        }
+    }
 }
@@ -2,4 +2,4 @@ mod buf_ext;
 mod buf_mut_ext;
 
 pub use buf_ext::BufExt;
-pub use buf_mut_ext::BufMutExt;
+pub use buf_mut_ext::{uint_lenenc_size, BufMutExt};
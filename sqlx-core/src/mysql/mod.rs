@@ -5,8 +5,8 @@ pub use connection::MySqlConnection;
 pub use cursor::MySqlCursor;
 pub use database::MySql;
 pub use error::MySqlError;
-pub use row::{MySqlRow, MySqlValue};
-pub use types::MySqlTypeInfo;
+pub use row::{MySqlRawValue, MySqlRow, MySqlValue};
+pub use types::{MySqlInet, MySqlTypeInfo};
 
 mod arguments;
 mod connection;
@@ -18,6 +18,8 @@ mod io;
 mod protocol;
 mod row;
 mod rsa;
+#[cfg(feature = "serde")]
+mod serialize;
 mod stream;
 mod tls;
 mod types;
@@ -31,3 +33,4 @@ make_query_as!(MySqlQueryAs, MySql, MySqlRow);
 impl_map_row_for_row!(MySql, MySqlRow);
 impl_column_index_for_row!(MySql);
 impl_from_row_for_tuples!(MySql, MySqlRow);
+impl_from_row_for_map!(MySql, MySqlRow);
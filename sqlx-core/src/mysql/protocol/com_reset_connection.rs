@@ -0,0 +1,13 @@
+use crate::io::BufMut;
+use crate::mysql::protocol::{Capabilities, Encode};
+
+// https://dev.mysql.com/doc/internals/en/com-reset-connection.html
+#[derive(Debug)]
+pub struct ComResetConnection;
+
+impl Encode for ComResetConnection {
+    fn encode(&self, buf: &mut Vec<u8>, _: Capabilities) {
+        // COM_RESET_CONNECTION : int<1>
+        buf.put_u8(0x1f);
+    }
+}
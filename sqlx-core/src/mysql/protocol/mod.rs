@@ -12,12 +12,16 @@ pub(crate) use status::Status;
 
 mod com_ping;
 mod com_query;
+mod com_reset_connection;
+mod com_stmt_close;
 mod com_stmt_execute;
 mod com_stmt_prepare;
 mod handshake;
 
 pub(crate) use com_ping::ComPing;
 pub(crate) use com_query::ComQuery;
+pub(crate) use com_reset_connection::ComResetConnection;
+pub(crate) use com_stmt_close::ComStmtClose;
 pub(crate) use com_stmt_execute::{ComStmtExecute, Cursor};
 pub(crate) use com_stmt_prepare::ComStmtPrepare;
 pub(crate) use handshake::Handshake;
@@ -3,6 +3,7 @@ use std::fmt::{self, Debug, Display, Formatter};
 // https://dev.mysql.com/doc/dev/mysql-server/8.0.12/binary__log__types_8h.html
 // https://mariadb.com/kb/en/library/resultset/#field-types
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "offline", derive(serde::Serialize, serde::Deserialize))]
 pub struct TypeId(pub u8);
 
 macro_rules! type_id_consts {
@@ -64,6 +65,9 @@ type_id_consts! {
     pub const TIME: TypeId = TypeId(11);
     pub const DATETIME: TypeId = TypeId(12);
     pub const TIMESTAMP: TypeId = TypeId(7);
+
+    // JSON (MySQL 5.7+; MariaDB reports JSON columns as LONGTEXT)
+    pub const JSON: TypeId = TypeId(245);
 }
 
 impl Default for TypeId {
@@ -1,18 +1,54 @@
-use std::collections::HashMap;
+use std::any::type_name;
 use std::convert::TryFrom;
+use std::str::from_utf8;
 use std::sync::Arc;
 
+use byteorder::LittleEndian;
+
+use crate::decode::Decode;
 use crate::error::UnexpectedNullError;
+use crate::io::Buf;
 use crate::mysql::protocol;
-use crate::mysql::MySql;
-use crate::row::{ColumnIndex, Row};
+use crate::mysql::types::check_int_range;
+use crate::mysql::{MySql, MySqlTypeInfo};
+use crate::row::{decode_column, Column, ColumnIndex, ColumnNames, Row};
+use crate::types::Type;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum MySqlValue<'c> {
     Binary(&'c [u8]),
     Text(&'c [u8]),
 }
 
+impl<'c> MySqlValue<'c> {
+    // Reads the value as the 1-based index of an `ENUM` value, either as an integer in the
+    // binary protocol or as text; used by `#[derive(Type)]` with `mysql_enum = "index"`
+    #[doc(hidden)]
+    pub fn enum_index(self) -> crate::Result<u16> {
+        let index = match self {
+            MySqlValue::Binary(mut buf) => match buf.len() {
+                1 => buf.get_u8()? as u64,
+                2 => buf.get_u16::<LittleEndian>()? as u64,
+                4 => buf.get_u32::<LittleEndian>()? as u64,
+                8 => buf.get_u64::<LittleEndian>()?,
+
+                len => {
+                    return Err(crate::Error::Decode(
+                        format!("expected an ENUM index, got {} bytes", len).into(),
+                    ));
+                }
+            },
+
+            MySqlValue::Text(buf) => from_utf8(buf)
+                .map_err(crate::Error::decode)?
+                .parse()
+                .map_err(crate::Error::decode)?,
+        };
+
+        u16::try_from(index).map_err(crate::Error::decode)
+    }
+}
+
 impl<'c> TryFrom<Option<MySqlValue<'c>>> for MySqlValue<'c> {
     type Error = crate::Error;
 
@@ -25,9 +61,57 @@ impl<'c> TryFrom<Option<MySqlValue<'c>>> for MySqlValue<'c> {
     }
 }
 
+/// A raw value from a [`MySqlRow`] along with the type of its column.
+///
+/// Returned by [`MySqlRow::try_get_raw_value`] to allow decoding values of types that
+/// SQLx does not support (yet) by hand.
+#[derive(Debug)]
+pub struct MySqlRawValue<'c> {
+    value: Option<MySqlValue<'c>>,
+    type_info: MySqlTypeInfo,
+}
+
+impl<'c> MySqlRawValue<'c> {
+    /// Returns the value, or `None` if it is `NULL`.
+    ///
+    /// The value is in the binary protocol for prepared queries and in the text protocol
+    /// for unprepared (simple) queries.
+    pub fn value(&self) -> Option<MySqlValue<'c>> {
+        self.value
+    }
+
+    /// Returns `true` if the value is `NULL`.
+    pub fn is_null(&self) -> bool {
+        self.value.is_none()
+    }
+
+    /// Returns the type of the column the value was read from.
+    pub fn type_info(&self) -> &MySqlTypeInfo {
+        &self.type_info
+    }
+}
+
 pub struct MySqlRow<'c> {
     pub(super) row: protocol::Row<'c>,
-    pub(super) columns: Arc<HashMap<Box<str>, u16>>,
+    pub(super) columns: Arc<ColumnNames>,
+    pub(super) types: Arc<[MySqlTypeInfo]>,
+}
+
+impl<'c> MySqlRow<'c> {
+    /// Returns the raw value at `index` along with the type of its column,
+    /// without attempting to decode it.
+    pub fn try_get_raw_value<'r, I>(&'r self, index: I) -> crate::Result<MySqlRawValue<'r>>
+    where
+        'c: 'r,
+        I: ColumnIndex<MySql>,
+    {
+        let index = index.resolve(self)?;
+
+        Ok(MySqlRawValue {
+            value: self.try_get_raw(index)?,
+            type_info: self.types[index].clone(),
+        })
+    }
 }
 
 impl<'c> Row<'c> for MySqlRow<'c> {
@@ -37,6 +121,36 @@ impl<'c> Row<'c> for MySqlRow<'c> {
         self.row.len()
     }
 
+    fn column_name(&self, index: usize) -> Option<&str> {
+        self.columns.name(index)
+    }
+
+    fn column(&self, index: usize) -> Option<Column<'_, Self::Database>> {
+        let type_info = self.types.get(index)?;
+
+        Some(Column {
+            ordinal: index,
+            name: self.column_name(index),
+            type_info: Some(type_info.clone()),
+        })
+    }
+
+    fn try_get<'r, T, I>(&'r self, index: I) -> crate::Result<T>
+    where
+        'c: 'r,
+        T: Type<Self::Database>,
+        I: ColumnIndex<Self::Database>,
+        T: Decode<'r, Self::Database>,
+    {
+        let index = index.resolve(self)?;
+        let value = self.try_get_raw(index)?;
+        let found = &self.types[index];
+
+        check_int_range(value, &T::type_info(), found, type_name::<T>())?;
+
+        decode_column(value, found)
+    }
+
     fn try_get_raw<'r, I>(&'r self, index: I) -> crate::Result<Option<MySqlValue<'r>>>
     where
         'c: 'r,
@@ -53,3 +167,21 @@ impl<'c> Row<'c> for MySqlRow<'c> {
         }))
     }
 }
+
+#[test]
+fn test_enum_index() {
+    assert_eq!(MySqlValue::Binary(&[2]).enum_index().unwrap(), 2);
+    assert_eq!(MySqlValue::Binary(&[3, 0]).enum_index().unwrap(), 3);
+    assert_eq!(MySqlValue::Binary(&[1, 1, 0, 0]).enum_index().unwrap(), 257);
+    assert_eq!(
+        MySqlValue::Binary(&[4, 0, 0, 0, 0, 0, 0, 0])
+            .enum_index()
+            .unwrap(),
+        4
+    );
+    assert_eq!(MySqlValue::Text(b"12").enum_index().unwrap(), 12);
+
+    assert!(MySqlValue::Binary(&[1, 0, 0]).enum_index().is_err());
+    assert!(MySqlValue::Binary(&[0, 0, 1, 0]).enum_index().is_err());
+    assert!(MySqlValue::Text(b"small").enum_index().is_err());
+}
@@ -0,0 +1,122 @@
+use std::borrow::Cow;
+use std::str::from_utf8;
+
+use serde::ser::{Error as _, SerializeMap};
+use serde::{Serialize, Serializer};
+
+use crate::decode::Decode;
+use crate::mysql::protocol::TypeId;
+use crate::mysql::{MySql, MySqlRow, MySqlValue};
+use crate::row::Row;
+
+/// Serializes the row as a map from column name to value, for quickly turning rows into JSON.
+///
+/// Values are mapped by the type of their column: integers and floats as numbers, text as
+/// strings, binary strings (`BINARY`, `BLOB`, etc.) as base64 strings and `NULL` as `null`.
+/// A value of any other type is serialized as its text, except for dates and times received
+/// in the binary protocol of prepared queries, which are formatted with the `chrono` feature
+/// and serialized as base64 strings otherwise. A column without a name is keyed by its index.
+impl Serialize for MySqlRow<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+
+        for index in 0..self.len() {
+            let name = self
+                .column_name(index)
+                .map_or_else(|| Cow::Owned(index.to_string()), Cow::Borrowed);
+
+            map.serialize_entry(&name, &Column { row: self, index })?;
+        }
+
+        map.end()
+    }
+}
+
+struct Column<'a, 'c> {
+    row: &'a MySqlRow<'c>,
+    index: usize,
+}
+
+impl Serialize for Column<'_, '_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let value = match self.row.try_get_raw(self.index).map_err(S::Error::custom)? {
+            Some(value) => value,
+            None => return serializer.serialize_none(),
+        };
+
+        let type_info = &self.row.types[self.index];
+
+        match (type_info.id, type_info.is_unsigned) {
+            (TypeId::TINY_INT, false) => serializer.serialize_i8(decode::<_, S::Error>(value)?),
+            (TypeId::SMALL_INT, false) => serializer.serialize_i16(decode::<_, S::Error>(value)?),
+            (TypeId::INT, false) => serializer.serialize_i32(decode::<_, S::Error>(value)?),
+            (TypeId::BIG_INT, false) => serializer.serialize_i64(decode::<_, S::Error>(value)?),
+
+            (TypeId::TINY_INT, true) => serializer.serialize_u8(decode::<_, S::Error>(value)?),
+            (TypeId::SMALL_INT, true) => serializer.serialize_u16(decode::<_, S::Error>(value)?),
+            (TypeId::INT, true) => serializer.serialize_u32(decode::<_, S::Error>(value)?),
+            (TypeId::BIG_INT, true) => serializer.serialize_u64(decode::<_, S::Error>(value)?),
+
+            (TypeId::FLOAT, _) => serializer.serialize_f32(decode::<_, S::Error>(value)?),
+            (TypeId::DOUBLE, _) => serializer.serialize_f64(decode::<_, S::Error>(value)?),
+
+            #[cfg(feature = "chrono")]
+            (TypeId::DATE, _) if is_binary_protocol(value) => {
+                serializer.collect_str(&decode::<chrono::NaiveDate, S::Error>(value)?)
+            }
+
+            #[cfg(feature = "chrono")]
+            (TypeId::DATETIME, _) | (TypeId::TIMESTAMP, _) if is_binary_protocol(value) => {
+                serializer.collect_str(&decode::<chrono::NaiveDateTime, S::Error>(value)?)
+            }
+
+            _ => {
+                let buf = match value {
+                    MySqlValue::Binary(buf) | MySqlValue::Text(buf) => buf,
+                };
+
+                // binary strings are string types with the `binary` character set
+                let is_binary_string = type_info.char_set == 63
+                    && matches!(
+                        type_info.id,
+                        TypeId::CHAR
+                            | TypeId::VAR_CHAR
+                            | TypeId::TEXT
+                            | TypeId::TINY_BLOB
+                            | TypeId::MEDIUM_BLOB
+                            | TypeId::LONG_BLOB
+                    );
+
+                let text = if is_binary_string {
+                    None
+                } else {
+                    from_utf8(buf).ok()
+                };
+
+                match text {
+                    Some(text) => serializer.serialize_str(text),
+                    None => serializer.serialize_str(&base64::encode(buf)),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+fn is_binary_protocol(value: MySqlValue<'_>) -> bool {
+    matches!(value, MySqlValue::Binary(_))
+}
+
+fn decode<'de, T, E>(value: MySqlValue<'de>) -> Result<T, E>
+where
+    T: Decode<'de, MySql>,
+    E: serde::ser::Error,
+{
+    T::decode(Some(value)).map_err(E::custom)
+}
@@ -40,6 +40,7 @@ impl MySqlStream {
             | Capabilities::PLUGIN_AUTH_LENENC_DATA
             | Capabilities::MULTI_STATEMENTS
             | Capabilities::MULTI_RESULTS
+            | Capabilities::PS_MULTI_RESULTS
             | Capabilities::PLUGIN_AUTH;
 
         if url.database().is_some() {
@@ -2,7 +2,7 @@ use byteorder::LittleEndian;
 
 use crate::decode::Decode;
 use crate::encode::Encode;
-use crate::mysql::io::BufMutExt;
+use crate::mysql::io::{uint_lenenc_size, BufMutExt};
 use crate::mysql::protocol::TypeId;
 use crate::mysql::types::MySqlTypeInfo;
 use crate::mysql::{MySql, MySqlValue};
@@ -30,12 +30,20 @@ impl Encode<MySql> for [u8] {
     fn encode(&self, buf: &mut Vec<u8>) {
         buf.put_bytes_lenenc::<LittleEndian>(self);
     }
+
+    fn size_hint(&self) -> usize {
+        uint_lenenc_size(self.len() as u64) + self.len()
+    }
 }
 
 impl Encode<MySql> for Vec<u8> {
     fn encode(&self, buf: &mut Vec<u8>) {
         <[u8] as Encode<MySql>>::encode(self, buf);
     }
+
+    fn size_hint(&self) -> usize {
+        <[u8] as Encode<MySql>>::size_hint(self)
+    }
 }
 
 impl<'de> Decode<'de, MySql> for Vec<u8> {
@@ -53,3 +61,85 @@ impl<'de> Decode<'de, MySql> for &'de [u8] {
         }
     }
 }
+
+/// A fixed number of bytes, such as a hash stored in a `BINARY(N)` column.
+///
+/// Decoding checks that the value is exactly `N` bytes long. Note that MySQL pads values
+/// shorter than the width of a `BINARY` column with `0x00` and returns the padding, as it
+/// cannot be told apart from data; values of `BINARY` columns are therefore always as long as
+/// the column is wide.
+///
+/// For the same reason, the padding is not stripped when decoding a `Vec<u8>` (or any other
+/// byte type) either: `BINARY` and `VARBINARY` columns are decoded alike, without looking at
+/// the column type, and values of both are returned exactly as MySQL sends them.
+impl<const N: usize> Type<MySql> for [u8; N] {
+    fn type_info() -> MySqlTypeInfo {
+        <[u8] as Type<MySql>>::type_info()
+    }
+}
+
+impl<const N: usize> Encode<MySql> for [u8; N] {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        <[u8] as Encode<MySql>>::encode(self, buf);
+    }
+
+    fn size_hint(&self) -> usize {
+        <[u8] as Encode<MySql>>::size_hint(self)
+    }
+}
+
+impl<'de, const N: usize> Decode<'de, MySql> for [u8; N] {
+    fn decode(value: Option<MySqlValue<'de>>) -> crate::Result<Self> {
+        let buf = <&[u8] as Decode<MySql>>::decode(value)?;
+
+        buf.try_into().map_err(|_| {
+            crate::Error::Decode(format!("expected {} bytes, got {}", N, buf.len()).into())
+        })
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl Type<MySql> for ::bytes::Bytes {
+    fn type_info() -> MySqlTypeInfo {
+        <[u8] as Type<MySql>>::type_info()
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl Encode<MySql> for ::bytes::Bytes {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        <[u8] as Encode<MySql>>::encode(self, buf);
+    }
+
+    fn size_hint(&self) -> usize {
+        <[u8] as Encode<MySql>>::size_hint(self)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<'de> Decode<'de, MySql> for ::bytes::Bytes {
+    fn decode(value: Option<MySqlValue<'de>>) -> crate::Result<Self> {
+        <&[u8] as Decode<MySql>>::decode(value).map(::bytes::Bytes::copy_from_slice)
+    }
+}
+
+#[test]
+fn test_decode_fixed_size_bytes() {
+    let value = [0xDE_u8, 0xAD, 0xBE, 0xEF];
+
+    let mut buf = Vec::new();
+    Encode::<MySql>::encode(&value, &mut buf);
+    assert_eq!(buf, [4, 0xDE, 0xAD, 0xBE, 0xEF]);
+
+    let decode = |buf| <[u8; 4] as Decode<MySql>>::decode(Some(MySqlValue::Binary(buf)));
+
+    assert_eq!(decode(&value).unwrap(), value);
+
+    // trailing zeroes are data, not padding to be removed
+    assert_eq!(decode(&[1, 0, 0, 0]).unwrap(), [1, 0, 0, 0]);
+
+    let err = decode(&[1, 2, 3]).unwrap_err().to_string();
+    assert_eq!(err, "expected 4 bytes, got 3");
+
+    assert!(decode(&[1, 2, 3, 4, 5]).is_err());
+}
@@ -23,6 +23,10 @@ impl Encode<MySql> for DateTime<Utc> {
     fn encode(&self, buf: &mut Vec<u8>) {
         Encode::<MySql>::encode(&self.naive_utc(), buf);
     }
+
+    fn size_hint(&self) -> usize {
+        Encode::<MySql>::size_hint(&self.naive_utc())
+    }
 }
 
 impl<'de> Decode<'de, MySql> for DateTime<Utc> {
@@ -0,0 +1,217 @@
+use std::convert::TryInto;
+use std::str::from_utf8;
+use std::time::Duration;
+
+use byteorder::LittleEndian;
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::io::{Buf, BufMut};
+use crate::mysql::protocol::TypeId;
+use crate::mysql::types::MySqlTypeInfo;
+use crate::mysql::{MySql, MySqlValue};
+use crate::types::Type;
+use crate::Error;
+
+// TIME ranges from '-838:59:59.000000' to '838:59:59.000000'
+// https://dev.mysql.com/doc/refman/8.0/en/time.html
+const MAX_SECS: u64 = 838 * 3600 + 59 * 60 + 59;
+
+/// The equivalent MySQL type for `std::time::Duration` is `TIME`.
+///
+/// ### Note
+/// MySQL `TIME` is limited to 838:59:59; encoding a longer `Duration` panics. As `Duration`
+/// cannot be negative, decoding a negative `TIME` is an error.
+impl Type<MySql> for Duration {
+    fn type_info() -> MySqlTypeInfo {
+        MySqlTypeInfo::new(TypeId::TIME)
+    }
+}
+
+impl Encode<MySql> for Duration {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        let secs = self.as_secs();
+
+        if secs > MAX_SECS {
+            panic!("Duration out of range for MySQL TIME: {:?}", self);
+        }
+
+        let len = Encode::<MySql>::size_hint(self) - 1;
+        buf.push(len as u8);
+
+        if len == 0 {
+            return;
+        }
+
+        // Duration is not negative
+        buf.push(0);
+
+        // days : int<4>
+        buf.put_u32::<LittleEndian>((secs / 86400) as u32);
+
+        buf.push((secs / 3600 % 24) as u8);
+        buf.push((secs / 60 % 60) as u8);
+        buf.push((secs % 60) as u8);
+
+        if len > 8 {
+            buf.put_u32::<LittleEndian>(self.subsec_micros());
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        match (self.as_secs(), self.subsec_micros()) {
+            // if all fields are 0, length is 0 and no other field is sent
+            (0, 0) => 1,
+
+            // if micro_seconds is 0, length is 8 and micro_seconds is not sent
+            (_, 0) => 9,
+
+            // otherwise length is 12
+            (_, _) => 13,
+        }
+    }
+}
+
+impl<'de> Decode<'de, MySql> for Duration {
+    fn decode(value: Option<MySqlValue<'de>>) -> crate::Result<Self> {
+        match value.try_into()? {
+            MySqlValue::Binary(mut buf) => {
+                // data length, expecting 0, 8 or 12 (fractional seconds)
+                let len = buf.get_u8()?;
+
+                if len == 0 {
+                    return Ok(Duration::from_secs(0));
+                }
+
+                // is negative : int<1>
+                if buf.get_u8()? != 0 {
+                    return Err(Error::Decode(
+                        "negative TIME cannot be decoded as Duration".into(),
+                    ));
+                }
+
+                let days = buf.get_u32::<LittleEndian>()? as u64;
+                let hours = buf.get_u8()? as u64;
+                let minutes = buf.get_u8()? as u64;
+                let seconds = buf.get_u8()? as u64;
+
+                let micros = if len > 8 {
+                    buf.get_u32::<LittleEndian>()?
+                } else {
+                    0
+                };
+
+                Ok(Duration::new(
+                    days * 86400 + hours * 3600 + minutes * 60 + seconds,
+                    micros * 1000,
+                ))
+            }
+
+            MySqlValue::Text(buf) => {
+                let s = from_utf8(buf).map_err(Error::decode)?;
+                decode_text(s)
+            }
+        }
+    }
+}
+
+// parses `HHH:MM:SS[.ffffff]`
+fn decode_text(s: &str) -> crate::Result<Duration> {
+    if s.starts_with('-') {
+        return Err(Error::Decode(
+            format!("negative TIME {:?} cannot be decoded as Duration", s).into(),
+        ));
+    }
+
+    let invalid = || Error::Decode(format!("invalid TIME: {:?}", s).into());
+
+    let mut parts = s.splitn(3, ':');
+    let (hours, minutes, seconds) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(hours), Some(minutes), Some(seconds)) => (hours, minutes, seconds),
+        _ => return Err(invalid()),
+    };
+
+    let (seconds, fraction) = match seconds.find('.') {
+        Some(i) => (&seconds[..i], &seconds[i + 1..]),
+        None => (seconds, ""),
+    };
+
+    if fraction.len() > 6 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+
+    let hours: u64 = hours.parse().map_err(|_| invalid())?;
+    let minutes: u64 = minutes.parse().map_err(|_| invalid())?;
+    let seconds: u64 = seconds.parse().map_err(|_| invalid())?;
+
+    // the fraction holds up to 6 digits; pad it out to microseconds
+    let micros = fraction
+        .bytes()
+        .chain(std::iter::repeat(b'0'))
+        .take(6)
+        .fold(0, |micros, b| micros * 10 + (b - b'0') as u32);
+
+    Ok(Duration::new(
+        hours * 3600 + minutes * 60 + seconds,
+        micros * 1000,
+    ))
+}
+
+#[test]
+fn test_encode_duration() {
+    let mut buf = Vec::new();
+
+    Encode::<MySql>::encode(&Duration::from_secs(0), &mut buf);
+    assert_eq!(*buf, [0]);
+
+    buf.clear();
+
+    // 120:25:10
+    Encode::<MySql>::encode(&Duration::from_secs(120 * 3600 + 25 * 60 + 10), &mut buf);
+    assert_eq!(*buf, [8, 0, 5, 0, 0, 0, 0, 25, 10]);
+
+    buf.clear();
+
+    Encode::<MySql>::encode(&Duration::from_micros(1_500_000), &mut buf);
+    assert_eq!(*buf, [12, 0, 0, 0, 0, 0, 0, 0, 1, 32, 161, 7, 0]);
+}
+
+#[test]
+fn test_decode_duration() {
+    let decode = |buf: &[u8]| <Duration as Decode<MySql>>::decode(Some(MySqlValue::Binary(buf)));
+
+    assert_eq!(decode(&[0]).unwrap(), Duration::from_secs(0));
+
+    assert_eq!(
+        decode(&[8, 0, 34, 0, 0, 0, 22, 59, 59]).unwrap(),
+        Duration::from_secs(MAX_SECS)
+    );
+
+    assert_eq!(
+        decode(&[12, 0, 0, 0, 0, 0, 0, 0, 1, 32, 161, 7, 0]).unwrap(),
+        Duration::from_micros(1_500_000)
+    );
+
+    assert!(decode(&[8, 1, 0, 0, 0, 0, 1, 0, 0]).is_err());
+}
+
+#[test]
+fn test_decode_duration_text() {
+    assert_eq!(decode_text("00:00:00").unwrap(), Duration::from_secs(0));
+    assert_eq!(
+        decode_text("838:59:59").unwrap(),
+        Duration::from_secs(MAX_SECS)
+    );
+    assert_eq!(
+        decode_text("00:00:01.5").unwrap(),
+        Duration::from_micros(1_500_000)
+    );
+    assert_eq!(
+        decode_text("12:00:00.000001").unwrap(),
+        Duration::new(12 * 3600, 1000)
+    );
+
+    assert!(decode_text("-01:00:00").is_err());
+    assert!(decode_text("01:00").is_err());
+    assert!(decode_text("01:00:00.1234567").is_err());
+}
@@ -0,0 +1,129 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::ops::Deref;
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::mysql::protocol::TypeId;
+use crate::mysql::types::MySqlTypeInfo;
+use crate::mysql::{MySql, MySqlValue};
+use crate::types::Type;
+use crate::Error;
+
+/// An IP address in the binary form produced by `INET6_ATON`, as stored in a
+/// `VARBINARY(16)` column.
+///
+/// An IPv4 address is 4 bytes and an IPv6 address is 16 bytes, both in network byte order.
+/// MySQL has no type for IP addresses, so this wrapper opts in to reading a binary string
+/// as an address; a plain `IpAddr` is not accepted in its place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MySqlInet(pub IpAddr);
+
+impl MySqlInet {
+    /// Consumes the `MySqlInet` wrapper and returns the IP address.
+    pub fn into_inner(self) -> IpAddr {
+        self.0
+    }
+}
+
+impl From<IpAddr> for MySqlInet {
+    fn from(addr: IpAddr) -> Self {
+        MySqlInet(addr)
+    }
+}
+
+impl Deref for MySqlInet {
+    type Target = IpAddr;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Type<MySql> for MySqlInet {
+    fn type_info() -> MySqlTypeInfo {
+        MySqlTypeInfo {
+            id: TypeId::VAR_CHAR,
+            is_binary: true,
+            is_unsigned: false,
+            char_set: 63, // binary
+        }
+    }
+}
+
+impl Encode<MySql> for MySqlInet {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self.0 {
+            IpAddr::V4(addr) => <[u8] as Encode<MySql>>::encode(&addr.octets(), buf),
+            IpAddr::V6(addr) => <[u8] as Encode<MySql>>::encode(&addr.octets(), buf),
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        match self.0 {
+            IpAddr::V4(_) => 1 + 4,
+            IpAddr::V6(_) => 1 + 16,
+        }
+    }
+}
+
+impl<'de> Decode<'de, MySql> for MySqlInet {
+    fn decode(value: Option<MySqlValue<'de>>) -> crate::Result<Self> {
+        let buf = <&[u8] as Decode<MySql>>::decode(value)?;
+
+        let addr = match buf.len() {
+            4 => {
+                let mut octets = [0; 4];
+                octets.copy_from_slice(buf);
+
+                IpAddr::V4(Ipv4Addr::from(octets))
+            }
+
+            16 => {
+                let mut octets = [0; 16];
+                octets.copy_from_slice(buf);
+
+                IpAddr::V6(Ipv6Addr::from(octets))
+            }
+
+            len => {
+                return Err(Error::Decode(
+                    format!("expected an IP address of 4 or 16 bytes, got {} bytes", len).into(),
+                ));
+            }
+        };
+
+        Ok(MySqlInet(addr))
+    }
+}
+
+#[test]
+fn test_encode_inet() {
+    let mut buf = Vec::new();
+    MySqlInet("192.168.0.1".parse().unwrap()).encode(&mut buf);
+    assert_eq!(buf, [4, 192, 168, 0, 1]);
+
+    let mut buf = Vec::new();
+    MySqlInet("::1".parse().unwrap()).encode(&mut buf);
+    assert_eq!(buf.len(), 1 + 16);
+    assert_eq!(buf[0], 16);
+    assert_eq!(buf[16], 1);
+}
+
+#[test]
+fn test_decode_inet() {
+    let decode = |buf| MySqlInet::decode(Some(MySqlValue::Binary(buf)));
+
+    assert_eq!(
+        decode(&[10, 0, 0, 1]).unwrap(),
+        MySqlInet("10.0.0.1".parse().unwrap())
+    );
+
+    let mut v6 = [0; 16];
+    v6[0] = 0xfe;
+    v6[1] = 0x80;
+    v6[15] = 1;
+    assert_eq!(decode(&v6).unwrap(), MySqlInet("fe80::1".parse().unwrap()));
+
+    let err = decode(&[1, 2, 3]).unwrap_err().to_string();
+    assert_eq!(err, "expected an IP address of 4 or 16 bytes, got 3 bytes");
+}
@@ -110,3 +110,123 @@ impl<'de> Decode<'de, MySql> for i64 {
         }
     }
 }
+
+// The range of values of an integer type, or `None` if it is not an integer type
+fn int_range(ty: &MySqlTypeInfo) -> Option<(i128, i128)> {
+    let bits = match ty.id {
+        TypeId::TINY_INT => 8,
+        TypeId::SMALL_INT => 16,
+        TypeId::INT => 32,
+        TypeId::BIG_INT => 64,
+
+        _ => return None,
+    };
+
+    Some(if ty.is_unsigned {
+        (0, (1 << bits) - 1)
+    } else {
+        (-(1 << (bits - 1)), (1 << (bits - 1)) - 1)
+    })
+}
+
+fn read_int(value: MySqlValue<'_>, ty: &MySqlTypeInfo) -> Option<i128> {
+    match value {
+        MySqlValue::Binary(mut buf) => Some(match (buf.len(), ty.is_unsigned) {
+            (1, false) => buf.read_i8().ok()?.into(),
+            (1, true) => buf.read_u8().ok()?.into(),
+            (2, false) => buf.read_i16::<LittleEndian>().ok()?.into(),
+            (2, true) => buf.read_u16::<LittleEndian>().ok()?.into(),
+            (4, false) => buf.read_i32::<LittleEndian>().ok()?.into(),
+            (4, true) => buf.read_u32::<LittleEndian>().ok()?.into(),
+            (8, false) => buf.read_i64::<LittleEndian>().ok()?.into(),
+            (8, true) => buf.read_u64::<LittleEndian>().ok()?.into(),
+
+            _ => return None,
+        }),
+
+        MySqlValue::Text(s) => from_utf8(s).ok()?.parse().ok(),
+    }
+}
+
+// Integers are sent in the width of their column and only the column definition says whether
+// they are unsigned, so decoding one into a narrower or differently signed Rust type would
+// silently truncate or wrap it. Values are checked against the range of the Rust type first.
+pub(crate) fn check_int_range(
+    value: Option<MySqlValue<'_>>,
+    expected: &MySqlTypeInfo,
+    found: &MySqlTypeInfo,
+    rust_type: &str,
+) -> crate::Result<()> {
+    let (value, (min, max)) = match (value, int_range(expected), int_range(found)) {
+        (Some(value), Some(range), Some(_)) => match read_int(value, found) {
+            Some(value) => (value, range),
+            None => return Ok(()),
+        },
+
+        _ => return Ok(()),
+    };
+
+    if value < min || value > max {
+        return Err(Error::Decode(
+            format!(
+                "{}{} value {} is out of range for `{}`",
+                found.id.type_name(),
+                if found.is_unsigned { " UNSIGNED" } else { "" },
+                value,
+                rust_type
+            )
+            .into(),
+        ));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_check_int_range() {
+    let int = MySqlTypeInfo::new(TypeId::INT);
+    let bigint = MySqlTypeInfo::new(TypeId::BIG_INT);
+    let bigint_unsigned = MySqlTypeInfo::unsigned(TypeId::BIG_INT);
+
+    let big = (i64::from(i32::MAX) + 1).to_le_bytes();
+    let max = u64::MAX.to_le_bytes();
+
+    assert!(check_int_range(Some(MySqlValue::Binary(&big)), &int, &bigint, "i32").is_err());
+    assert!(check_int_range(Some(MySqlValue::Binary(&big)), &bigint, &bigint, "i64").is_ok());
+
+    let err = check_int_range(
+        Some(MySqlValue::Binary(&max)),
+        &bigint,
+        &bigint_unsigned,
+        "i64",
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "BIG_INT UNSIGNED value 18446744073709551615 is out of range for `i64`"
+    );
+
+    assert!(check_int_range(
+        Some(MySqlValue::Binary(&max)),
+        &bigint_unsigned,
+        &bigint_unsigned,
+        "u64"
+    )
+    .is_ok());
+
+    // values that fit are fine, whatever their column type
+    let small = 42_u64.to_le_bytes();
+
+    assert!(check_int_range(
+        Some(MySqlValue::Binary(&small)),
+        &int,
+        &bigint_unsigned,
+        "i32"
+    )
+    .is_ok());
+
+    assert!(check_int_range(Some(MySqlValue::Text(b"-2147483649")), &int, &bigint, "i32").is_err());
+
+    assert!(check_int_range(None, &int, &bigint, "i32").is_ok());
+}
@@ -0,0 +1,95 @@
+use std::convert::TryInto;
+
+use byteorder::LittleEndian;
+use serde::{Deserialize, Serialize};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::mysql::io::BufMutExt;
+use crate::mysql::protocol::TypeId;
+use crate::mysql::types::MySqlTypeInfo;
+use crate::mysql::{MySql, MySqlValue};
+use crate::types::{Json, JsonValue, Type};
+use crate::Error;
+
+impl Type<MySql> for JsonValue {
+    fn type_info() -> MySqlTypeInfo {
+        <Json<Self> as Type<MySql>>::type_info()
+    }
+}
+
+impl Encode<MySql> for JsonValue {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        <Json<&Self> as Encode<MySql>>::encode(&Json(self), buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        <Json<&Self> as Encode<MySql>>::size_hint(&Json(self))
+    }
+}
+
+impl<'de> Decode<'de, MySql> for JsonValue {
+    fn decode(value: Option<MySqlValue<'de>>) -> crate::Result<Self> {
+        <Json<Self> as Decode<MySql>>::decode(value).map(|item| item.0)
+    }
+}
+
+impl<T> Type<MySql> for Json<T> {
+    fn type_info() -> MySqlTypeInfo {
+        // the server reports JSON columns as binary, although their values are UTF-8 text
+        MySqlTypeInfo {
+            id: TypeId::JSON,
+            is_binary: true,
+            is_unsigned: false,
+            char_set: 63, // binary
+        }
+    }
+}
+
+impl<T> Encode<MySql> for Json<T>
+where
+    T: Serialize,
+{
+    fn encode(&self, buf: &mut Vec<u8>) {
+        let json = serde_json::to_string(&self.0)
+            .expect("failed to serialize json for encoding to database");
+
+        buf.put_str_lenenc::<LittleEndian>(&json);
+    }
+
+    fn size_hint(&self) -> usize {
+        // the serialized length is not known up front; this is the length prefix
+        // followed by the shortest possible JSON value
+        2
+    }
+}
+
+impl<'de, T> Decode<'de, MySql> for Json<T>
+where
+    T: 'de,
+    T: Deserialize<'de>,
+{
+    fn decode(value: Option<MySqlValue<'de>>) -> crate::Result<Self> {
+        // JSON is stored in an internal binary format but the server converts it to its text
+        // for clients in both protocols
+        match value.try_into()? {
+            MySqlValue::Binary(buf) | MySqlValue::Text(buf) => {
+                serde_json::from_slice(buf).map(Json).map_err(Error::decode)
+            }
+        }
+    }
+}
+
+#[test]
+fn test_decode_json_from_both_protocols() {
+    let buf = br#"{"name":"Joe","tags":["a","b"],"address":{"city":"Paris"}}"#;
+
+    for value in [MySqlValue::Text(buf), MySqlValue::Binary(buf)] {
+        let json = <JsonValue as Decode<MySql>>::decode(Some(value)).unwrap();
+
+        assert_eq!(json["address"]["city"], "Paris");
+        assert_eq!(json["tags"][1], "b");
+    }
+
+    assert!(<JsonValue as Decode<MySql>>::decode(Some(MySqlValue::Text(b"{"))).is_err());
+}
@@ -1,6 +1,8 @@
 mod bool;
 mod bytes;
+mod duration;
 mod float;
+mod inet;
 mod int;
 mod str;
 mod uint;
@@ -8,6 +10,12 @@ mod uint;
 #[cfg(feature = "chrono")]
 mod chrono;
 
+#[cfg(feature = "json")]
+mod json;
+
+pub use inet::MySqlInet;
+pub(crate) use int::check_int_range;
+
 use std::fmt::{self, Debug, Display};
 
 use crate::decode::Decode;
@@ -17,6 +25,7 @@ use crate::mysql::{MySql, MySqlValue};
 use crate::types::TypeInfo;
 
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "offline", derive(serde::Serialize, serde::Deserialize))]
 pub struct MySqlTypeInfo {
     pub(crate) id: TypeId,
     pub(crate) is_unsigned: bool,
@@ -65,6 +74,7 @@ impl MySqlTypeInfo {
     pub fn type_feature_gate(&self) -> Option<&'static str> {
         match self.id {
             TypeId::DATE | TypeId::TIME | TypeId::DATETIME | TypeId::TIMESTAMP => Some("chrono"),
+            TypeId::JSON => Some("json"),
             _ => None,
         }
     }
@@ -81,6 +91,10 @@ impl Display for MySqlTypeInfo {
 }
 
 impl TypeInfo for MySqlTypeInfo {
+    fn name(&self) -> &str {
+        self.id.type_name()
+    }
+
     fn compatible(&self, other: &Self) -> bool {
         match self.id {
             TypeId::VAR_CHAR
@@ -122,3 +136,12 @@ where
             .transpose()
     }
 }
+
+#[test]
+fn test_type_info_name() {
+    use crate::types::Type;
+
+    assert_eq!(<i32 as Type<MySql>>::type_info().name(), "INT");
+    assert_eq!(<str as Type<MySql>>::type_info().name(), "TEXT");
+    assert_eq!(MySqlTypeInfo::new(TypeId(0xff)).name(), "<unknown>");
+}
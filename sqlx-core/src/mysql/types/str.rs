@@ -2,9 +2,9 @@ use std::str;
 
 use byteorder::LittleEndian;
 
-use crate::decode::Decode;
+use crate::decode::{decode_char, Decode};
 use crate::encode::Encode;
-use crate::mysql::io::BufMutExt;
+use crate::mysql::io::{uint_lenenc_size, BufMutExt};
 use crate::mysql::protocol::TypeId;
 use crate::mysql::types::MySqlTypeInfo;
 use crate::mysql::{MySql, MySqlValue};
@@ -27,6 +27,10 @@ impl Encode<MySql> for str {
     fn encode(&self, buf: &mut Vec<u8>) {
         buf.put_str_lenenc::<LittleEndian>(self);
     }
+
+    fn size_hint(&self) -> usize {
+        uint_lenenc_size(self.len() as u64) + self.len()
+    }
 }
 
 impl Type<MySql> for String {
@@ -39,6 +43,10 @@ impl Encode<MySql> for String {
     fn encode(&self, buf: &mut Vec<u8>) {
         <str as Encode<MySql>>::encode(self.as_str(), buf)
     }
+
+    fn size_hint(&self) -> usize {
+        <str as Encode<MySql>>::size_hint(self.as_str())
+    }
 }
 
 impl<'de> Decode<'de, MySql> for &'de str {
@@ -56,3 +64,45 @@ impl<'de> Decode<'de, MySql> for String {
         <&'de str as Decode<MySql>>::decode(buf).map(ToOwned::to_owned)
     }
 }
+
+impl Type<MySql> for char {
+    fn type_info() -> MySqlTypeInfo {
+        MySqlTypeInfo {
+            id: TypeId::CHAR,
+            is_binary: false,
+            is_unsigned: false,
+            char_set: 224, // utf8mb4_unicode_ci
+        }
+    }
+}
+
+impl Encode<MySql> for char {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        <str as Encode<MySql>>::encode(self.encode_utf8(&mut [0; 4]), buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        1 + self.len_utf8()
+    }
+}
+
+impl<'de> Decode<'de, MySql> for char {
+    fn decode(value: Option<MySqlValue<'de>>) -> crate::Result<Self> {
+        <&'de str as Decode<MySql>>::decode(value).and_then(decode_char)
+    }
+}
+
+#[test]
+fn test_decode_str_borrows_from_the_row() {
+    let buf = b"hello, world";
+
+    // both protocols send strings as-is; decoding only validates the UTF-8
+    for value in [MySqlValue::Text(buf), MySqlValue::Binary(buf)] {
+        let s = <&str as Decode<MySql>>::decode(Some(value)).unwrap();
+
+        assert_eq!(s, "hello, world");
+        assert_eq!(s.as_ptr(), buf.as_ptr());
+    }
+
+    assert!(<&str as Decode<MySql>>::decode(Some(MySqlValue::Text(b"\xFF"))).is_err());
+}
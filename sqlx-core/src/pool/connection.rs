@@ -5,7 +5,9 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use super::inner::{DecrementSizeGuard, SharedPool};
+use super::Options;
 use crate::connection::{Connect, Connection};
+use crate::runtime::spawn;
 
 /// A connection checked out from [`Pool`][crate::Pool].
 ///
@@ -90,6 +92,16 @@ where
     fn ping(&mut self) -> BoxFuture<crate::Result<()>> {
         Box::pin(self.deref_mut().ping())
     }
+
+    #[inline]
+    fn reset(&mut self) -> BoxFuture<'_, crate::Result<()>> {
+        Box::pin(self.deref_mut().reset())
+    }
+
+    #[inline]
+    fn reset_with<'c>(&'c mut self, query: &'c str) -> BoxFuture<'c, crate::Result<()>> {
+        self.deref_mut().reset_with(query)
+    }
 }
 
 /// Returns the connection to the [`Pool`][crate::Pool] it was checked-out from.
@@ -99,7 +111,24 @@ where
 {
     fn drop(&mut self) {
         if let Some(live) = self.live.take() {
-            self.pool.release(live.float(&self.pool));
+            if !self.pool.options().reset_on_release {
+                self.pool.release(live.float(&self.pool));
+                return;
+            }
+
+            let pool = Arc::clone(&self.pool);
+
+            spawn(async move {
+                let mut floating = live.float(&pool);
+
+                match floating.reset(pool.options()).await {
+                    Ok(()) => pool.release(floating),
+
+                    // the session is in an unknown state so it can not be handed out again;
+                    // dropping the connection frees up its slot in the pool
+                    Err(e) => log::info!("failed to reset connection on release: {}", e),
+                }
+            });
         }
     }
 }
@@ -170,6 +199,18 @@ impl<'s, C> Floating<'s, Live<C>> {
         }
     }
 
+    pub async fn reset(&mut self, options: &Options) -> crate::Result<()>
+    where
+        C: Connection,
+    {
+        let raw = &mut self.inner.raw;
+
+        match &options.reset_query {
+            Some(query) => raw.reset_with(query).await,
+            None => raw.reset().await,
+        }
+    }
+
     pub fn into_idle(self) -> Floating<'s, Idle<C>> {
         Floating {
             inner: self.inner.into_idle(),
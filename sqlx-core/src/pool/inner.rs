@@ -3,7 +3,7 @@ use std::mem;
 use std::ptr;
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use crossbeam_queue::{ArrayQueue, SegQueue};
 use futures_core::task::{Poll, Waker};
@@ -132,15 +132,19 @@ impl<C> SharedPool<C>
 where
     C: Connect,
 {
-    pub(super) async fn new_arc(url: &str, options: Options) -> crate::Result<Arc<Self>> {
-        let mut pool = Self {
+    fn new(url: &str, options: Options) -> Self {
+        Self {
             url: url.to_owned(),
             idle_conns: ArrayQueue::new(options.max_size as usize),
             waiters: SegQueue::new(),
             size: AtomicU32::new(0),
             is_closed: AtomicBool::new(false),
             options,
-        };
+        }
+    }
+
+    pub(super) async fn new_arc(url: &str, options: Options) -> crate::Result<Arc<Self>> {
+        let mut pool = Self::new(url, options);
 
         pool.init_min_connections().await?;
 
@@ -151,10 +155,21 @@ where
         Ok(pool)
     }
 
+    pub(super) fn new_lazy_arc(url: &str, options: Options) -> Arc<Self> {
+        let pool = Arc::new(Self::new(url, options));
+
+        spawn_reaper(&pool);
+
+        pool
+    }
+
     pub(super) async fn acquire<'s>(&'s self) -> crate::Result<Floating<'s, Live<C>>> {
         let start = Instant::now();
         let deadline = start + self.options.connect_timeout;
 
+        // number of times we have failed to open a new connection
+        let mut reconnects = 0;
+
         // Unless the pool has been closed ...
         while !self.is_closed() {
             // Attempt to immediately acquire a connection. This will return Some
@@ -164,14 +179,41 @@ where
                 if let Some(live) = check_conn(conn, &self.options).await {
                     return Ok(live);
                 }
+
+                // the connection was retired or broken (e.g., the server restarted or closed
+                // the socket); it has been dropped, freeing up its slot, so fall through and
+                // replace it with a fresh connection instead of handing out the dead one
             }
 
             if let Some(guard) = self.try_increment_size() {
                 // pool has slots available; open a new connection
                 match self.connect(deadline, guard).await {
-                    Ok(Some(conn)) => return Ok(conn),
+                    Ok(Ok(conn)) => return Ok(conn),
+
                     // [size] is internally decremented on _retry_ and _error_
-                    Ok(None) => continue,
+                    Ok(Err(error)) => {
+                        // only failed attempts to open a connection are counted; any number of
+                        // broken idle connections are replaced
+                        if self
+                            .options
+                            .reconnect_attempts
+                            .map_or(false, |attempts| reconnects >= attempts)
+                        {
+                            return Err(error);
+                        }
+
+                        // back off so a server that refuses connections outright (e.g., while
+                        // it restarts) is not hammered until `deadline`
+                        sleep(cmp::min(
+                            reconnect_backoff(reconnects),
+                            deadline_as_timeout(deadline)?,
+                        ))
+                        .await;
+
+                        reconnects += 1;
+                        continue;
+                    }
+
                     Err(e) => return Err(e),
                 }
             }
@@ -186,18 +228,39 @@ where
 
     // takes `&mut self` so this can only be called during init
     async fn init_min_connections(&mut self) -> crate::Result<()> {
-        for _ in 0..self.options.min_size {
-            let deadline = Instant::now() + self.options.connect_timeout;
+        // all of the connections must be opened within the timeout
+        let deadline = Instant::now() + self.options.connect_timeout;
 
+        while self.size() < self.options.min_size {
             // this guard will prevent us from exceeding `max_size`
-            while let Some(guard) = self.try_increment_size() {
-                // [connect] will raise an error when past deadline
-                // [connect] returns None if its okay to retry
-                if let Some(conn) = self.connect(deadline, guard).await? {
+            let guard = match self.try_increment_size() {
+                Some(guard) => guard,
+                None => break,
+            };
+
+            // [connect] will raise an error when past deadline
+            // [connect] returns Ok(Err(_)) if its okay to retry
+            match self.connect(deadline, guard).await {
+                Ok(Ok(conn)) => {
                     self.idle_conns
                         .push(conn.into_idle().into_leakable())
                         .expect("BUG: connection queue overflow in init_min_connections");
                 }
+
+                Ok(Err(_)) => {}
+
+                Err(error) if !self.options.require_min_size => {
+                    log::warn!(
+                        "opened {} of {} connections for the pool: {}",
+                        self.size(),
+                        self.options.min_size,
+                        error
+                    );
+
+                    break;
+                }
+
+                Err(error) => return Err(error),
             }
         }
 
@@ -208,7 +271,7 @@ where
         &'s self,
         deadline: Instant,
         guard: DecrementSizeGuard<'s>,
-    ) -> crate::Result<Option<Floating<'s, Live<C>>>> {
+    ) -> crate::Result<Result<Floating<'s, Live<C>>, Error>> {
         if self.is_closed() {
             return Err(Error::PoolClosed);
         }
@@ -218,16 +281,18 @@ where
         // result here is `Result<Result<C, Error>, TimeoutError>`
         match crate::runtime::timeout(timeout, C::connect(&self.url)).await {
             // successfully established connection
-            Ok(Ok(raw)) => Ok(Some(Floating::new_live(raw, guard))),
+            Ok(Ok(raw)) => Ok(Ok(Floating::new_live(raw, guard))),
 
             // an IO error while connecting is assumed to be the system starting up
-            Ok(Err(crate::Error::Io(_))) => Ok(None),
+            Ok(Err(error @ crate::Error::Io(_))) => Ok(Err(error)),
 
             // TODO: Handle other database "boot period"s
 
             // [postgres] the database system is starting up
             // TODO: Make this check actually check if this is postgres
-            Ok(Err(crate::Error::Database(error))) if error.code() == Some("57P03") => Ok(None),
+            Ok(Err(crate::Error::Database(error))) if error.code() == Some("57P03") => {
+                Ok(Err(crate::Error::Database(error)))
+            }
 
             // Any other error while connection should immediately
             // terminate and bubble the error up
@@ -239,6 +304,11 @@ where
     }
 }
 
+// Waits 10ms before the first retry to open a connection, doubling up to 1s
+fn reconnect_backoff(reconnects: u32) -> Duration {
+    Duration::from_millis(10 << cmp::min(reconnects, 7)).min(Duration::from_secs(1))
+}
+
 // NOTE: Function names here are bizzare. Helpful help would be appreciated.
 
 fn is_beyond_lifetime<C>(live: &Live<C>, options: &Options) -> bool {
@@ -270,7 +340,6 @@ where
         let _ = conn.close().await;
         return None;
     } else if options.test_on_acquire {
-        // TODO: Check on acquire should be a configuration setting
         // Check that the connection is still live
         if let Err(e) = conn.ping().await {
             // an error here means the other end has hung up or we lost connectivity
@@ -369,3 +438,12 @@ impl Drop for DecrementSizeGuard<'_> {
         }
     }
 }
+
+#[test]
+fn test_reconnect_backoff() {
+    assert_eq!(reconnect_backoff(0), Duration::from_millis(10));
+    assert_eq!(reconnect_backoff(1), Duration::from_millis(20));
+    assert_eq!(reconnect_backoff(6), Duration::from_millis(640));
+    assert_eq!(reconnect_backoff(7), Duration::from_secs(1));
+    assert_eq!(reconnect_backoff(u32::MAX), Duration::from_secs(1));
+}
@@ -44,6 +44,10 @@ where
         Ok(Pool(inner))
     }
 
+    fn with_options_lazy(url: &str, options: Options) -> Self {
+        Pool(SharedPool::new_lazy_arc(url, options))
+    }
+
     /// Returns a [Builder] to configure a new connection pool.
     pub fn builder() -> Builder<C> {
         Builder::new()
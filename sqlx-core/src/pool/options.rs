@@ -25,7 +25,7 @@ where
                 max_size: 10,
                 // don't open connections until necessary
                 min_size: 0,
-                // try to connect for 10 seconds before erroring
+                // try to connect for 60 seconds before erroring
                 connect_timeout: Duration::from_secs(60),
                 // reap connections that have been alive > 30 minutes
                 // prevents unbounded live-leaking of memory due to naive prepared statement caching
@@ -35,6 +35,13 @@ where
                 idle_timeout: None,
                 // If true, test the health of a connection on acquire
                 test_on_acquire: true,
+                // retry opening a connection on acquire until `connect_timeout` elapses
+                reconnect_attempts: None,
+                // keep the session state (and statement cache) of released connections
+                reset_on_release: false,
+                reset_query: None,
+                // fail to build the pool if `min_size` connections can't be opened
+                require_min_size: true,
             },
         }
     }
@@ -64,6 +71,18 @@ where
         self
     }
 
+    /// If true, [build] returns an error if it cannot open [min_size] connections within
+    /// [connect_timeout].
+    ///
+    /// If false, the pool is built with the connections that could be opened and the error is
+    /// logged instead; further connections are opened as they are acquired.
+    ///
+    /// Defaults to `true`.
+    pub fn require_min_size(mut self, require: bool) -> Self {
+        self.options.require_min_size = require;
+        self
+    }
+
     /// Set the maximum lifetime of individual connections.
     ///
     /// Any connection with a lifetime greater than this will be closed.
@@ -100,6 +119,54 @@ where
         self
     }
 
+    /// Set the number of times [Pool::acquire] retries opening a new connection that failed
+    /// with an error that may be temporary (such as the server refusing connections while it
+    /// restarts) before returning that error.
+    ///
+    /// Retries wait 10ms at first, doubling up to 1s between attempts. By default, retries
+    /// continue until [connect_timeout] elapses; setting a number of attempts makes
+    /// [Pool::acquire] fail sooner while the server is unavailable.
+    ///
+    /// Idle connections found to be broken by the health check enabled by [test_on_acquire]
+    /// are always dropped and replaced, however many there are; this commonly happens after
+    /// the database server restarts or closes idle connections. Connections are only replaced
+    /// before they are handed out, so a query is never retried.
+    ///
+    /// [connect_timeout]: Builder::connect_timeout
+    /// [test_on_acquire]: Builder::test_on_acquire
+    pub fn reconnect_attempts(mut self, attempts: u32) -> Self {
+        self.options.reconnect_attempts = Some(attempts);
+        self
+    }
+
+    /// If true, the session state of a connection is reset when it is released back to the
+    /// pool, with a call to `Connection::reset`.
+    ///
+    /// This rolls back a transaction left open and clears anything the previous user of the
+    /// connection may have changed, such as session variables and temporary tables. It also
+    /// drops the prepared statements cached by the connection, so they are prepared again on
+    /// their next use; see [reset_query] to reset with a query of your own. A connection that
+    /// fails to reset is closed instead of being returned to the pool.
+    ///
+    /// Defaults to `false`.
+    pub fn reset_on_release(mut self, reset: bool) -> Self {
+        self.options.reset_on_release = reset;
+        self
+    }
+
+    /// Set a query to reset the session state of a connection with when it is released back
+    /// to the pool, in place of `Connection::reset`. Implies [reset_on_release].
+    ///
+    /// Any open transaction is rolled back before the query is executed; see
+    /// `Connection::reset_with`. For example, a Postgres pool could use
+    /// `RESET ALL; CLOSE ALL; UNLISTEN *` to keep temporary tables, or a SQLite pool could use
+    /// `PRAGMA foreign_keys = ON` to undo a change to the setting.
+    pub fn reset_query(mut self, query: impl Into<String>) -> Self {
+        self.options.reset_on_release = true;
+        self.options.reset_query = Some(query.into());
+        self
+    }
+
     /// Spin up the connection pool.
     ///
     /// If [min_size] was set to a non-zero value, that many connections will be immediately
@@ -110,6 +177,20 @@ where
     {
         Pool::with_options(url, self.options).await
     }
+
+    /// Create the connection pool without opening any connections.
+    ///
+    /// Connections are opened as they are acquired, so the first acquisitions pay the cost of
+    /// connecting and an unreachable database is only reported by [Pool::acquire]. Once open,
+    /// [min_size] connections are kept as with [build].
+    ///
+    /// This must be called from within the async runtime.
+    pub fn build_lazy(self, url: &str) -> Pool<C>
+    where
+        C: Connect,
+    {
+        Pool::with_options_lazy(url, self.options)
+    }
 }
 
 impl<C, DB> Default for Builder<C>
@@ -130,4 +211,8 @@ pub(crate) struct Options {
     pub max_lifetime: Option<Duration>,
     pub idle_timeout: Option<Duration>,
     pub test_on_acquire: bool,
+    pub reconnect_attempts: Option<u32>,
+    pub reset_on_release: bool,
+    pub reset_query: Option<String>,
+    pub require_min_size: bool,
 }
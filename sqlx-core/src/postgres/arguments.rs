@@ -1,9 +1,11 @@
 use byteorder::{ByteOrder, NetworkEndian};
 
 use crate::arguments::Arguments;
-use crate::encode::{Encode, IsNull};
+use crate::encode::{DynEncode, Encode, IsNull};
 use crate::io::BufMut;
-use crate::postgres::Postgres;
+use crate::postgres::protocol::{TypeFormat, TypeId};
+use crate::postgres::types::SharedStr;
+use crate::postgres::{PgTypeInfo, Postgres};
 use crate::types::Type;
 
 #[derive(Default)]
@@ -11,8 +13,58 @@ pub struct PgArguments {
     // OIDs of the bind parameters
     pub(super) types: Vec<u32>,
 
+    // Names of the bind parameter types that are only known by name, with the index of
+    // their (zero) OID in `types`; resolved by the connection before the query is sent
+    pub(super) unresolved_types: Vec<(usize, SharedStr)>,
+
     // Write buffer for serializing bind values
     pub(super) values: Vec<u8>,
+
+    // Formats of the bind values, in the order they were added
+    pub(super) formats: Vec<TypeFormat>,
+
+    // The first value that could not be encoded, with its index; a NULL was bound in its
+    // place and the query fails with the error before it is sent
+    pub(super) error: Option<(usize, crate::Error)>,
+}
+
+impl PgArguments {
+    /// Returns the number of bound arguments.
+    pub fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    /// Returns `true` if no arguments have been bound.
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty()
+    }
+
+    /// Returns the types of the bound arguments, in the order they were added.
+    ///
+    /// Only the OID of each type is recorded, so user-defined types are not named.
+    pub fn types(&self) -> impl ExactSizeIterator<Item = PgTypeInfo> + '_ {
+        self.types.iter().map(|&oid| PgTypeInfo::with_oid(oid))
+    }
+
+    // Removes the arguments added after the first `len`, whose values ended at `values_len`
+    pub(super) fn truncate(&mut self, len: usize, values_len: usize) {
+        self.types.truncate(len);
+        self.formats.truncate(len);
+        self.unresolved_types.retain(|(index, _)| *index < len);
+        self.values.truncate(values_len);
+
+        if matches!(self.error, Some((index, _)) if index >= len) {
+            self.error = None;
+        }
+    }
+
+    // Returns the error of the first value that could not be encoded, if any
+    pub(super) fn take_error(&mut self) -> crate::Result<()> {
+        match self.error.take() {
+            Some((_, error)) => Err(error),
+            None => Ok(()),
+        }
+    }
 }
 
 impl Arguments for PgArguments {
@@ -20,6 +72,7 @@ impl Arguments for PgArguments {
 
     fn reserve(&mut self, len: usize, size: usize) {
         self.types.reserve(len);
+        self.formats.reserve(len);
         self.values.reserve(size);
     }
 
@@ -28,16 +81,86 @@ impl Arguments for PgArguments {
         T: Type<Self::Database>,
         T: Encode<Self::Database>,
     {
-        // TODO: When/if we receive types that do _not_ support BINARY, we need to check here
-        // TODO: There is no need to be explicit unless we are expecting mixed BINARY / TEXT
+        if let Err(error) = value.check_encode() {
+            return self.push_error(<T as Type<Postgres>>::type_info(), error);
+        }
+
+        self.push(
+            <T as Type<Postgres>>::type_info(),
+            value.size_hint(),
+            |buf| (TypeFormat::Binary, value.encode_nullable(buf)),
+        );
+    }
+
+    fn add_dyn(&mut self, value: &dyn DynEncode<Postgres>) {
+        if let Err(error) = value.check_encode_dyn() {
+            return self.push_error(value.type_info_dyn(), error);
+        }
+
+        self.push(value.type_info_dyn(), value.size_hint_dyn(), |buf| {
+            (TypeFormat::Binary, value.encode_dyn(buf))
+        });
+    }
+}
+
+impl PgArguments {
+    /// Adds a value to be sent in the text format of Postgres, as written by
+    /// [`Encode::encode_text`].
+    ///
+    /// A value whose type has no text encoding is sent in the binary format, as by
+    /// [`add`](Arguments::add).
+    pub fn add_text<T>(&mut self, value: T)
+    where
+        T: Type<Postgres>,
+        T: Encode<Postgres>,
+    {
+        if let Err(error) = value.check_encode() {
+            return self.push_error(<T as Type<Postgres>>::type_info(), error);
+        }
+
+        self.push(
+            <T as Type<Postgres>>::type_info(),
+            value.size_hint(),
+            |buf| match value.encode_text(buf) {
+                Some(is_null) => (TypeFormat::Text, is_null),
+                None => (TypeFormat::Binary, value.encode_nullable(buf)),
+            },
+        );
+    }
 
-        self.types.push(<T as Type<Postgres>>::type_info().id.0);
+    // Appends a NULL in place of a value that could not be encoded, keeping the parameters
+    // numbered as they were bound, and records `error` if it is the first
+    fn push_error(&mut self, type_info: PgTypeInfo, error: crate::Error) {
+        if self.error.is_none() {
+            self.error = Some((self.types.len(), error));
+        }
+
+        self.push(type_info, 0, |_| (TypeFormat::Binary, IsNull::Yes));
+    }
+
+    // Appends a value written by `encode` in the format it returns; `add` passes a closure
+    // over the concrete type so that only `add_dyn` encodes through the vtable
+    fn push<F>(&mut self, type_info: PgTypeInfo, size_hint: usize, encode: F)
+    where
+        F: FnOnce(&mut Vec<u8>) -> (TypeFormat, IsNull),
+    {
+        if let (TypeId(0), Some(name)) = (type_info.id, type_info.name) {
+            self.unresolved_types.push((self.types.len(), name));
+        }
+
+        self.types.push(type_info.id.0);
+
+        // the length prefix and the value
+        self.values.reserve(4 + size_hint);
 
         let pos = self.values.len();
 
         self.values.put_i32::<NetworkEndian>(0);
 
-        let len = if let IsNull::No = value.encode_nullable(&mut self.values) {
+        let (format, is_null) = encode(&mut self.values);
+        self.formats.push(format);
+
+        let len = if let IsNull::No = is_null {
             (self.values.len() - pos - 4) as i32
         } else {
             // Write a -1 for the len to indicate NULL
@@ -50,3 +173,72 @@ impl Arguments for PgArguments {
         NetworkEndian::write_i32(&mut self.values[pos..], len as i32);
     }
 }
+
+#[test]
+fn test_size_hint_is_a_lower_bound() {
+    fn assert_lower_bound<T: Encode<Postgres>>(value: T) {
+        let mut buf = Vec::new();
+        value.encode(&mut buf);
+
+        assert!(value.size_hint() <= buf.len());
+    }
+
+    assert_lower_bound(true);
+    assert_lower_bound(42_i16);
+    assert_lower_bound(42_i32);
+    assert_lower_bound(42_i64);
+    assert_lower_bound(4.2_f64);
+    assert_lower_bound("hello");
+    assert_lower_bound(String::from("hello"));
+    assert_lower_bound(vec![0xDE_u8, 0xAD, 0xBE, 0xEF]);
+    assert_lower_bound(vec![1_i32, 2, 3]);
+    assert_lower_bound(Some(42_i32));
+}
+
+#[test]
+fn test_len_and_types() {
+    use crate::types::TypeInfo;
+
+    let mut args = PgArguments::default();
+    assert!(args.is_empty());
+
+    args.add(42_i32);
+    args.add("hello");
+    args.add(None::<bool>);
+
+    assert_eq!(args.len(), 3);
+
+    let names: Vec<_> = args.types().map(|ty| ty.name().to_owned()).collect();
+    assert_eq!(names, ["INT4", "TEXT", "BOOL"]);
+}
+
+#[test]
+fn test_add_text() {
+    let mut args = PgArguments::default();
+
+    args.add_text(-42_i32);
+    args.add(7_i32);
+    args.add_text(None::<i64>);
+    args.add_text(vec![0xDE_u8, 0xAD]);
+
+    assert_eq!(
+        args.formats,
+        [
+            TypeFormat::Text,
+            TypeFormat::Binary,
+            TypeFormat::Text,
+            TypeFormat::Binary
+        ]
+    );
+
+    let mut expected = Vec::new();
+    expected.extend_from_slice(&3_i32.to_be_bytes());
+    expected.extend_from_slice(b"-42");
+    expected.extend_from_slice(&4_i32.to_be_bytes());
+    expected.extend_from_slice(&7_i32.to_be_bytes());
+    expected.extend_from_slice(&(-1_i32).to_be_bytes());
+    expected.extend_from_slice(&2_i32.to_be_bytes());
+    expected.extend_from_slice(&[0xDE, 0xAD]);
+
+    assert_eq!(args.values, expected);
+}
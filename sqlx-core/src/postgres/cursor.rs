@@ -1,4 +1,3 @@
-use std::collections::HashMap;
 use std::sync::Arc;
 
 use futures_core::future::BoxFuture;
@@ -6,17 +5,36 @@ use futures_core::future::BoxFuture;
 use crate::connection::ConnectionSource;
 use crate::cursor::Cursor;
 use crate::executor::Execute;
+#[cfg(feature = "log-queries")]
+use crate::logger::QueryLogger;
 use crate::pool::Pool;
 use crate::postgres::protocol::{
-    DataRow, Message, ReadyForQuery, RowDescription, StatementId, TypeFormat,
+    CommandComplete, DataRow, Message, ReadyForQuery, RowDescription, StatementId, TypeFormat,
+    TypeId,
 };
 use crate::postgres::{PgArguments, PgConnection, PgRow, Postgres};
+use crate::row::ColumnNames;
 
 pub struct PgCursor<'c, 'q> {
     source: ConnectionSource<'c, PgConnection>,
     query: Option<(&'q str, Option<PgArguments>)>,
-    columns: Arc<HashMap<Box<str>, usize>>,
+    pub(super) columns: Arc<ColumnNames>,
     formats: Arc<[TypeFormat]>,
+    pub(super) types: Arc<[TypeId]>,
+    command_tag: Option<Box<str>>,
+    #[cfg(feature = "log-queries")]
+    logger: Option<QueryLogger<'q>>,
+}
+
+impl PgCursor<'_, '_> {
+    /// Returns the tag of the most recently completed command, e.g. `SELECT 42` or
+    /// `CREATE TABLE`, as sent by the server.
+    ///
+    /// The tag of a command is only known once all of its rows have been read, so this
+    /// returns `None` until then.
+    pub fn command_tag(&self) -> Option<&str> {
+        self.command_tag.as_deref()
+    }
 }
 
 impl<'c, 'q> Cursor<'c, 'q> for PgCursor<'c, 'q> {
@@ -32,7 +50,11 @@ impl<'c, 'q> Cursor<'c, 'q> for PgCursor<'c, 'q> {
             source: ConnectionSource::Pool(pool.clone()),
             columns: Arc::default(),
             formats: Arc::new([] as [TypeFormat; 0]),
+            types: Arc::new([] as [TypeId; 0]),
             query: Some(query.into_parts()),
+            command_tag: None,
+            #[cfg(feature = "log-queries")]
+            logger: None,
         }
     }
 
@@ -46,7 +68,11 @@ impl<'c, 'q> Cursor<'c, 'q> for PgCursor<'c, 'q> {
             source: ConnectionSource::ConnectionRef(conn),
             columns: Arc::default(),
             formats: Arc::new([] as [TypeFormat; 0]),
+            types: Arc::new([] as [TypeId; 0]),
             query: Some(query.into_parts()),
+            command_tag: None,
+            #[cfg(feature = "log-queries")]
+            logger: None,
         }
     }
 
@@ -55,29 +81,24 @@ impl<'c, 'q> Cursor<'c, 'q> for PgCursor<'c, 'q> {
     }
 }
 
-fn parse_row_description(rd: RowDescription) -> (HashMap<Box<str>, usize>, Vec<TypeFormat>) {
-    let mut columns = HashMap::new();
-    let mut formats = Vec::new();
+type ColumnDescription = (ColumnNames, Vec<TypeFormat>, Vec<TypeId>);
 
-    columns.reserve(rd.fields.len());
-    formats.reserve(rd.fields.len());
-
-    for (index, field) in rd.fields.iter().enumerate() {
-        if let Some(name) = &field.name {
-            columns.insert(name.clone(), index);
-        }
+fn parse_row_description(rd: RowDescription) -> ColumnDescription {
+    let columns = ColumnNames::new(rd.fields.iter().map(|field| field.name.as_deref()));
+    let mut formats = Vec::with_capacity(rd.fields.len());
+    let mut types = Vec::with_capacity(rd.fields.len());
 
+    for field in rd.fields.iter() {
         formats.push(field.type_format);
+        types.push(field.type_id);
     }
 
-    (columns, formats)
+    (columns, formats, types)
 }
 
 // Used to describe the incoming results
 // We store the column map in an Arc and share it among all rows
-async fn expect_desc(
-    conn: &mut PgConnection,
-) -> crate::Result<(HashMap<Box<str>, usize>, Vec<TypeFormat>)> {
+async fn expect_desc(conn: &mut PgConnection) -> crate::Result<ColumnDescription> {
     let description: Option<_> = loop {
         match conn.stream.receive().await? {
             Message::ParseComplete | Message::BindComplete => {}
@@ -105,44 +126,93 @@ async fn expect_desc(
 async fn get_or_describe(
     conn: &mut PgConnection,
     statement: StatementId,
-) -> crate::Result<(Arc<HashMap<Box<str>, usize>>, Arc<[TypeFormat]>)> {
+) -> crate::Result<(Arc<ColumnNames>, Arc<[TypeFormat]>, Arc<[TypeId]>)> {
     if !conn.cache_statement_columns.contains_key(&statement)
         || !conn.cache_statement_formats.contains_key(&statement)
+        || !conn.cache_statement_types.contains_key(&statement)
     {
-        let (columns, formats) = expect_desc(conn).await?;
+        conn.statement_cache_stats.misses += 1;
+
+        let (columns, formats, types) = expect_desc(conn).await?;
 
         conn.cache_statement_columns
             .insert(statement, Arc::new(columns));
 
         conn.cache_statement_formats
             .insert(statement, Arc::from(formats));
+
+        conn.cache_statement_types
+            .insert(statement, Arc::from(types));
+    } else {
+        conn.statement_cache_stats.hits += 1;
     }
 
     Ok((
         Arc::clone(&conn.cache_statement_columns[&statement]),
         Arc::clone(&conn.cache_statement_formats[&statement]),
+        Arc::clone(&conn.cache_statement_types[&statement]),
     ))
 }
 
 async fn next<'a, 'c: 'a, 'q: 'a>(
     cursor: &'a mut PgCursor<'c, 'q>,
 ) -> crate::Result<Option<PgRow<'a>>> {
+    let result = match advance(cursor).await {
+        Ok(false) => return Ok(None),
+        Ok(true) => match cursor.source.resolve().await {
+            Ok(conn) => DataRow::read(conn.stream.buffer(), &mut conn.current_row_values),
+            Err(error) => Err(error),
+        },
+        Err(error) => Err(error),
+    };
+
+    match result {
+        Ok(data) => Ok(Some(PgRow {
+            columns: Arc::clone(&cursor.columns),
+            formats: Arc::clone(&cursor.formats),
+            types: Arc::clone(&cursor.types),
+            data,
+        })),
+
+        Err(error) => {
+            #[cfg(feature = "log-queries")]
+            if let Some(logger) = cursor.logger.take() {
+                logger.fail(&error);
+            }
+
+            Err(error)
+        }
+    }
+}
+
+// Reads up to the next row, returning `true` once it is in the buffer of the stream or
+// `false` at the end of the query
+async fn advance(cursor: &mut PgCursor<'_, '_>) -> crate::Result<bool> {
     let mut conn = cursor.source.resolve().await?;
 
     // The first time [next] is called we need to actually execute our
     // contained query. We guard against this happening on _all_ next calls
     // by using [Option::take] which replaces the potential value in the Option with `None
     if let Some((query, arguments)) = cursor.query.take() {
+        #[cfg(feature = "log-queries")]
+        {
+            cursor.logger = Some(QueryLogger::new(
+                query,
+                arguments.as_ref().map_or(0, PgArguments::len),
+            ));
+        }
+
         let statement = conn.run(query, arguments).await?;
 
         // If there is a statement ID, this is a non-simple or prepared query
         if let Some(statement) = statement {
             // A prepared statement will re-use the previous column map if
             // this query has been executed before
-            let (columns, formats) = get_or_describe(&mut *conn, statement).await?;
+            let (columns, formats, types) = get_or_describe(&mut *conn, statement).await?;
 
             cursor.columns = columns;
             cursor.formats = formats;
+            cursor.types = types;
         }
 
         // A non-prepared query must be described each time
@@ -156,7 +226,14 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
             Message::ParseComplete | Message::BindComplete => {}
 
             // Indicates that _a_ query has finished executing
-            Message::CommandComplete => {}
+            Message::CommandComplete => {
+                let cc = CommandComplete::read(conn.stream.buffer())?;
+
+                cursor.command_tag = Some(cc.tag);
+            }
+
+            // Indicates that the query string was empty; there are no rows to return
+            Message::EmptyQueryResponse => {}
 
             // Indicates that all queries have finished executing
             Message::ReadyForQuery => {
@@ -164,25 +241,29 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
                 let _ready = ReadyForQuery::read(conn.stream.buffer())?;
 
                 conn.is_ready = true;
-                break;
+
+                // the query is complete; log it
+                #[cfg(feature = "log-queries")]
+                drop(cursor.logger.take());
+                return Ok(false);
             }
 
             Message::RowDescription => {
                 let rd = RowDescription::read(conn.stream.buffer())?;
-                let (columns, formats) = parse_row_description(rd);
+                let (columns, formats, types) = parse_row_description(rd);
 
                 cursor.columns = Arc::new(columns);
                 cursor.formats = Arc::from(formats);
+                cursor.types = Arc::from(types);
             }
 
             Message::DataRow => {
-                let data = DataRow::read(conn.stream.buffer(), &mut conn.current_row_values)?;
+                #[cfg(feature = "log-queries")]
+                if let Some(logger) = &mut cursor.logger {
+                    logger.increment_rows();
+                }
 
-                return Ok(Some(PgRow {
-                    columns: Arc::clone(&cursor.columns),
-                    formats: Arc::clone(&cursor.formats),
-                    data,
-                }));
+                return Ok(true);
             }
 
             message => {
@@ -190,6 +271,4 @@ async fn next<'a, 'c: 'a, 'q: 'a>(
             }
         }
     }
-
-    Ok(None)
 }
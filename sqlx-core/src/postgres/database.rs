@@ -18,6 +18,33 @@ impl Database for Postgres {
     type RawBuffer = Vec<u8>;
 }
 
+impl Postgres {
+    /// Quotes `ident` so it can be interpolated into a query as a table, column or
+    /// other identifier.
+    ///
+    /// The identifier is wrapped in double quotes with any embedded double quotes doubled,
+    /// which also makes it case-sensitive. Returns `None` if `ident` contains a NUL
+    /// character, which Postgres never allows in an identifier.
+    ///
+    /// ```rust
+    /// # use sqlx_core::postgres::Postgres;
+    /// assert_eq!(Postgres::quote_identifier("my \"table\"").unwrap(), r#""my ""table""""#);
+    /// ```
+    pub fn quote_identifier(ident: &str) -> Option<String> {
+        if ident.contains('\0') {
+            return None;
+        }
+
+        let mut quoted = String::with_capacity(ident.len() + 2);
+
+        quoted.push('"');
+        quoted.push_str(&ident.replace('"', "\"\""));
+        quoted.push('"');
+
+        Some(quoted)
+    }
+}
+
 impl<'a> HasRow<'a> for Postgres {
     type Database = Postgres;
 
@@ -33,3 +60,19 @@ impl<'s, 'q> HasCursor<'s, 'q> for Postgres {
 impl<'a> HasRawValue<'a> for Postgres {
     type RawValue = Option<PgValue<'a>>;
 }
+
+#[test]
+fn test_quote_identifier() {
+    assert_eq!(Postgres::quote_identifier("users").unwrap(), "\"users\"");
+    assert_eq!(Postgres::quote_identifier("Users").unwrap(), "\"Users\"");
+    assert_eq!(
+        Postgres::quote_identifier("a\"; DROP TABLE users; --").unwrap(),
+        "\"a\"\"; DROP TABLE users; --\""
+    );
+    assert_eq!(
+        Postgres::quote_identifier("üñí©ødé").unwrap(),
+        "\"üñí©ødé\""
+    );
+    assert_eq!(Postgres::quote_identifier("").unwrap(), "\"\"");
+    assert!(Postgres::quote_identifier("a\0b").is_none());
+}
@@ -4,40 +4,120 @@ use crate::error::DatabaseError;
 use crate::postgres::protocol::Response;
 
 #[derive(Debug)]
-pub struct PgError(pub(super) Response);
+pub struct PgError {
+    pub(super) response: Response,
+
+    // The text of the query that failed; only kept if the connection was asked to
+    // include queries in errors
+    pub(super) query: Option<Box<str>>,
+}
+
+impl PgError {
+    /// Returns the text of the query that caused the error, if the connection was configured
+    /// to [include queries in errors](super::PgConnection::include_query_in_errors).
+    pub fn query(&self) -> Option<&str> {
+        self.query.as_deref()
+    }
+}
 
 impl DatabaseError for PgError {
     fn message(&self) -> &str {
-        &self.0.message
+        &self.response.message
     }
 
     fn code(&self) -> Option<&str> {
-        Some(&self.0.code)
+        Some(&self.response.code)
     }
 
     fn details(&self) -> Option<&str> {
-        self.0.detail.as_ref().map(|s| &**s)
+        self.response.detail.as_deref()
     }
 
     fn hint(&self) -> Option<&str> {
-        self.0.hint.as_ref().map(|s| &**s)
+        self.response.hint.as_deref()
     }
 
     fn table_name(&self) -> Option<&str> {
-        self.0.table.as_ref().map(|s| &**s)
+        self.response.table.as_deref()
     }
 
     fn column_name(&self) -> Option<&str> {
-        self.0.column.as_ref().map(|s| &**s)
+        self.response.column.as_deref()
     }
 
     fn constraint_name(&self) -> Option<&str> {
-        self.0.constraint.as_ref().map(|s| &**s)
+        self.response.constraint.as_deref()
+    }
+
+    fn position(&self) -> Option<usize> {
+        self.response.position
     }
 }
 
 impl Display for PgError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.pad(self.message())
+        match (&self.query, self.response.position) {
+            (Some(query), Some(position)) => {
+                f.write_str(self.message())?;
+                write_snippet(f, query, position)
+            }
+
+            _ => f.pad(self.message()),
+        }
+    }
+}
+
+// Writes the line of `query` containing the 1-based character `position` with a caret
+// pointing at the position, in the style of `psql`:
+//
+//     LINE 1: SELECT * FORM users
+//                      ^
+fn write_snippet(f: &mut fmt::Formatter, query: &str, position: usize) -> fmt::Result {
+    let offset = query
+        .char_indices()
+        .nth(position.saturating_sub(1))
+        .map_or(query.len(), |(offset, _)| offset);
+
+    let line_start = query[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let line_number = query[..offset].matches('\n').count() + 1;
+    let line = query[line_start..].lines().next().unwrap_or_default();
+
+    let prefix = format!("LINE {}: ", line_number);
+    let column = query[line_start..offset].chars().count();
+
+    write!(
+        f,
+        "\n{}{}\n{:width$}^",
+        prefix,
+        line,
+        "",
+        width = prefix.len() + column
+    )
+}
+
+#[test]
+fn test_write_snippet() {
+    struct Snippet(&'static str, usize);
+
+    impl Display for Snippet {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write_snippet(f, self.0, self.1)
+        }
     }
+
+    assert_eq!(
+        Snippet("SELECT * FORM users", 10).to_string(),
+        "\nLINE 1: SELECT * FORM users\n                 ^"
+    );
+
+    assert_eq!(
+        Snippet("SELECT id,\n  name FORM users", 24).to_string(),
+        "\nLINE 2:   name FORM users\n                    ^"
+    );
+
+    // positions count characters, not bytes
+    assert_eq!(
+        Snippet("SELECT 'ü' FORM", 12).to_string(),
+        "\nLINE 1: SELECT 'ü' FORM\n                   ^"
+    );
 }
@@ -1,5 +1,7 @@
 use std::collections::{HashMap, HashSet};
 use std::fmt::Write;
+use std::mem;
+use std::sync::Arc;
 
 use futures_core::future::BoxFuture;
 use futures_util::{stream, StreamExt, TryStreamExt};
@@ -7,14 +9,17 @@ use futures_util::{stream, StreamExt, TryStreamExt};
 use crate::arguments::Arguments;
 use crate::cursor::Cursor;
 use crate::describe::{Column, Describe};
-use crate::executor::{Execute, Executor, RefExecutor};
+use crate::executor::{Execute, Executor, QueryResult, RefExecutor};
+#[cfg(feature = "log-queries")]
+use crate::logger::QueryLogger;
 use crate::postgres::protocol::{
     self, CommandComplete, Field, Message, ParameterDescription, ReadyForQuery, RowDescription,
     StatementId, TypeFormat, TypeId,
 };
-use crate::postgres::types::SharedStr;
+use crate::postgres::types::{has_binary_output, SharedStr};
 use crate::postgres::{PgArguments, PgConnection, PgCursor, PgRow, PgTypeInfo, Postgres};
 use crate::row::Row;
+use crate::types::TypeInfo;
 
 impl PgConnection {
     pub(crate) fn write_simple_query(&mut self, query: &str) {
@@ -28,6 +33,7 @@ impl PgConnection {
             let id = StatementId(self.next_statement_id);
 
             self.next_statement_id += 1;
+            self.statement_cache_stats.prepared += 1;
 
             self.stream.write(protocol::Parse {
                 statement: id,
@@ -49,10 +55,13 @@ impl PgConnection {
         self.stream.write(protocol::Bind {
             portal,
             statement,
-            formats: &[TypeFormat::Binary],
+            formats: &*args.formats,
             values_len: args.types.len() as i16,
             values: &*args.values,
-            result_formats: &[TypeFormat::Binary],
+            result_formats: self
+                .cache_statement_result_formats
+                .get(&statement)
+                .map_or(&[TypeFormat::Binary], |formats| &**formats),
         });
     }
 
@@ -64,7 +73,7 @@ impl PgConnection {
         self.stream.write(protocol::Sync);
     }
 
-    async fn wait_until_ready(&mut self) -> crate::Result<()> {
+    pub(super) async fn wait_until_ready(&mut self) -> crate::Result<()> {
         // depending on how the previous query finished we may need to continue
         // pulling messages from the stream until we receive a [ReadyForQuery] message
 
@@ -84,6 +93,180 @@ impl PgConnection {
         Ok(())
     }
 
+    // Prepare a new statement in its own round-trip, describing it to learn the types of its
+    // result columns before it is first bound. If the server rejects the statement we can
+    // check whether the bound arguments were to blame before reporting the error.
+    async fn prepare(&mut self, query: &str, args: &PgArguments) -> crate::Result<()> {
+        self.wait_until_ready().await?;
+
+        let statement = self.write_prepare(query, args);
+
+        self.write_describe(protocol::Describe::Statement(statement));
+        self.write_sync();
+
+        self.stream.flush().await?;
+        self.is_ready = false;
+
+        let error = match self.stream.receive().await {
+            Ok(Message::ParseComplete) => {
+                let formats = self.expect_result_formats().await?;
+
+                // only statements with a column that can not be sent in binary are remembered
+                if formats.contains(&TypeFormat::Text) {
+                    self.cache_statement_result_formats
+                        .insert(statement, Arc::from(formats));
+                }
+
+                return self.wait_until_ready().await;
+            }
+
+            Err(error @ crate::Error::Database(_)) | Err(error @ crate::Error::LockTimeout(_)) => {
+                error
+            }
+
+            Err(error) => return Err(error),
+
+            Ok(message) => {
+                return Err(protocol_err!("expected ParseComplete; received {:?}", message).into());
+            }
+        };
+
+        // the statement was never created on the server so it must not be reused
+        self.cache_statement.remove(query);
+        self.wait_until_ready().await?;
+
+        let error = match error {
+            crate::Error::Database(error)
+                if self.check_parameter_types && !args.types.is_empty() =>
+            {
+                error
+            }
+
+            error => return Err(error),
+        };
+
+        Err(self
+            .find_parameter_type_mismatch(query, &args.types)
+            .await?
+            .unwrap_or(crate::Error::Database(error)))
+    }
+
+    // Reads the description of a statement and returns the format to request for each of its
+    // result columns: binary, unless the type of the column has no binary output
+    async fn expect_result_formats(&mut self) -> crate::Result<Vec<TypeFormat>> {
+        match self.stream.receive().await? {
+            Message::ParameterDescription => {}
+
+            message => {
+                return Err(
+                    protocol_err!("expected ParameterDescription; received {:?}", message).into(),
+                );
+            }
+        }
+
+        let fields = match self.stream.receive().await? {
+            Message::NoData => return Ok(Vec::new()),
+            Message::RowDescription => RowDescription::read(self.stream.buffer())?.fields,
+
+            message => {
+                return Err(protocol_err!(
+                    "expected RowDescription or NoData; received {:?}",
+                    message
+                )
+                .into());
+            }
+        };
+
+        Ok(fields
+            .iter()
+            .map(|field| {
+                if has_binary_output(field.type_id) {
+                    TypeFormat::Binary
+                } else {
+                    TypeFormat::Text
+                }
+            })
+            .collect())
+    }
+
+    // Ask the server which parameter types it infers for the query, by preparing it as the
+    // unnamed statement with no declared types, and compare those against the bound types.
+    async fn find_parameter_type_mismatch(
+        &mut self,
+        query: &str,
+        types: &[u32],
+    ) -> crate::Result<Option<crate::Error>> {
+        self.stream.write(protocol::Parse {
+            statement: StatementId(0),
+            query,
+            param_types: &[],
+        });
+
+        self.write_describe(protocol::Describe::Statement(StatementId(0)));
+        self.write_sync();
+
+        self.stream.flush().await?;
+        self.is_ready = false;
+
+        let params = match self.stream.receive().await {
+            Ok(Message::ParseComplete) => match self.stream.receive().await? {
+                Message::ParameterDescription => ParameterDescription::read(self.stream.buffer())?,
+
+                message => {
+                    return Err(protocol_err!(
+                        "expected ParameterDescription; received {:?}",
+                        message
+                    )
+                    .into());
+                }
+            },
+
+            // the server cannot infer the parameter types either; there is nothing to compare
+            Err(crate::Error::Database(_)) | Err(crate::Error::LockTimeout(_)) => {
+                self.wait_until_ready().await?;
+                return Ok(None);
+            }
+
+            Err(error) => return Err(error),
+
+            Ok(message) => {
+                return Err(protocol_err!("expected ParseComplete; received {:?}", message).into());
+            }
+        };
+
+        self.wait_until_ready().await?;
+
+        let mismatch = params
+            .ids
+            .iter()
+            .zip(types)
+            .enumerate()
+            // an OID of 0 means the type was left unspecified
+            .filter(|(_, (_, &bound))| bound != 0)
+            .find(|(_, (&expected, &bound))| {
+                !PgTypeInfo::with_oid(expected.0).compatible(&PgTypeInfo::with_oid(bound))
+            });
+
+        let (index, (expected, bound)) = match mismatch {
+            Some((index, (&expected, &bound))) => (index, (expected, bound)),
+            None => return Ok(None),
+        };
+
+        let type_names = self.get_type_names(vec![expected, TypeId(bound)]).await?;
+
+        // a bound OID that is not in `pg_type` (e.g., from a wrong `#[sqlx(postgres(oid = ..))]`) has no name
+        let describe = |oid: u32| match type_names.get(&oid) {
+            Some(name) => PgTypeInfo::new(TypeId(oid), name).to_string(),
+            None => PgTypeInfo::with_oid(oid).to_string(),
+        };
+
+        Ok(Some(crate::Error::ParameterTypeMismatch {
+            index: index + 1,
+            expected: describe(expected.0).into(),
+            found: describe(bound).into(),
+        }))
+    }
+
     // Write out the query to the connection stream, ensure that we are synchronized at the
     // most recent [ReadyForQuery] and flush our buffer to postgres.
     //
@@ -95,7 +278,22 @@ impl PgConnection {
         query: &str,
         arguments: Option<PgArguments>,
     ) -> crate::Result<Option<StatementId>> {
-        let statement = if let Some(arguments) = arguments {
+        let statement = if let Some(mut arguments) = arguments {
+            arguments.take_error()?;
+
+            if !arguments.unresolved_types.is_empty() {
+                self.resolve_types(&mut arguments).await?;
+            }
+
+            self.set_error_query(query);
+
+            // A statement the server has not seen before is prepared on its own so the format
+            // of its results can be chosen and a type mismatch in the bound arguments can be
+            // reported clearly
+            if !self.cache_statement.contains_key(query) {
+                self.prepare(query, &arguments).await?;
+            }
+
             // Check the statement cache for a statement ID that matches the given query
             // If it doesn't exist, we generate a new statement ID and write out [Parse] to the
             // connection command buffer
@@ -122,6 +320,8 @@ impl PgConnection {
 
             Some(statement)
         } else {
+            self.set_error_query(query);
+
             // https://www.postgresql.org/docs/12/protocol-flow.html#id-1.10.5.7.4
             self.write_simple_query(query);
 
@@ -136,6 +336,15 @@ impl PgConnection {
         Ok(statement)
     }
 
+    // Remember the query being run so errors reported for it can point into its text
+    pub(super) fn set_error_query(&mut self, query: &str) {
+        self.stream.query = if self.include_query_in_errors {
+            Some(query.into())
+        } else {
+            None
+        };
+    }
+
     async fn do_describe<'e, 'q: 'e>(
         &'e mut self,
         query: &'q str,
@@ -209,6 +418,37 @@ impl PgConnection {
         })
     }
 
+    // Fill in the OIDs of the argument types that are only known by name
+    pub(super) async fn resolve_types(&mut self, arguments: &mut PgArguments) -> crate::Result<()> {
+        for (index, name) in mem::take(&mut arguments.unresolved_types) {
+            let oid = match self.cache_type_oid.get(&*name) {
+                Some(&oid) => oid,
+
+                None => {
+                    // `to_regtype` parses the name as SQL would and finds the type through
+                    // the search path; it returns NULL if there is no such type
+                    let oid = crate::query::query("SELECT to_regtype($1)::oid")
+                        .bind(&*name)
+                        .try_map(|row: PgRow| row.try_get::<Option<u32>, _>(0))
+                        .fetch_one(&mut *self)
+                        .await?;
+
+                    let oid = oid.ok_or_else(|| crate::Error::TypeNotFound {
+                        type_name: (&*name).into(),
+                    })?;
+
+                    self.cache_type_oid.insert((&*name).into(), oid);
+
+                    oid
+                }
+            };
+
+            arguments.types[index] = oid;
+        }
+
+        Ok(())
+    }
+
     async fn get_type_names(
         &mut self,
         ids: impl IntoIterator<Item = TypeId>,
@@ -328,8 +568,9 @@ impl PgConnection {
 
     // Poll messages from Postgres, counting the rows affected, until we finish the query
     // This must be called directly after a call to [PgConnection::execute]
-    async fn affected_rows(&mut self) -> crate::Result<u64> {
+    async fn affected_rows(&mut self) -> crate::Result<QueryResult> {
         let mut rows = 0;
+        let mut tag = None;
 
         loop {
             match self.stream.receive().await? {
@@ -345,7 +586,10 @@ impl PgConnection {
                 }
 
                 Message::CommandComplete => {
-                    rows += CommandComplete::read(self.stream.buffer())?.affected_rows;
+                    let complete = CommandComplete::read(self.stream.buffer())?;
+
+                    rows += complete.affected_rows;
+                    tag = Some(complete.tag);
                 }
 
                 Message::ReadyForQuery => {
@@ -364,7 +608,11 @@ impl PgConnection {
             }
         }
 
-        Ok(rows)
+        Ok(QueryResult {
+            rows_affected: rows,
+            command_tag: tag,
+            ..QueryResult::default()
+        })
     }
 }
 
@@ -374,15 +622,28 @@ impl Executor for super::PgConnection {
     fn execute<'e, 'q: 'e, 'c: 'e, E: 'e>(
         &'c mut self,
         query: E,
-    ) -> BoxFuture<'e, crate::Result<u64>>
+    ) -> BoxFuture<'e, crate::Result<QueryResult>>
     where
         E: Execute<'q, Self::Database>,
     {
         Box::pin(async move {
             let (query, arguments) = query.into_parts();
+            #[cfg(feature = "log-queries")]
+            let mut logger =
+                QueryLogger::new(query, arguments.as_ref().map_or(0, PgArguments::len));
+
+            let result = match self.run(query, arguments).await {
+                Ok(_) => self.affected_rows().await,
+                Err(error) => Err(error),
+            };
+
+            #[cfg(feature = "log-queries")]
+            match &result {
+                Ok(result) => logger.set_rows_affected(result.rows_affected()),
+                Err(error) => logger.fail(error),
+            }
 
-            self.run(query, arguments).await?;
-            self.affected_rows().await
+            result
         })
     }
 
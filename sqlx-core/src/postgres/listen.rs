@@ -7,7 +7,7 @@ use futures_core::future::BoxFuture;
 use futures_core::stream::Stream;
 
 use crate::describe::Describe;
-use crate::executor::{Execute, Executor, RefExecutor};
+use crate::executor::{Execute, Executor, QueryResult, RefExecutor};
 use crate::pool::{Pool, PoolConnection};
 use crate::postgres::protocol::{Message, NotificationResponse};
 use crate::postgres::{PgConnection, PgCursor, Postgres};
@@ -194,7 +194,7 @@ impl Executor for PgListener {
     fn execute<'e, 'q: 'e, 'c: 'e, E: 'e>(
         &'c mut self,
         query: E,
-    ) -> BoxFuture<'e, crate::Result<u64>>
+    ) -> BoxFuture<'e, crate::Result<QueryResult>>
     where
         E: Execute<'q, Self::Database>,
     {
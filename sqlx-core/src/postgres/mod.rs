@@ -1,24 +1,33 @@
 //! **Postgres** database and connection types.
 
 pub use arguments::PgArguments;
-pub use connection::PgConnection;
+pub use connection::{PgConnection, PgStatementCacheStats};
 pub use cursor::PgCursor;
 pub use database::Postgres;
 pub use error::PgError;
 pub use listen::{PgListener, PgNotification};
-pub use row::{PgRow, PgValue};
+pub use pipeline::PgPipeline;
+pub use query_builder::{PgQueryBuilder, PgSql, PgUnnestColumns, PgValuesRow};
+pub use row::{PgRawValue, PgRow, PgValue};
+pub use simple_query::{PgSimpleQueryResult, PgSimpleQueryRow};
 pub use types::PgTypeInfo;
 
 mod arguments;
 mod connection;
+mod csv;
 mod cursor;
 mod database;
 mod error;
 mod executor;
 mod listen;
+mod pipeline;
 mod protocol;
+mod query_builder;
 mod row;
 mod sasl;
+#[cfg(feature = "serde")]
+mod serialize;
+mod simple_query;
 mod stream;
 mod tls;
 pub mod types;
@@ -31,3 +40,4 @@ make_query_as!(PgQueryAs, Postgres, PgRow);
 impl_map_row_for_row!(Postgres, PgRow);
 impl_column_index_for_row!(Postgres);
 impl_from_row_for_tuples!(Postgres, PgRow);
+impl_from_row_for_map!(Postgres, PgRow);
@@ -0,0 +1,154 @@
+use crate::executor::{Execute, QueryResult};
+#[cfg(feature = "log-queries")]
+use crate::logger::QueryLogger;
+use crate::postgres::protocol::{CommandComplete, Message};
+use crate::postgres::{PgArguments, PgConnection, Postgres};
+
+/// Queries that are sent to the server together, returned by [`PgConnection::pipeline`].
+pub struct PgPipeline<'c, 'q> {
+    conn: &'c mut PgConnection,
+    queries: Vec<(&'q str, PgArguments)>,
+}
+
+impl PgConnection {
+    /// Starts a pipeline of queries that are sent to the server together and executed in
+    /// order, saving a round-trip for each query.
+    ///
+    /// The queries are executed as a single implicit transaction (unless a transaction is
+    /// already open): if one of them fails, the queries after it are skipped and the ones
+    /// before it are rolled back.
+    ///
+    /// ```rust,ignore
+    /// let results = conn
+    ///     .pipeline()
+    ///     .push(sqlx::query("INSERT INTO logs (message) VALUES ($1)").bind("a"))
+    ///     .push(sqlx::query("UPDATE counters SET n = n + 1"))
+    ///     .execute()
+    ///     .await?;
+    /// ```
+    pub fn pipeline<'q>(&mut self) -> PgPipeline<'_, 'q> {
+        PgPipeline {
+            conn: self,
+            queries: Vec::new(),
+        }
+    }
+}
+
+impl<'c, 'q> PgPipeline<'c, 'q> {
+    /// Adds a query to the end of the pipeline.
+    ///
+    /// Every query is prepared, so a query may only hold a single statement. Rows returned
+    /// by a query are discarded.
+    pub fn push<E>(&mut self, query: E) -> &mut Self
+    where
+        E: Execute<'q, Postgres>,
+    {
+        let (query, arguments) = query.into_parts();
+
+        self.queries.push((query, arguments.unwrap_or_default()));
+        self
+    }
+
+    /// Sends all queries of the pipeline and waits for them to complete, returning the result
+    /// of each query in the order they were added.
+    pub async fn execute(&mut self) -> crate::Result<Vec<QueryResult>> {
+        let conn = &mut *self.conn;
+        let mut queries = std::mem::take(&mut self.queries);
+
+        for (_, arguments) in &mut queries {
+            arguments.take_error()?;
+
+            if !arguments.unresolved_types.is_empty() {
+                conn.resolve_types(arguments).await?;
+            }
+        }
+
+        // statements prepared by the pipeline are forgotten if it fails, as the statements
+        // after the failing query are never prepared
+        let mut prepared = Vec::new();
+
+        for (query, arguments) in &queries {
+            if !conn.cache_statement.contains_key(*query) {
+                prepared.push(*query);
+            }
+
+            let statement = conn.write_prepare(query, arguments);
+
+            conn.write_bind("", statement, arguments);
+            conn.write_execute("", 0);
+        }
+
+        // a single [Sync] ends the implicit transaction of the pipeline
+        conn.write_sync();
+
+        conn.wait_until_ready().await?;
+
+        conn.stream.flush().await?;
+        conn.is_ready = false;
+
+        let mut results = Vec::with_capacity(queries.len());
+
+        // the arguments are only read by the logger
+        #[cfg_attr(not(feature = "log-queries"), allow(unused_variables))]
+        for (query, arguments) in &queries {
+            conn.set_error_query(query);
+
+            #[cfg(feature = "log-queries")]
+            let mut logger = QueryLogger::new(query, arguments.len());
+
+            match read_result(conn).await {
+                Ok(result) => {
+                    #[cfg(feature = "log-queries")]
+                    logger.set_rows_affected(result.rows_affected());
+                    results.push(result);
+                }
+
+                Err(error) => {
+                    #[cfg(feature = "log-queries")]
+                    logger.fail(&error);
+
+                    for query in prepared {
+                        conn.cache_statement.remove(query);
+                    }
+
+                    // the remaining messages up to [ReadyForQuery] are discarded
+                    // before the next query
+                    return Err(error);
+                }
+            }
+        }
+
+        conn.wait_until_ready().await?;
+
+        Ok(results)
+    }
+}
+
+// Reads the messages of a single query of the pipeline, up to its [CommandComplete]
+async fn read_result(conn: &mut PgConnection) -> crate::Result<QueryResult> {
+    loop {
+        match conn.stream.receive().await? {
+            Message::ParseComplete
+            | Message::BindComplete
+            | Message::NoData
+            | Message::RowDescription
+            | Message::DataRow => {}
+
+            Message::CommandComplete => {
+                let complete = CommandComplete::read(conn.stream.buffer())?;
+
+                return Ok(QueryResult {
+                    rows_affected: complete.affected_rows,
+                    command_tag: Some(complete.tag),
+                    ..QueryResult::default()
+                });
+            }
+
+            Message::EmptyQueryResponse => return Ok(QueryResult::default()),
+
+            message => {
+                return Err(protocol_err!("pipeline: unexpected message: {:?}", message).into());
+            }
+        }
+    }
+}
@@ -3,6 +3,7 @@ use crate::io::Buf;
 #[derive(Debug)]
 pub(crate) struct CommandComplete {
     pub(crate) affected_rows: u64,
+    pub(crate) tag: Box<str>,
 }
 
 impl CommandComplete {
@@ -11,8 +12,9 @@ impl CommandComplete {
         // If it can't be parsed, the tag is probably "CREATE TABLE" or something
         // and we should return 0 rows
 
-        let rows = buf
-            .get_str_nul()?
+        let tag = buf.get_str_nul()?;
+
+        let rows = tag
             .rsplit(' ')
             .next()
             .and_then(|s| s.parse().ok())
@@ -20,6 +22,7 @@ impl CommandComplete {
 
         Ok(Self {
             affected_rows: rows,
+            tag: tag.into(),
         })
     }
 }
@@ -38,6 +41,7 @@ mod tests {
         let message = CommandComplete::read(COMMAND_COMPLETE_INSERT).unwrap();
 
         assert_eq!(message.affected_rows, 1);
+        assert_eq!(&*message.tag, "INSERT 0 1");
     }
 
     #[test]
@@ -45,6 +49,7 @@ mod tests {
         let message = CommandComplete::read(COMMAND_COMPLETE_UPDATE).unwrap();
 
         assert_eq!(message.affected_rows, 512);
+        assert_eq!(&*message.tag, "UPDATE 512");
     }
 
     #[test]
@@ -59,5 +64,6 @@ mod tests {
         let message = CommandComplete::read(COMMAND_COMPLETE_CREATE_TABLE).unwrap();
 
         assert_eq!(message.affected_rows, 0);
+        assert_eq!(&*message.tag, "CREATE TABLE");
     }
 }
@@ -21,19 +21,20 @@ impl<'c> DataRow<'c> {
 }
 
 impl<'c> DataRow<'c> {
+    // The ranges of the values are written to `values`, which is owned by the connection and
+    // cleared (not reallocated) for each row; a row is dropped before the next one is read
     pub(crate) fn read(
         buffer: &'c [u8],
         values: &'c mut Vec<Option<Range<u32>>>,
     ) -> crate::Result<Self> {
-        // let buffer = connection.stream.buffer();
-        // let values = &mut connection.current_row_values;
-
         values.clear();
 
         let mut buf = buffer;
 
         let len = buf.get_u16::<NetworkEndian>()?;
 
+        values.reserve(len as usize);
+
         let mut index = 6;
 
         while values.len() < (len as usize) {
@@ -61,3 +62,37 @@ impl<'c> DataRow<'c> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::DataRow;
+
+    const DATA_ROW: &[u8] = b"\0\x03\0\0\0\x011\xff\xff\xff\xff\0\0\0\x02hi";
+
+    #[test]
+    fn it_reads_data_row() {
+        let mut values = Vec::new();
+        let m = DataRow::read(DATA_ROW, &mut values).unwrap();
+
+        assert_eq!(m.len(), 3);
+        assert_eq!(m.get(0), Some(&b"1"[..]));
+        assert_eq!(m.get(1), None);
+        assert_eq!(m.get(2), Some(&b"hi"[..]));
+    }
+
+    #[test]
+    fn it_reuses_the_value_buffer() {
+        let mut values = Vec::new();
+        let _ = DataRow::read(DATA_ROW, &mut values).unwrap();
+
+        let ptr = values.as_ptr();
+
+        for _ in 0..100 {
+            let m = DataRow::read(DATA_ROW, &mut values).unwrap();
+            assert_eq!(m.get(2), Some(&b"hi"[..]));
+        }
+
+        assert_eq!(values.as_ptr(), ptr);
+        assert_eq!(values.len(), 3);
+    }
+}
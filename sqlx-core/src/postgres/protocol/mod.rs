@@ -44,6 +44,7 @@ mod command_complete;
 mod data_row;
 mod notification_response;
 mod parameter_description;
+mod parameter_status;
 mod ready_for_query;
 mod response;
 mod row_description;
@@ -59,6 +60,7 @@ pub(crate) use data_row::DataRow;
 pub(crate) use message::Message;
 pub(crate) use notification_response::NotificationResponse;
 pub(crate) use parameter_description::ParameterDescription;
+pub(crate) use parameter_status::ParameterStatus;
 pub(crate) use ready_for_query::ReadyForQuery;
 pub(crate) use response::Response;
 pub(crate) use row_description::{Field, RowDescription};
@@ -1,4 +1,5 @@
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "offline", derive(serde::Serialize, serde::Deserialize))]
 pub struct TypeId(pub(crate) u32);
 
 // DEVELOPER PRO TIP: find builtin type OIDs easily by grepping this file
@@ -13,21 +14,44 @@ impl TypeId {
 
     pub(crate) const BOOL: TypeId = TypeId(16);
 
+    pub(crate) const CHAR: TypeId = TypeId(18);
+    pub(crate) const NAME: TypeId = TypeId(19);
+
     pub(crate) const INT2: TypeId = TypeId(21);
     pub(crate) const INT4: TypeId = TypeId(23);
     pub(crate) const INT8: TypeId = TypeId(20);
 
+    pub(crate) const OID: TypeId = TypeId(26);
+
+    pub(crate) const REGPROC: TypeId = TypeId(24);
+    pub(crate) const REGPROCEDURE: TypeId = TypeId(2202);
+    pub(crate) const REGOPER: TypeId = TypeId(2203);
+    pub(crate) const REGOPERATOR: TypeId = TypeId(2204);
+    pub(crate) const REGCLASS: TypeId = TypeId(2205);
+    pub(crate) const REGTYPE: TypeId = TypeId(2206);
+    pub(crate) const REGCONFIG: TypeId = TypeId(3734);
+    pub(crate) const REGDICTIONARY: TypeId = TypeId(3769);
+    pub(crate) const REGNAMESPACE: TypeId = TypeId(4089);
+    pub(crate) const REGROLE: TypeId = TypeId(4096);
+
     pub(crate) const FLOAT4: TypeId = TypeId(700);
     pub(crate) const FLOAT8: TypeId = TypeId(701);
 
     pub(crate) const NUMERIC: TypeId = TypeId(1700);
 
     pub(crate) const TEXT: TypeId = TypeId(25);
+    pub(crate) const BPCHAR: TypeId = TypeId(1042);
+    pub(crate) const VARCHAR: TypeId = TypeId(1043);
+    pub(crate) const UNKNOWN: TypeId = TypeId(705);
+
+    // the result type of functions that return nothing
+    pub(crate) const VOID: TypeId = TypeId(2278);
 
     pub(crate) const DATE: TypeId = TypeId(1082);
     pub(crate) const TIME: TypeId = TypeId(1083);
     pub(crate) const TIMESTAMP: TypeId = TypeId(1114);
     pub(crate) const TIMESTAMPTZ: TypeId = TypeId(1184);
+    pub(crate) const INTERVAL: TypeId = TypeId(1186);
 
     pub(crate) const BYTEA: TypeId = TypeId(17);
 
@@ -36,14 +60,41 @@ impl TypeId {
     pub(crate) const CIDR: TypeId = TypeId(650);
     pub(crate) const INET: TypeId = TypeId(869);
 
+    pub(crate) const JSON: TypeId = TypeId(114);
+    pub(crate) const JSONB: TypeId = TypeId(3802);
+
+    pub(crate) const XML: TypeId = TypeId(142);
+
+    pub(crate) const POINT: TypeId = TypeId(600);
+    pub(crate) const LSEG: TypeId = TypeId(601);
+    pub(crate) const PATH: TypeId = TypeId(602);
+    pub(crate) const BOX: TypeId = TypeId(603);
+    pub(crate) const POLYGON: TypeId = TypeId(604);
+    pub(crate) const LINE: TypeId = TypeId(628);
+
+    pub(crate) const INT4RANGE: TypeId = TypeId(3904);
+    pub(crate) const NUMRANGE: TypeId = TypeId(3906);
+    pub(crate) const TSRANGE: TypeId = TypeId(3908);
+    pub(crate) const TSTZRANGE: TypeId = TypeId(3910);
+    pub(crate) const DATERANGE: TypeId = TypeId(3912);
+    pub(crate) const INT8RANGE: TypeId = TypeId(3926);
+
+    pub(crate) const ACLITEM: TypeId = TypeId(1033);
+    pub(crate) const GTSVECTOR: TypeId = TypeId(3642);
+
     // Arrays
 
     pub(crate) const ARRAY_BOOL: TypeId = TypeId(1000);
 
+    pub(crate) const ARRAY_CHAR: TypeId = TypeId(1002);
+    pub(crate) const ARRAY_NAME: TypeId = TypeId(1003);
+
     pub(crate) const ARRAY_INT2: TypeId = TypeId(1005);
     pub(crate) const ARRAY_INT4: TypeId = TypeId(1007);
     pub(crate) const ARRAY_INT8: TypeId = TypeId(1016);
 
+    pub(crate) const ARRAY_OID: TypeId = TypeId(1028);
+
     pub(crate) const ARRAY_FLOAT4: TypeId = TypeId(1021);
     pub(crate) const ARRAY_FLOAT8: TypeId = TypeId(1022);
 
@@ -55,6 +106,7 @@ impl TypeId {
     pub(crate) const ARRAY_TIME: TypeId = TypeId(1183);
     pub(crate) const ARRAY_TIMESTAMP: TypeId = TypeId(1115);
     pub(crate) const ARRAY_TIMESTAMPTZ: TypeId = TypeId(1185);
+    pub(crate) const ARRAY_INTERVAL: TypeId = TypeId(1187);
 
     pub(crate) const ARRAY_BYTEA: TypeId = TypeId(1001);
 
@@ -62,4 +114,123 @@ impl TypeId {
 
     pub(crate) const ARRAY_CIDR: TypeId = TypeId(651);
     pub(crate) const ARRAY_INET: TypeId = TypeId(1041);
+
+    pub(crate) const ARRAY_JSON: TypeId = TypeId(199);
+    pub(crate) const ARRAY_JSONB: TypeId = TypeId(3807);
+
+    pub(crate) const ARRAY_XML: TypeId = TypeId(143);
+
+    pub(crate) const ARRAY_POINT: TypeId = TypeId(1017);
+    pub(crate) const ARRAY_LSEG: TypeId = TypeId(1018);
+    pub(crate) const ARRAY_PATH: TypeId = TypeId(1019);
+    pub(crate) const ARRAY_BOX: TypeId = TypeId(1020);
+    pub(crate) const ARRAY_POLYGON: TypeId = TypeId(1027);
+    pub(crate) const ARRAY_LINE: TypeId = TypeId(629);
+
+    pub(crate) const ARRAY_INT4RANGE: TypeId = TypeId(3905);
+    pub(crate) const ARRAY_NUMRANGE: TypeId = TypeId(3907);
+    pub(crate) const ARRAY_TSRANGE: TypeId = TypeId(3909);
+    pub(crate) const ARRAY_TSTZRANGE: TypeId = TypeId(3911);
+    pub(crate) const ARRAY_DATERANGE: TypeId = TypeId(3913);
+    pub(crate) const ARRAY_INT8RANGE: TypeId = TypeId(3927);
+
+    pub(crate) const ARRAY_ACLITEM: TypeId = TypeId(1034);
+    pub(crate) const ARRAY_GTSVECTOR: TypeId = TypeId(3644);
+}
+
+impl TypeId {
+    /// Returns the name of a builtin type.
+    pub(crate) fn name(self) -> Option<&'static str> {
+        Some(match self {
+            TypeId::BOOL => "BOOL",
+            TypeId::CHAR => "CHAR",
+            TypeId::NAME => "NAME",
+            TypeId::INT2 => "INT2",
+            TypeId::INT4 => "INT4",
+            TypeId::INT8 => "INT8",
+            TypeId::OID => "OID",
+            TypeId::REGPROC => "REGPROC",
+            TypeId::REGPROCEDURE => "REGPROCEDURE",
+            TypeId::REGOPER => "REGOPER",
+            TypeId::REGOPERATOR => "REGOPERATOR",
+            TypeId::REGCLASS => "REGCLASS",
+            TypeId::REGTYPE => "REGTYPE",
+            TypeId::REGCONFIG => "REGCONFIG",
+            TypeId::REGDICTIONARY => "REGDICTIONARY",
+            TypeId::REGNAMESPACE => "REGNAMESPACE",
+            TypeId::REGROLE => "REGROLE",
+            TypeId::FLOAT4 => "FLOAT4",
+            TypeId::FLOAT8 => "FLOAT8",
+            TypeId::NUMERIC => "NUMERIC",
+            TypeId::TEXT => "TEXT",
+            TypeId::BPCHAR => "BPCHAR",
+            TypeId::VARCHAR => "VARCHAR",
+            TypeId::UNKNOWN => "UNKNOWN",
+            TypeId::VOID => "VOID",
+            TypeId::DATE => "DATE",
+            TypeId::TIME => "TIME",
+            TypeId::TIMESTAMP => "TIMESTAMP",
+            TypeId::TIMESTAMPTZ => "TIMESTAMPTZ",
+            TypeId::INTERVAL => "INTERVAL",
+            TypeId::BYTEA => "BYTEA",
+            TypeId::UUID => "UUID",
+            TypeId::CIDR => "CIDR",
+            TypeId::INET => "INET",
+            TypeId::JSON => "JSON",
+            TypeId::JSONB => "JSONB",
+            TypeId::XML => "XML",
+            TypeId::POINT => "POINT",
+            TypeId::LSEG => "LSEG",
+            TypeId::PATH => "PATH",
+            TypeId::BOX => "BOX",
+            TypeId::POLYGON => "POLYGON",
+            TypeId::LINE => "LINE",
+            TypeId::INT4RANGE => "INT4RANGE",
+            TypeId::NUMRANGE => "NUMRANGE",
+            TypeId::TSRANGE => "TSRANGE",
+            TypeId::TSTZRANGE => "TSTZRANGE",
+            TypeId::DATERANGE => "DATERANGE",
+            TypeId::INT8RANGE => "INT8RANGE",
+            TypeId::ACLITEM => "ACLITEM",
+            TypeId::GTSVECTOR => "GTSVECTOR",
+            TypeId::ARRAY_BOOL => "BOOL[]",
+            TypeId::ARRAY_CHAR => "CHAR[]",
+            TypeId::ARRAY_NAME => "NAME[]",
+            TypeId::ARRAY_INT2 => "INT2[]",
+            TypeId::ARRAY_INT4 => "INT4[]",
+            TypeId::ARRAY_INT8 => "INT8[]",
+            TypeId::ARRAY_OID => "OID[]",
+            TypeId::ARRAY_FLOAT4 => "FLOAT4[]",
+            TypeId::ARRAY_FLOAT8 => "FLOAT8[]",
+            TypeId::ARRAY_TEXT => "TEXT[]",
+            TypeId::ARRAY_DATE => "DATE[]",
+            TypeId::ARRAY_TIME => "TIME[]",
+            TypeId::ARRAY_TIMESTAMP => "TIMESTAMP[]",
+            TypeId::ARRAY_TIMESTAMPTZ => "TIMESTAMPTZ[]",
+            TypeId::ARRAY_INTERVAL => "INTERVAL[]",
+            TypeId::ARRAY_BYTEA => "BYTEA[]",
+            TypeId::ARRAY_UUID => "UUID[]",
+            TypeId::ARRAY_CIDR => "CIDR[]",
+            TypeId::ARRAY_INET => "INET[]",
+            TypeId::ARRAY_JSON => "JSON[]",
+            TypeId::ARRAY_JSONB => "JSONB[]",
+            TypeId::ARRAY_XML => "XML[]",
+            TypeId::ARRAY_POINT => "POINT[]",
+            TypeId::ARRAY_LSEG => "LSEG[]",
+            TypeId::ARRAY_PATH => "PATH[]",
+            TypeId::ARRAY_BOX => "BOX[]",
+            TypeId::ARRAY_POLYGON => "POLYGON[]",
+            TypeId::ARRAY_LINE => "LINE[]",
+            TypeId::ARRAY_INT4RANGE => "INT4RANGE[]",
+            TypeId::ARRAY_NUMRANGE => "NUMRANGE[]",
+            TypeId::ARRAY_TSRANGE => "TSRANGE[]",
+            TypeId::ARRAY_TSTZRANGE => "TSTZRANGE[]",
+            TypeId::ARRAY_DATERANGE => "DATERANGE[]",
+            TypeId::ARRAY_INT8RANGE => "INT8RANGE[]",
+            TypeId::ARRAY_ACLITEM => "ACLITEM[]",
+            TypeId::ARRAY_GTSVECTOR => "GTSVECTOR[]",
+
+            _ => return None,
+        })
+    }
 }
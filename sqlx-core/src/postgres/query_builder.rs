@@ -0,0 +1,876 @@
+use std::fmt::{Display, Write};
+use std::mem;
+
+use crate::arguments::Arguments;
+use crate::encode::Encode;
+use crate::postgres::types::HasArrayType;
+use crate::postgres::{PgArguments, Postgres};
+use crate::query::{query, Query};
+use crate::types::Type;
+
+// Postgres sends the number of bind parameters as a 16-bit integer
+const MAX_PARAMETERS: usize = u16::MAX as usize;
+
+/// Builds a query, such as a multi-row `INSERT ... VALUES`, whose number of bind
+/// parameters is only known at runtime.
+///
+/// ```rust,ignore
+/// let mut builder = PgQueryBuilder::new("INSERT INTO users (id, name) ");
+///
+/// builder.push_values(users, |mut row, user| {
+///     row.push_bind(user.id).push_bind(user.name);
+/// })?;
+///
+/// builder.build().execute(&mut conn).await?;
+/// ```
+pub struct PgQueryBuilder {
+    query: String,
+    arguments: PgArguments,
+    // length of the leading SQL given to `new`, kept by `reset`
+    init_len: usize,
+    built: bool,
+}
+
+/// A fragment of SQL made of trusted SQL text and bound values; appended to a query with
+/// [`PgQueryBuilder::push_sql`].
+///
+/// The text must be a `&'static str`, so it cannot be assembled from user input, and it is
+/// checked to not leave a quoted string, quoted identifier or comment open and to not contain
+/// placeholders; values can only be bound as parameters. Placeholders are numbered when the
+/// fragment is appended to a query, so fragments can be built separately and combined.
+///
+/// ```rust,ignore
+/// let mut filter = PgSql::new("TRUE")?;
+///
+/// if let Some(name) = name {
+///     filter.push(" AND name = ")?.push_bind(name);
+/// }
+///
+/// if let Some(age) = min_age {
+///     filter.push(" AND age >= ")?.push_bind(age);
+/// }
+///
+/// let mut builder = PgQueryBuilder::new("SELECT * FROM users WHERE ");
+/// builder.push_sql(filter)?;
+/// ```
+#[derive(Default)]
+pub struct PgSql {
+    parts: Vec<PgSqlPart>,
+    arguments: PgArguments,
+}
+
+enum PgSqlPart {
+    Sql(&'static str),
+    Bind,
+}
+
+/// The columns of an `unnest(..)`; given to the closure of [`PgQueryBuilder::push_unnest`].
+pub struct PgUnnestColumns<'b> {
+    builder: &'b mut PgQueryBuilder,
+    len: Option<usize>,
+    // the first column that could not be bound; nothing is bound after it
+    error: Option<crate::Error>,
+}
+
+/// A single row of a `VALUES` list; returned by [`PgQueryBuilder::push_values`].
+pub struct PgValuesRow<'b> {
+    builder: &'b mut PgQueryBuilder,
+    pushed: bool,
+}
+
+impl PgQueryBuilder {
+    /// Starts a new query with `init` as its leading SQL.
+    pub fn new(init: impl Into<String>) -> Self {
+        let query = init.into();
+
+        Self {
+            init_len: query.len(),
+            query,
+            arguments: PgArguments::default(),
+            built: false,
+        }
+    }
+
+    /// Appends raw SQL to the query.
+    ///
+    /// The SQL is not escaped in any way; use [`push_bind`](Self::push_bind) for values.
+    pub fn push(&mut self, sql: impl Display) -> &mut Self {
+        self.check_not_built();
+
+        let _ = write!(self.query, "{}", sql);
+        self
+    }
+
+    /// Binds `value` as the next parameter and appends its placeholder (`$N`) to the query.
+    ///
+    /// Returns an error, without binding `value`, if the query already has the 65535
+    /// parameters Postgres allows.
+    pub fn push_bind<T>(&mut self, value: T) -> crate::Result<&mut Self>
+    where
+        T: Type<Postgres>,
+        T: Encode<Postgres>,
+    {
+        self.check_parameters(1)?;
+
+        Ok(self.bind(value))
+    }
+
+    /// Appends a [`PgSql`] fragment to the query, binding its values as the next parameters.
+    ///
+    /// Returns an error, without appending the fragment, if the query would end up with more
+    /// than the 65535 parameters Postgres allows.
+    pub fn push_sql(&mut self, sql: PgSql) -> crate::Result<&mut Self> {
+        self.check_not_built();
+        self.check_parameters(sql.arguments.types.len())?;
+
+        let mut index = self.arguments.types.len();
+
+        for part in sql.parts {
+            match part {
+                PgSqlPart::Sql(sql) => self.query.push_str(sql),
+
+                PgSqlPart::Bind => {
+                    index += 1;
+                    let _ = write!(self.query, "${}", index);
+                }
+            }
+        }
+
+        append_arguments(&mut self.arguments, sql.arguments);
+        Ok(self)
+    }
+
+    /// Appends `VALUES (..), (..), ...` with one row for each item of `rows`.
+    ///
+    /// The columns of each row are bound by `push_row`. Returns an error if `rows` is empty
+    /// or if the query would end up with more than the 65535 parameters Postgres allows,
+    /// leaving the query as it was before this call.
+    pub fn push_values<I, F>(&mut self, rows: I, mut push_row: F) -> crate::Result<&mut Self>
+    where
+        I: IntoIterator,
+        F: FnMut(PgValuesRow<'_>, I::Item),
+    {
+        self.check_not_built();
+
+        let query_len = self.query.len();
+        let arguments_len = self.arguments.types.len();
+        let values_len = self.arguments.values.len();
+
+        self.query.push_str("VALUES ");
+
+        let mut empty = true;
+
+        for row in rows {
+            if !empty {
+                self.query.push_str(", ");
+            }
+
+            empty = false;
+
+            self.query.push('(');
+
+            push_row(
+                PgValuesRow {
+                    builder: self,
+                    pushed: false,
+                },
+                row,
+            );
+
+            self.query.push(')');
+
+            if let Err(error) = self.check_parameters(0) {
+                self.query.truncate(query_len);
+                self.arguments.truncate(arguments_len, values_len);
+
+                return Err(error);
+            }
+        }
+
+        if empty {
+            // a bare `VALUES` would only fail on the server, as a syntax error
+            self.query.truncate(query_len);
+
+            return Err(crate::Error::EmptyList { list: "VALUES" });
+        }
+
+        Ok(self)
+    }
+
+    /// Appends `SELECT * FROM unnest($1, $2, ...)` with one array parameter for each column
+    /// bound by `push_columns`.
+    ///
+    /// This inserts any number of rows with a fixed number of parameters:
+    ///
+    /// ```rust,ignore
+    /// let mut builder = PgQueryBuilder::new("INSERT INTO users (id, name) ");
+    ///
+    /// builder.push_unnest(|columns| {
+    ///     columns.push_bind(&ids).push_bind(&names);
+    /// })?;
+    /// ```
+    ///
+    /// Returns an error if no column is bound, if the arrays do not all have the same length
+    /// or if the query would end up with more than the 65535 parameters Postgres allows,
+    /// leaving the query as it was before this call.
+    pub fn push_unnest<F>(&mut self, push_columns: F) -> crate::Result<&mut Self>
+    where
+        F: FnOnce(&mut PgUnnestColumns<'_>),
+    {
+        self.check_not_built();
+
+        let query_len = self.query.len();
+        let arguments_len = self.arguments.types.len();
+        let values_len = self.arguments.values.len();
+
+        self.query.push_str("SELECT * FROM unnest(");
+
+        let mut columns = PgUnnestColumns {
+            builder: self,
+            len: None,
+            error: None,
+        };
+
+        push_columns(&mut columns);
+
+        let error = match (columns.error, columns.len) {
+            (Some(error), _) => Some(error),
+            // `unnest()` would only fail on the server
+            (None, None) => Some(crate::Error::EmptyList { list: "unnest" }),
+            (None, Some(_)) => None,
+        };
+
+        if let Some(error) = error {
+            // the columns are only checked as they are bound; undo the half-written call
+            self.query.truncate(query_len);
+            self.arguments.truncate(arguments_len, values_len);
+
+            return Err(error);
+        }
+
+        self.query.push(')');
+
+        Ok(self)
+    }
+
+    /// Returns the SQL built so far.
+    pub fn sql(&self) -> &str {
+        &self.query
+    }
+
+    /// Returns the query with all of the bound parameters, ready to be executed.
+    ///
+    /// The parameters are moved into the returned query, so the builder must be
+    /// [`reset`](Self::reset) before it is used again.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the builder has already been built and not reset since.
+    pub fn build(&mut self) -> Query<'_, Postgres> {
+        self.check_not_built();
+        self.built = true;
+
+        query(&self.query).bind_all(mem::take(&mut self.arguments))
+    }
+
+    /// Truncates the query back to the leading SQL given to [`new`](Self::new) and removes
+    /// all bound parameters, so the builder can be used to build another query (e.g. the next
+    /// batch of a bulk insert) without allocating again.
+    pub fn reset(&mut self) -> &mut Self {
+        self.query.truncate(self.init_len);
+        self.arguments = PgArguments::default();
+        self.built = false;
+        self
+    }
+
+    // Binds `value` without checking the number of parameters; callers check it themselves
+    fn bind<T>(&mut self, value: T) -> &mut Self
+    where
+        T: Type<Postgres>,
+        T: Encode<Postgres>,
+    {
+        self.check_not_built();
+        self.arguments.add(value);
+
+        let _ = write!(self.query, "${}", self.arguments.types.len());
+        self
+    }
+
+    fn check_parameters(&self, additional: usize) -> crate::Result<()> {
+        let len = self.arguments.types.len() + additional;
+
+        if len > MAX_PARAMETERS {
+            return Err(crate::Error::TooManyParameters {
+                len,
+                max: MAX_PARAMETERS,
+            });
+        }
+
+        Ok(())
+    }
+
+    fn check_not_built(&self) {
+        assert!(
+            !self.built,
+            "PgQueryBuilder must be reset before it is reused after `build()`"
+        );
+    }
+}
+
+impl PgSql {
+    /// Starts a new fragment with `sql` as its leading SQL.
+    ///
+    /// Returns an error if `sql` is not a valid fragment; see [`push`](Self::push).
+    pub fn new(sql: &'static str) -> crate::Result<Self> {
+        let mut fragment = Self::default();
+        fragment.push(sql)?;
+
+        Ok(fragment)
+    }
+
+    /// Appends SQL text to the fragment.
+    ///
+    /// Returns an error if `sql` leaves a quoted string, quoted identifier or comment open or
+    /// contains a placeholder (`$N`); values must be bound with [`push_bind`](Self::push_bind).
+    pub fn push(&mut self, sql: &'static str) -> crate::Result<&mut Self> {
+        check_fragment(sql).map_err(|reason| crate::Error::InvalidSqlFragment {
+            sql: sql.into(),
+            reason,
+        })?;
+
+        self.parts.push(PgSqlPart::Sql(sql));
+
+        Ok(self)
+    }
+
+    /// Binds `value` as the next parameter of the fragment.
+    pub fn push_bind<T>(&mut self, value: T) -> &mut Self
+    where
+        T: Type<Postgres>,
+        T: Encode<Postgres>,
+    {
+        self.arguments.add(value);
+        self.parts.push(PgSqlPart::Bind);
+        self
+    }
+
+    /// Appends another fragment, along with its bound values, to this fragment.
+    pub fn append(&mut self, other: PgSql) -> &mut Self {
+        self.parts.extend(other.parts);
+
+        append_arguments(&mut self.arguments, other.arguments);
+        self
+    }
+}
+
+fn append_arguments(arguments: &mut PgArguments, other: PgArguments) {
+    if arguments.error.is_none() {
+        let offset = arguments.types.len();
+        arguments.error = other.error.map(|(index, error)| (offset + index, error));
+    }
+
+    // each value is written with its own length prefix so the buffers can be concatenated
+    arguments.types.extend(other.types);
+    arguments.values.extend(other.values);
+    arguments.formats.extend(other.formats);
+}
+
+fn is_ident_char(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b == b'$' || b >= 0x80
+}
+
+// Checks that a fragment of SQL closes everything it opens and has no placeholders, following
+// the lexical rules of Postgres
+// https://www.postgresql.org/docs/current/sql-syntax-lexical.html
+fn check_fragment(sql: &str) -> Result<(), &'static str> {
+    let bytes = sql.as_bytes();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        i = match bytes[i] {
+            b'\'' => {
+                // backslash escapes are only recognized in escape strings, E'..'
+                let escapes = i > 0
+                    && (bytes[i - 1] == b'E' || bytes[i - 1] == b'e')
+                    && (i < 2 || !is_ident_char(bytes[i - 2]));
+
+                skip_quoted(bytes, i + 1, b'\'', escapes).ok_or("unterminated quoted string")?
+            }
+
+            b'"' => {
+                skip_quoted(bytes, i + 1, b'"', false).ok_or("unterminated quoted identifier")?
+            }
+
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                match bytes[i..].iter().position(|&b| b == b'\n') {
+                    Some(end) => i + end + 1,
+                    None => bytes.len(),
+                }
+            }
+
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                skip_block_comment(bytes, i + 2).ok_or("unterminated comment")?
+            }
+
+            // `$` is allowed within identifiers
+            b'$' if i > 0 && is_ident_char(bytes[i - 1]) => i + 1,
+
+            b'$' if matches!(bytes.get(i + 1), Some(b'0'..=b'9')) => {
+                return Err("contains a placeholder; bind values with `push_bind` instead");
+            }
+
+            b'$' => skip_dollar_quoted(sql, i).ok_or("unterminated dollar-quoted string")?,
+
+            _ => i + 1,
+        };
+    }
+
+    Ok(())
+}
+
+// Returns the index after the closing quote
+fn skip_quoted(bytes: &[u8], mut i: usize, quote: u8, escapes: bool) -> Option<usize> {
+    while i < bytes.len() {
+        if escapes && bytes[i] == b'\\' {
+            i += 2;
+        } else if bytes[i] == quote {
+            // a doubled quote is an escaped quote
+            if bytes.get(i + 1) != Some(&quote) {
+                return Some(i + 1);
+            }
+
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+
+    None
+}
+
+// Returns the index after the end of the comment; block comments nest
+fn skip_block_comment(bytes: &[u8], mut i: usize) -> Option<usize> {
+    let mut depth = 1;
+
+    while i + 1 < bytes.len() {
+        match &bytes[i..i + 2] {
+            b"/*" => {
+                depth += 1;
+                i += 2;
+            }
+
+            b"*/" => {
+                depth -= 1;
+                i += 2;
+
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+
+            _ => i += 1,
+        }
+    }
+
+    None
+}
+
+// Returns the index after the closing tag of a dollar-quoted string starting at `i`, or the
+// index after the `$` if it does not start a dollar-quoted string
+fn skip_dollar_quoted(sql: &str, i: usize) -> Option<usize> {
+    let bytes = sql.as_bytes();
+    let mut end = i + 1;
+
+    while end < bytes.len() && bytes[end] != b'$' && is_ident_char(bytes[end]) {
+        end += 1;
+    }
+
+    if bytes.get(end) != Some(&b'$') {
+        return Some(i + 1);
+    }
+
+    let tag = &sql[i..=end];
+
+    sql[end + 1..]
+        .find(tag)
+        .map(|pos| end + 1 + pos + tag.len())
+}
+
+impl PgUnnestColumns<'_> {
+    /// Binds `values` as the array of the next column.
+    pub fn push_bind<T>(&mut self, values: &[T]) -> &mut Self
+    where
+        T: HasArrayType + Type<Postgres> + Encode<Postgres>,
+    {
+        if self.error.is_some() {
+            return self;
+        }
+
+        if let Err(error) = self.builder.check_parameters(1) {
+            self.error = Some(error);
+            return self;
+        }
+
+        match self.len {
+            None => {}
+
+            Some(len) if len == values.len() => {
+                self.builder.query.push_str(", ");
+            }
+
+            Some(len) => {
+                self.error = Some(crate::Error::ArrayLengthMismatch {
+                    expected: len,
+                    found: values.len(),
+                });
+
+                return self;
+            }
+        }
+
+        self.len = Some(values.len());
+        self.builder.bind(values);
+        self
+    }
+}
+
+impl PgValuesRow<'_> {
+    /// Binds `value` as the next column of this row.
+    pub fn push_bind<T>(&mut self, value: T) -> &mut Self
+    where
+        T: Type<Postgres>,
+        T: Encode<Postgres>,
+    {
+        if self.pushed {
+            self.builder.query.push_str(", ");
+        }
+
+        self.pushed = true;
+        self.builder.bind(value);
+        self
+    }
+
+    /// Appends raw SQL, such as `DEFAULT`, as the next column of this row.
+    pub fn push_unbound(&mut self, sql: impl Display) -> &mut Self {
+        if self.pushed {
+            self.builder.query.push_str(", ");
+        }
+
+        self.pushed = true;
+        self.builder.push(sql);
+        self
+    }
+}
+
+#[test]
+fn test_push_values() {
+    let mut builder = PgQueryBuilder::new("INSERT INTO users (id, name) ");
+
+    builder
+        .push_values(vec![(1, "a"), (2, "b")], |mut row, (id, name)| {
+            row.push_bind(id).push_bind(name);
+        })
+        .unwrap()
+        .push(" RETURNING id");
+
+    assert_eq!(
+        builder.sql(),
+        "INSERT INTO users (id, name) VALUES ($1, $2), ($3, $4) RETURNING id"
+    );
+}
+
+#[test]
+fn test_push_values_too_many_parameters() {
+    let mut builder = PgQueryBuilder::new("INSERT INTO ids (id) ");
+
+    let res = builder.push_values(0..=MAX_PARAMETERS as i32, |mut row, id| {
+        row.push_bind(id);
+    });
+
+    assert!(matches!(
+        res,
+        Err(crate::Error::TooManyParameters { len: 65536, .. })
+    ));
+
+    // the builder is left as it was
+    assert_eq!(builder.sql(), "INSERT INTO ids (id) ");
+    assert!(builder.arguments.is_empty());
+    assert!(builder.arguments.values.is_empty());
+}
+
+#[test]
+fn test_push_values_empty() {
+    let mut builder = PgQueryBuilder::new("INSERT INTO ids (id) ");
+
+    let res = builder.push_values(Vec::<i32>::new(), |mut row, id| {
+        row.push_bind(id);
+    });
+
+    assert!(matches!(
+        res,
+        Err(crate::Error::EmptyList { list: "VALUES" })
+    ));
+
+    // the builder is left as it was
+    assert_eq!(builder.sql(), "INSERT INTO ids (id) ");
+    assert!(builder.arguments.is_empty());
+}
+
+#[test]
+fn test_push_bind_too_many_parameters() {
+    let mut builder = PgQueryBuilder::new("SELECT ");
+
+    for i in 0..MAX_PARAMETERS as i32 {
+        builder.push_bind(i).unwrap();
+    }
+
+    let len = builder.sql().len();
+
+    assert!(matches!(
+        builder.push_bind(0),
+        Err(crate::Error::TooManyParameters { len: 65536, .. })
+    ));
+
+    // nothing was bound or written
+    assert_eq!(builder.arguments.len(), MAX_PARAMETERS);
+    assert_eq!(builder.sql().len(), len);
+
+    let mut fragment = PgSql::new("").unwrap();
+    fragment.push_bind(1).push(" + ").unwrap().push_bind(2);
+
+    let mut builder = PgQueryBuilder::new("SELECT ");
+
+    for i in 0..(MAX_PARAMETERS - 1) as i32 {
+        builder.push_bind(i).unwrap();
+    }
+
+    assert!(matches!(
+        builder.push_sql(fragment),
+        Err(crate::Error::TooManyParameters { len: 65536, .. })
+    ));
+}
+
+#[test]
+fn test_reset() {
+    let mut builder = PgQueryBuilder::new("INSERT INTO ids (id) ");
+
+    builder
+        .push_values(vec![1, 2], |mut row, id| {
+            row.push_bind(id);
+        })
+        .unwrap();
+
+    let _ = builder.build();
+
+    builder
+        .reset()
+        .push_values(vec![3], |mut row, id| {
+            row.push_bind(id);
+        })
+        .unwrap();
+
+    assert_eq!(builder.sql(), "INSERT INTO ids (id) VALUES ($1)");
+    assert_eq!(builder.arguments.len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "PgQueryBuilder must be reset")]
+fn test_build_twice() {
+    let mut builder = PgQueryBuilder::new("SELECT ");
+    builder.push_bind(1).unwrap();
+
+    let _ = builder.build();
+    let _ = builder.build();
+}
+
+#[test]
+#[should_panic(expected = "PgQueryBuilder must be reset")]
+fn test_push_sql_after_build() {
+    let mut builder = PgQueryBuilder::new("SELECT ");
+    builder.push_bind(1).unwrap();
+
+    let _ = builder.build();
+
+    let mut fragment = PgSql::new(" + ").unwrap();
+    fragment.push_bind(2);
+
+    let _ = builder.push_sql(fragment);
+}
+
+#[test]
+fn test_push_unnest() {
+    let mut builder = PgQueryBuilder::new("INSERT INTO users (id, name) ");
+
+    builder
+        .push_unnest(|columns| {
+            columns.push_bind(&[1, 2]).push_bind(&["a", "b"]);
+        })
+        .unwrap();
+
+    assert_eq!(
+        builder.sql(),
+        "INSERT INTO users (id, name) SELECT * FROM unnest($1, $2)"
+    );
+
+    let mut builder = PgQueryBuilder::new("INSERT INTO users (id, name) ");
+
+    let res = builder.push_unnest(|columns| {
+        columns.push_bind(&[1, 2]).push_bind(&["a"]);
+    });
+
+    assert!(matches!(
+        res,
+        Err(crate::Error::ArrayLengthMismatch {
+            expected: 2,
+            found: 1
+        })
+    ));
+
+    // the builder is left as it was, so it can still be used
+    assert_eq!(builder.sql(), "INSERT INTO users (id, name) ");
+    assert!(builder.arguments.is_empty());
+    assert!(builder.arguments.values.is_empty());
+
+    builder
+        .push_unnest(|columns| {
+            columns.push_bind(&[1]).push_bind(&["a"]);
+        })
+        .unwrap();
+
+    assert_eq!(
+        builder.sql(),
+        "INSERT INTO users (id, name) SELECT * FROM unnest($1, $2)"
+    );
+}
+
+#[test]
+fn test_push_unnest_no_columns() {
+    let mut builder = PgQueryBuilder::new("INSERT INTO users (id, name) ");
+
+    let res = builder.push_unnest(|_| {});
+
+    assert!(matches!(
+        res,
+        Err(crate::Error::EmptyList { list: "unnest" })
+    ));
+
+    assert_eq!(builder.sql(), "INSERT INTO users (id, name) ");
+}
+
+#[test]
+fn test_push_unnest_too_many_parameters() {
+    let mut builder = PgQueryBuilder::new("SELECT ");
+
+    for i in 0..(MAX_PARAMETERS - 1) as i32 {
+        builder.push_bind(i).unwrap();
+    }
+
+    let len = builder.sql().len();
+
+    let res = builder.push_unnest(|columns| {
+        columns.push_bind(&[1, 2]).push_bind(&[3, 4]);
+    });
+
+    assert!(matches!(
+        res,
+        Err(crate::Error::TooManyParameters { len: 65536, .. })
+    ));
+
+    // the first column is unbound again
+    assert_eq!(builder.arguments.len(), MAX_PARAMETERS - 1);
+    assert_eq!(builder.sql().len(), len);
+}
+
+#[test]
+fn test_push_sql() {
+    fn filter(name: Option<&'static str>, min_age: Option<i32>) -> crate::Result<PgSql> {
+        let mut filter = PgSql::new("TRUE")?;
+
+        if let Some(name) = name {
+            filter.push(" AND name = ")?.push_bind(name);
+        }
+
+        if let Some(min_age) = min_age {
+            filter.push(" AND age >= ")?.push_bind(min_age);
+        }
+
+        Ok(filter)
+    }
+
+    let mut builder = PgQueryBuilder::new("SELECT * FROM users WHERE id = ");
+
+    builder
+        .push_bind(1)
+        .unwrap()
+        .push(" AND ")
+        .push_sql(filter(Some("a"), Some(18)).unwrap())
+        .unwrap()
+        .push(" AND ")
+        .push_sql(filter(None, Some(21)).unwrap())
+        .unwrap()
+        .push(" LIMIT ")
+        .push_bind(10_i64)
+        .unwrap();
+
+    assert_eq!(
+        builder.sql(),
+        "SELECT * FROM users WHERE id = $1 AND TRUE AND name = $2 AND age >= $3 \
+         AND TRUE AND age >= $4 LIMIT $5"
+    );
+
+    assert_eq!(builder.arguments.len(), 5);
+
+    let names: Vec<_> = builder
+        .arguments
+        .types()
+        .map(|ty| crate::types::TypeInfo::name(&ty).to_owned())
+        .collect();
+
+    assert_eq!(names, ["INT4", "TEXT", "INT4", "INT4", "INT8"]);
+
+    // fragments can be combined before they are numbered
+    let mut a = PgSql::new("a = ").unwrap();
+    a.push_bind(1);
+
+    let mut b = PgSql::new(" OR b = ").unwrap();
+    b.push_bind(2);
+
+    a.append(b);
+
+    let mut builder = PgQueryBuilder::new("SELECT * FROM t WHERE ");
+    builder
+        .push_bind(0)
+        .unwrap()
+        .push(" AND (")
+        .push_sql(a)
+        .unwrap()
+        .push(")");
+
+    assert_eq!(
+        builder.sql(),
+        "SELECT * FROM t WHERE $1 AND (a = $2 OR b = $3)"
+    );
+}
+
+#[test]
+fn test_check_fragment() {
+    assert!(check_fragment("name = 'it''s' AND \"we\"\"ird\" = 1").is_ok());
+    assert!(check_fragment("note = E'\\'' -- it's a comment").is_ok());
+    assert!(check_fragment("/* a /* nested */ comment */ $$a $1 'b$$ || $tag$c$tag$").is_ok());
+    assert!(check_fragment("price$1 > 0").is_ok());
+
+    assert!(check_fragment("name = 'a").is_err());
+    assert!(check_fragment("name = 'a'' OR TRUE").is_err());
+    assert!(check_fragment("\"name = 1").is_err());
+
+    // a backslash only escapes a quote in an escape string
+    assert!(check_fragment("name = 'a\\' OR TRUE").is_ok());
+    assert!(check_fragment("name = E'a\\' OR TRUE").is_err());
+
+    assert!(check_fragment("/* a /* b */").is_err());
+    assert!(check_fragment("$$ a").is_err());
+    assert!(check_fragment("id = $1").is_err());
+
+    let err = PgSql::new("id = $1").err().unwrap();
+    assert!(matches!(err, crate::Error::InvalidSqlFragment { .. }));
+}
@@ -1,17 +1,26 @@
 use core::str::{from_utf8, Utf8Error};
 
-use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::sync::Arc;
 
-use crate::error::UnexpectedNullError;
-use crate::postgres::protocol::{DataRow, TypeFormat};
-use crate::postgres::Postgres;
-use crate::row::{ColumnIndex, Row};
+use crate::decode::Decode;
+use crate::error::{MismatchedTypeError, UnexpectedNullError};
+use crate::postgres::protocol::{DataRow, TypeFormat, TypeId};
+use crate::postgres::types::{is_oid_mismatch, is_time_zone_mismatch};
+use crate::postgres::{PgTypeInfo, Postgres};
+use crate::row::{decode_column, Column, ColumnIndex, ColumnNames, Row};
+use crate::types::{Type, TypeInfo};
 
 /// A value from Postgres. This may be in a BINARY or TEXT format depending
 /// on the data type and if the query was prepared or not.
-#[derive(Debug)]
+///
+/// The common scalar types can be converted from a value with [`TryFrom`], which decodes it
+/// in either format:
+///
+/// ```rust,ignore
+/// let n: i32 = value.try_into()?;
+/// ```
+#[derive(Debug, Clone, Copy)]
 pub enum PgValue<'c> {
     Binary(&'c [u8]),
     Text(&'c str),
@@ -29,10 +38,77 @@ impl<'c> TryFrom<Option<PgValue<'c>>> for PgValue<'c> {
     }
 }
 
+macro_rules! impl_try_from_value {
+    ($($ty:ty),*) => {
+        $(
+            impl TryFrom<PgValue<'_>> for $ty {
+                type Error = crate::Error;
+
+                #[inline]
+                fn try_from(value: PgValue<'_>) -> Result<Self, Self::Error> {
+                    <$ty as Decode<Postgres>>::decode(Some(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_value!(bool, i32, i64, f64, String);
+
+/// A raw value from a [`PgRow`] along with the type of its column.
+///
+/// Returned by [`PgRow::try_get_raw_value`] to allow decoding values of types that
+/// SQLx does not support (yet) by hand.
+#[derive(Debug)]
+pub struct PgRawValue<'c> {
+    value: Option<PgValue<'c>>,
+    type_info: PgTypeInfo,
+}
+
+impl<'c> PgRawValue<'c> {
+    /// Returns the value, or `None` if it is `NULL`.
+    ///
+    /// The value is in the binary format for prepared queries and in the text format
+    /// for unprepared (simple) queries.
+    pub fn value(&self) -> Option<PgValue<'c>> {
+        self.value
+    }
+
+    /// Returns `true` if the value is `NULL`.
+    pub fn is_null(&self) -> bool {
+        self.value.is_none()
+    }
+
+    /// Returns the type of the column the value was read from.
+    ///
+    /// Only the OID of the type is known here; the name is not looked up.
+    pub fn type_info(&self) -> &PgTypeInfo {
+        &self.type_info
+    }
+}
+
 pub struct PgRow<'c> {
     pub(super) data: DataRow<'c>,
-    pub(super) columns: Arc<HashMap<Box<str>, usize>>,
+    pub(super) columns: Arc<ColumnNames>,
     pub(super) formats: Arc<[TypeFormat]>,
+    pub(super) types: Arc<[TypeId]>,
+}
+
+impl<'c> PgRow<'c> {
+    /// Returns the raw value at `index` along with the type of its column,
+    /// without attempting to decode it.
+    pub fn try_get_raw_value<'r, I>(&'r self, index: I) -> crate::Result<PgRawValue<'r>>
+    where
+        'c: 'r,
+        I: ColumnIndex<Postgres>,
+    {
+        let index = index.resolve(self)?;
+
+        Ok(PgRawValue {
+            value: self.try_get_raw(index)?,
+            type_info: PgTypeInfo::with_oid(self.types[index].0),
+        })
+    }
 }
 
 impl<'c> Row<'c> for PgRow<'c> {
@@ -42,6 +118,54 @@ impl<'c> Row<'c> for PgRow<'c> {
         self.data.len()
     }
 
+    fn column_name(&self, index: usize) -> Option<&str> {
+        self.columns.name(index)
+    }
+
+    fn column(&self, index: usize) -> Option<Column<'_, Self::Database>> {
+        let type_id = self.types.get(index)?;
+
+        Some(Column {
+            ordinal: index,
+            name: self.column_name(index),
+            type_info: Some(PgTypeInfo::with_oid(type_id.0)),
+        })
+    }
+
+    fn try_get<'r, T, I>(&'r self, index: I) -> crate::Result<T>
+    where
+        'c: 'r,
+        T: Type<Self::Database>,
+        I: ColumnIndex<Self::Database>,
+        T: Decode<'r, Self::Database>,
+    {
+        let index = index.resolve(self)?;
+        let found = PgTypeInfo::with_oid(self.types[index].0);
+        let expected = T::type_info();
+
+        // `TIMESTAMP` and `TIMESTAMPTZ` share a binary format so decoding one as the other
+        // would succeed, silently dropping or assuming a time zone; the same goes for an `OID`
+        // decoded as a signed integer, which would wrap around
+        let mismatch = if is_time_zone_mismatch(expected.id, found.id) {
+            Some("timestamps with and without a time zone are not interchangeable")
+        } else if is_oid_mismatch(expected.id, found.id) {
+            Some("an OID is unsigned and must be decoded as a `u32`")
+        } else {
+            None
+        };
+
+        if let Some(source) = mismatch {
+            return Err(crate::Error::decode(MismatchedTypeError {
+                rust_type: std::any::type_name::<T>(),
+                expected: expected.name().into(),
+                found: found.name().into(),
+                source: source.into(),
+            }));
+        }
+
+        decode_column(self.try_get_raw(index)?, &found)
+    }
+
     fn try_get_raw<'r, I>(&'r self, index: I) -> crate::Result<Option<PgValue<'r>>>
     where
         'c: 'r,
@@ -59,3 +183,41 @@ impl<'c> Row<'c> for PgRow<'c> {
             .map_err(|err: Utf8Error| crate::Error::Decode(Box::new(err)))
     }
 }
+
+#[test]
+fn test_try_from_value() {
+    use std::convert::TryInto;
+
+    let n: i32 = PgValue::Binary(&[0, 0, 0, 42]).try_into().unwrap();
+    assert_eq!(n, 42);
+
+    let n: i32 = PgValue::Text("42").try_into().unwrap();
+    assert_eq!(n, 42);
+
+    let n: i64 = PgValue::Binary(&(-7_i64).to_be_bytes()).try_into().unwrap();
+    assert_eq!(n, -7);
+
+    let n: i64 = PgValue::Text("-7").try_into().unwrap();
+    assert_eq!(n, -7);
+
+    let x: f64 = PgValue::Binary(&1.5_f64.to_be_bytes()).try_into().unwrap();
+    assert_eq!(x, 1.5);
+
+    let x: f64 = PgValue::Text("1.5").try_into().unwrap();
+    assert_eq!(x, 1.5);
+
+    let s: String = PgValue::Binary(b"hello").try_into().unwrap();
+    assert_eq!(s, "hello");
+
+    let s: String = PgValue::Text("hello").try_into().unwrap();
+    assert_eq!(s, "hello");
+
+    let b: bool = PgValue::Binary(&[1]).try_into().unwrap();
+    assert!(b);
+
+    let b: bool = PgValue::Text("f").try_into().unwrap();
+    assert!(!b);
+
+    let res: Result<i32, crate::Error> = PgValue::Text("forty-two").try_into();
+    assert!(matches!(res, Err(crate::Error::Decode(_))));
+}
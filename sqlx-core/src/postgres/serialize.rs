@@ -0,0 +1,92 @@
+use std::borrow::Cow;
+
+use serde::ser::{Error as _, SerializeMap};
+use serde::{Serialize, Serializer};
+
+use crate::decode::Decode;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::{PgRow, PgValue, Postgres};
+use crate::row::Row;
+
+/// Serializes the row as a map from column name to value, for quickly turning rows into JSON.
+///
+/// Values are mapped by the type of their column: integers and floats as numbers, booleans as
+/// booleans, `BYTEA` as a base64 string, `JSON` and `JSONB` as JSON (with the `json` feature),
+/// `NULL` as `null` and text types as strings. A value of any other type is serialized as its
+/// text, which is only known for unprepared queries; prepared queries receive such values in
+/// a binary format, which is serialized as a base64 string. A column without a name is keyed
+/// by its index.
+impl Serialize for PgRow<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+
+        for index in 0..self.len() {
+            let name = self
+                .column_name(index)
+                .map_or_else(|| Cow::Owned(index.to_string()), Cow::Borrowed);
+
+            map.serialize_entry(&name, &Column { row: self, index })?;
+        }
+
+        map.end()
+    }
+}
+
+struct Column<'a, 'c> {
+    row: &'a PgRow<'c>,
+    index: usize,
+}
+
+impl Serialize for Column<'_, '_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let value = match self.row.try_get_raw(self.index).map_err(S::Error::custom)? {
+            Some(value) => value,
+            None => return serializer.serialize_none(),
+        };
+
+        match (self.row.types[self.index], value) {
+            (TypeId::BOOL, _) => serializer.serialize_bool(decode::<_, S::Error>(value)?),
+
+            (TypeId::INT2, _) => serializer.serialize_i16(decode::<_, S::Error>(value)?),
+            (TypeId::INT4, _) => serializer.serialize_i32(decode::<_, S::Error>(value)?),
+            (TypeId::INT8, _) => serializer.serialize_i64(decode::<_, S::Error>(value)?),
+            (TypeId::OID, _) => serializer.serialize_u32(decode::<_, S::Error>(value)?),
+
+            (TypeId::FLOAT4, _) => serializer.serialize_f32(decode::<_, S::Error>(value)?),
+            (TypeId::FLOAT8, _) => serializer.serialize_f64(decode::<_, S::Error>(value)?),
+
+            (TypeId::BYTEA, _) => {
+                serializer.serialize_str(&base64::encode(decode::<Vec<u8>, S::Error>(value)?))
+            }
+
+            #[cfg(feature = "json")]
+            (TypeId::JSON, _) | (TypeId::JSONB, _) => {
+                decode::<serde_json::Value, S::Error>(value)?.serialize(serializer)
+            }
+
+            (_, PgValue::Text(text)) => serializer.serialize_str(text),
+
+            (TypeId::TEXT, _)
+            | (TypeId::VARCHAR, _)
+            | (TypeId::BPCHAR, _)
+            | (TypeId::NAME, _)
+            | (TypeId::UNKNOWN, _) => serializer.serialize_str(decode::<_, S::Error>(value)?),
+
+            (_, PgValue::Binary(buf)) => serializer.serialize_str(&base64::encode(buf)),
+        }
+    }
+}
+
+fn decode<'de, T, E>(value: PgValue<'de>) -> Result<T, E>
+where
+    T: Decode<'de, Postgres>,
+    E: serde::ser::Error,
+{
+    T::decode(Some(value)).map_err(E::custom)
+}
@@ -0,0 +1,179 @@
+#[cfg(feature = "log-queries")]
+use crate::logger::QueryLogger;
+use crate::postgres::protocol::{CommandComplete, DataRow, Message, RowDescription};
+use crate::postgres::PgConnection;
+
+/// The result of one statement of a [`simple_query`](PgConnection::simple_query).
+#[derive(Debug, Clone)]
+pub struct PgSimpleQueryResult {
+    columns: Vec<Option<Box<str>>>,
+    rows: Vec<PgSimpleQueryRow>,
+    command_tag: Box<str>,
+}
+
+/// A row returned by a [`simple_query`](PgConnection::simple_query), with every value
+/// as Postgres formats it as text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PgSimpleQueryRow {
+    values: Vec<Option<String>>,
+}
+
+impl PgConnection {
+    /// Executes one or more statements with the simple query protocol, returning a result
+    /// for each statement with all values as text.
+    ///
+    /// This is intended for tools that run arbitrary SQL, such as a REPL; no statement is
+    /// prepared and no type information is needed to read the values. The statements are
+    /// executed as a single implicit transaction (unless a transaction is already open).
+    ///
+    /// ```rust,ignore
+    /// let results = conn.simple_query("SELECT 1; SELECT 'a', NULL").await?;
+    ///
+    /// assert_eq!(results[0].rows()[0].get(0), Some("1"));
+    /// assert_eq!(results[1].rows()[0].get(1), None);
+    /// ```
+    pub async fn simple_query(&mut self, query: &str) -> crate::Result<Vec<PgSimpleQueryResult>> {
+        self.wait_until_ready().await?;
+
+        self.set_error_query(query);
+        self.write_simple_query(query);
+
+        self.stream.flush().await?;
+        self.is_ready = false;
+
+        #[cfg(feature = "log-queries")]
+        let mut logger = QueryLogger::new(query, 0);
+
+        let result = async {
+            let mut results = Vec::new();
+            let mut columns = Vec::new();
+            let mut rows = Vec::new();
+
+            loop {
+                match self.stream.receive().await? {
+                    Message::RowDescription => {
+                        let rd = RowDescription::read(self.stream.buffer())?;
+
+                        columns = rd.fields.into_vec().into_iter().map(|f| f.name).collect();
+                    }
+
+                    Message::DataRow => {
+                        let data =
+                            DataRow::read(self.stream.buffer(), &mut self.current_row_values)?;
+                        let mut values = Vec::with_capacity(data.len());
+
+                        for index in 0..data.len() {
+                            values.push(match data.get(index) {
+                                Some(buf) => Some(
+                                    std::str::from_utf8(buf)
+                                        .map_err(crate::Error::decode)?
+                                        .to_owned(),
+                                ),
+
+                                None => None,
+                            });
+                        }
+
+                        #[cfg(feature = "log-queries")]
+                        logger.increment_rows();
+                        rows.push(PgSimpleQueryRow { values });
+                    }
+
+                    // Indicates that a statement has finished executing
+                    Message::CommandComplete => {
+                        let cc = CommandComplete::read(self.stream.buffer())?;
+
+                        results.push(PgSimpleQueryResult {
+                            columns: std::mem::take(&mut columns),
+                            rows: std::mem::take(&mut rows),
+                            command_tag: cc.tag,
+                        });
+                    }
+
+                    // Indicates that the query string was empty; there is no result
+                    Message::EmptyQueryResponse => {}
+
+                    Message::ReadyForQuery => {
+                        self.is_ready = true;
+                        break;
+                    }
+
+                    message => {
+                        return Err(protocol_err!(
+                            "simple_query: unexpected message: {:?}",
+                            message
+                        )
+                        .into());
+                    }
+                }
+            }
+
+            Ok(results)
+        };
+
+        match result.await {
+            Ok(results) => Ok(results),
+
+            Err(error) => {
+                #[cfg(feature = "log-queries")]
+                logger.fail(&error);
+                Err(error)
+            }
+        }
+    }
+}
+
+impl PgSimpleQueryResult {
+    /// Returns the names of the columns, in order. A column the server did not name
+    /// (`?column?`) has no name.
+    pub fn columns(&self) -> impl Iterator<Item = Option<&str>> + '_ {
+        self.columns.iter().map(|name| name.as_deref())
+    }
+
+    /// Returns the rows returned by the statement.
+    pub fn rows(&self) -> &[PgSimpleQueryRow] {
+        &self.rows
+    }
+
+    /// Returns the rows returned by the statement, consuming the result.
+    pub fn into_rows(self) -> Vec<PgSimpleQueryRow> {
+        self.rows
+    }
+
+    /// Returns the tag of the statement, e.g. `SELECT 42` or `CREATE TABLE`, as sent by
+    /// the server.
+    pub fn command_tag(&self) -> &str {
+        &self.command_tag
+    }
+}
+
+impl PgSimpleQueryRow {
+    /// Returns the number of values in the row.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the row has no values.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the value at `index`, or `None` if it is `NULL`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.values[index].as_deref()
+    }
+
+    /// Returns the values of the row, in the order of the columns.
+    pub fn values(&self) -> &[Option<String>] {
+        &self.values
+    }
+
+    /// Returns the values of the row, consuming it.
+    pub fn into_values(self) -> Vec<Option<String>> {
+        self.values
+    }
+}
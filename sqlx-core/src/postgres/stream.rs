@@ -5,7 +5,7 @@ use byteorder::NetworkEndian;
 use futures_channel::mpsc::UnboundedSender;
 
 use crate::io::{Buf, BufStream, MaybeTlsStream};
-use crate::postgres::protocol::{Message, NotificationResponse, Response, Write};
+use crate::postgres::protocol::{Message, NotificationResponse, ParameterStatus, Response, Write};
 use crate::postgres::PgError;
 use crate::url::Url;
 use futures_util::SinkExt;
@@ -18,6 +18,12 @@ pub struct PgStream {
     // Is referenced by our buffered stream
     // Is initialized to ReadyForQuery/0 at the start
     pub(super) message: (Message, u32),
+
+    // Text of the query most recently sent, attached to errors reported for it
+    pub(super) query: Option<Box<str>>,
+
+    // Value of the `server_version` parameter reported by the server
+    pub(super) server_version: Option<Box<str>>,
 }
 
 impl PgStream {
@@ -28,6 +34,8 @@ impl PgStream {
             notifications: None,
             stream: BufStream::new(stream),
             message: (Message::ReadyForQuery, 0),
+            query: None,
+            server_version: None,
         })
     }
 
@@ -85,8 +93,19 @@ impl PgStream {
                     let response = Response::read(self.stream.buffer())?;
 
                     if response.severity.is_error() {
+                        let is_lock_timeout = &*response.code == "55P03";
+
+                        let error = Box::new(PgError {
+                            response,
+                            query: self.query.clone(),
+                        });
+
                         // This is an error, bubble up as one immediately
-                        return Err(crate::Error::Database(Box::new(PgError(response))));
+                        return Err(if is_lock_timeout {
+                            crate::Error::LockTimeout(error)
+                        } else {
+                            crate::Error::Database(error)
+                        });
                     }
 
                     // TODO: Provide some way of receiving these non-critical
@@ -94,6 +113,19 @@ impl PgStream {
                     continue;
                 }
 
+                // sent during startup and whenever a reported run-time parameter changes,
+                // such as after a `SET application_name` or `DISCARD ALL`; of these we only
+                // track the server version
+                Message::ParameterStatus => {
+                    let status = ParameterStatus::read(self.stream.buffer())?;
+
+                    if status.name == "server_version" {
+                        self.server_version = Some(status.value.into());
+                    }
+
+                    continue;
+                }
+
                 Message::NotificationResponse => {
                     if let Some(buffer) = &mut self.notifications {
                         let notification = NotificationResponse::read(self.stream.buffer())?;
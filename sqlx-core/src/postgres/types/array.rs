@@ -0,0 +1,401 @@
+use std::convert::{TryFrom, TryInto};
+
+use byteorder::{NetworkEndian, ReadBytesExt};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::Error;
+use crate::io::BufMut;
+use crate::postgres::types::PgTypeInfo;
+use crate::postgres::{PgValue, Postgres};
+use crate::types::Type;
+
+/// Provides the type information of a one-dimensional Postgres array of `Self`.
+///
+/// Every type that implements this (alongside [`Type`], [`Encode`] and [`Decode`]) can
+/// be bound as a `&[T]`, `Vec<T>` or `[T; N]` and decoded as a `Vec<T>` or `[T; N]`.
+/// `Option<T>` can be used as the element type for arrays that contain `NULL`.
+///
+/// Types derived with `#[derive(sqlx::Type)]` implement this when the OID of their array
+/// type is given with `#[sqlx(postgres(oid = .., array_oid = ..))]`.
+pub trait HasArrayType {
+    fn array_type_info() -> PgTypeInfo;
+}
+
+impl<T> HasArrayType for Option<T>
+where
+    T: HasArrayType,
+{
+    fn array_type_info() -> PgTypeInfo {
+        T::array_type_info()
+    }
+}
+
+impl<T> Type<Postgres> for [T]
+where
+    T: HasArrayType,
+{
+    fn type_info() -> PgTypeInfo {
+        T::array_type_info()
+    }
+}
+
+impl<T> Type<Postgres> for Vec<T>
+where
+    T: HasArrayType,
+{
+    fn type_info() -> PgTypeInfo {
+        T::array_type_info()
+    }
+}
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/arrayfuncs.c
+// `array_send` writes the number of dimensions, a has-nulls flag and the element OID,
+// followed by the length and lower bound of each dimension and then the length-prefixed
+// elements
+
+impl<T> Encode<Postgres> for [T]
+where
+    T: HasArrayType + Type<Postgres> + Encode<Postgres>,
+{
+    fn encode(&self, buf: &mut Vec<u8>) {
+        // an empty array has no dimensions
+        buf.put_i32::<NetworkEndian>(if self.is_empty() { 0 } else { 1 });
+
+        // the has-nulls flag is ignored by Postgres on input
+        buf.put_i32::<NetworkEndian>(0);
+        buf.put_u32::<NetworkEndian>(T::type_info().id.0);
+
+        if self.is_empty() {
+            return;
+        }
+
+        buf.put_i32::<NetworkEndian>(self.len() as i32);
+        buf.put_i32::<NetworkEndian>(1);
+
+        for element in self {
+            // write zeros for length
+            buf.put_i32::<NetworkEndian>(0);
+
+            let start = buf.len();
+            let len = if let IsNull::No = element.encode_nullable(buf) {
+                (buf.len() - start) as i32
+            } else {
+                -1
+            };
+
+            // replaces zeros with actual length
+            buf[start - 4..start].copy_from_slice(&len.to_be_bytes());
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        20 + self
+            .iter()
+            .map(|element| 4 + element.size_hint())
+            .sum::<usize>()
+    }
+
+    fn check_encode(&self) -> crate::Result<()> {
+        self.iter().try_for_each(Encode::check_encode)
+    }
+}
+
+impl<T> Encode<Postgres> for Vec<T>
+where
+    T: HasArrayType + Type<Postgres> + Encode<Postgres>,
+{
+    fn encode(&self, buf: &mut Vec<u8>) {
+        <[T] as Encode<Postgres>>::encode(self, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        <[T] as Encode<Postgres>>::size_hint(self)
+    }
+
+    fn check_encode(&self) -> crate::Result<()> {
+        <[T] as Encode<Postgres>>::check_encode(self)
+    }
+}
+
+// Elements in the text format may be unescaped into a temporary buffer so they
+// cannot borrow from the row
+
+impl<'de, T> Decode<'de, Postgres> for Vec<T>
+where
+    T: HasArrayType + for<'a> Decode<'a, Postgres>,
+{
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
+        let mut elements = Vec::new();
+
+        decode_elements(value, |element| elements.push(element))?;
+
+        Ok(elements)
+    }
+}
+
+impl<T, const N: usize> Type<Postgres> for [T; N]
+where
+    T: HasArrayType,
+{
+    fn type_info() -> PgTypeInfo {
+        T::array_type_info()
+    }
+}
+
+impl<T, const N: usize> Encode<Postgres> for [T; N]
+where
+    T: HasArrayType + Type<Postgres> + Encode<Postgres>,
+{
+    fn encode(&self, buf: &mut Vec<u8>) {
+        <[T] as Encode<Postgres>>::encode(self, buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        <[T] as Encode<Postgres>>::size_hint(self)
+    }
+
+    fn check_encode(&self) -> crate::Result<()> {
+        <[T] as Encode<Postgres>>::check_encode(self)
+    }
+}
+
+// Decodes the elements into a `Vec` first; the array must have exactly `N` elements
+impl<'de, T, const N: usize> Decode<'de, Postgres> for [T; N]
+where
+    T: HasArrayType + for<'a> Decode<'a, Postgres>,
+{
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
+        let elements = <Vec<T> as Decode<Postgres>>::decode(value)?;
+
+        <[T; N]>::try_from(elements).map_err(|elements| {
+            Error::Decode(
+                format!(
+                    "expected an array of {} elements, got {}",
+                    N,
+                    elements.len()
+                )
+                .into(),
+            )
+        })
+    }
+}
+
+fn decode_elements<T>(value: Option<PgValue<'_>>, push: impl FnMut(T)) -> crate::Result<()>
+where
+    T: for<'a> Decode<'a, Postgres>,
+{
+    match value.try_into()? {
+        PgValue::Binary(buf) => decode_binary(buf, push),
+        PgValue::Text(s) => decode_text(s, push),
+    }
+}
+
+fn decode_binary<T>(mut buf: &[u8], mut push: impl FnMut(T)) -> crate::Result<()>
+where
+    T: for<'a> Decode<'a, Postgres>,
+{
+    let ndim = buf.read_i32::<NetworkEndian>().map_err(Error::decode)?;
+    let _flags = buf.read_i32::<NetworkEndian>().map_err(Error::decode)?;
+    let _element_oid = buf.read_u32::<NetworkEndian>().map_err(Error::decode)?;
+
+    match ndim {
+        0 => return Ok(()),
+        1 => {}
+
+        _ => {
+            return Err(Error::Decode(
+                format!("expected a one-dimensional array, got {} dimensions", ndim).into(),
+            ))
+        }
+    }
+
+    let len = buf.read_i32::<NetworkEndian>().map_err(Error::decode)?;
+    let _lower_bound = buf.read_i32::<NetworkEndian>().map_err(Error::decode)?;
+
+    for _ in 0..len {
+        let element_len = buf.read_i32::<NetworkEndian>().map_err(Error::decode)?;
+
+        let element = if element_len < 0 {
+            T::decode(None)?
+        } else if element_len as usize > buf.len() {
+            return Err(Error::Decode(
+                format!("invalid length of array element: {}", element_len).into(),
+            ));
+        } else {
+            let (element, rest) = buf.split_at(element_len as usize);
+            buf = rest;
+
+            T::decode(Some(PgValue::Binary(element)))?
+        };
+
+        push(element);
+    }
+
+    Ok(())
+}
+
+// https://www.postgresql.org/docs/current/arrays.html#ARRAYS-IO
+fn decode_text<T>(s: &str, mut push: impl FnMut(T)) -> crate::Result<()>
+where
+    T: for<'a> Decode<'a, Postgres>,
+{
+    // arrays with a lower bound other than 1 are prefixed with their dimensions,
+    // e.g. `[0:1]={1,2}`
+    let s = match s.find('=') {
+        Some(index) if s.starts_with('[') => &s[index + 1..],
+        _ => s,
+    };
+
+    if !s.starts_with('{') || !s.ends_with('}') || s.len() < 2 {
+        return Err(Error::Decode(
+            format!("unexpected value {:?} for array", s).into(),
+        ));
+    }
+
+    let inner = &s[1..s.len() - 1];
+
+    if inner.is_empty() {
+        return Ok(());
+    }
+
+    let mut chars = inner.chars().peekable();
+    let mut element = String::new();
+
+    loop {
+        element.clear();
+
+        let quoted = chars.peek() == Some(&'"');
+
+        if quoted {
+            chars.next();
+
+            loop {
+                match chars.next() {
+                    Some('"') => break,
+                    Some('\\') => element.extend(chars.next()),
+                    Some(ch) => element.push(ch),
+
+                    None => {
+                        return Err(Error::Decode(
+                            format!("unterminated quoted element in array {:?}", s).into(),
+                        ))
+                    }
+                }
+            }
+        } else {
+            while let Some(&ch) = chars.peek() {
+                if ch == ',' {
+                    break;
+                }
+
+                if ch == '{' {
+                    return Err(Error::Decode(
+                        "expected a one-dimensional array, got nested elements".into(),
+                    ));
+                }
+
+                element.push(ch);
+                chars.next();
+            }
+
+            // an empty string is always quoted
+            if element.is_empty() {
+                return Err(Error::Decode(
+                    format!("unexpected empty element in array {:?}", s).into(),
+                ));
+            }
+        }
+
+        // an unquoted `NULL` is a null element; a quoted `"NULL"` is the string
+        let value = if !quoted && element.eq_ignore_ascii_case("NULL") {
+            None
+        } else {
+            Some(PgValue::Text(&element))
+        };
+
+        push(T::decode(value)?);
+
+        match chars.next() {
+            Some(',') => {}
+            None => break,
+
+            Some(ch) => {
+                return Err(Error::Decode(
+                    format!("unexpected character {:?} in array {:?}", ch, s).into(),
+                ))
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_text() {
+    fn decode<T>(s: &str) -> crate::Result<Vec<T>>
+    where
+        T: HasArrayType + for<'a> Decode<'a, Postgres>,
+    {
+        Vec::<T>::decode(Some(PgValue::Text(s)))
+    }
+
+    let values: Vec<i32> = decode("{1,2,3}").unwrap();
+    assert_eq!(values, vec![1, 2, 3]);
+
+    let values: Vec<i32> = decode("{}").unwrap();
+    assert!(values.is_empty());
+
+    let values: Vec<Option<String>> =
+        decode(r#"{foo,"bar, baz",NULL,"NULL","a \"quote\""}"#).unwrap();
+
+    assert_eq!(
+        values,
+        vec![
+            Some("foo".to_owned()),
+            Some("bar, baz".to_owned()),
+            None,
+            Some("NULL".to_owned()),
+            Some("a \"quote\"".to_owned()),
+        ]
+    );
+
+    // delimiters, braces and escapes inside quotes are part of the element
+    let values: Vec<Option<String>> =
+        decode(r#"{"a,b","c\"d","NULL",null,"{}","back\\slash","",NuLl}"#).unwrap();
+
+    assert_eq!(
+        values,
+        vec![
+            Some("a,b".to_owned()),
+            Some("c\"d".to_owned()),
+            Some("NULL".to_owned()),
+            None,
+            Some("{}".to_owned()),
+            Some("back\\slash".to_owned()),
+            Some("".to_owned()),
+            None,
+        ]
+    );
+
+    // a null element can not be decoded into a non-optional type
+    assert!(decode::<String>("{a,NULL}").is_err());
+
+    assert!(decode::<String>(r#"{"unterminated}"#).is_err());
+    assert!(decode::<String>(r#"{"a"b}"#).is_err());
+    assert!(decode::<String>("{a,,b}").is_err());
+
+    let values: Vec<i32> = decode("[0:1]={1,2}").unwrap();
+    assert_eq!(values, vec![1, 2]);
+
+    assert!(decode::<i32>("{{1,2},{3,4}}").is_err());
+}
+
+#[test]
+fn test_decode_fixed_size_text() {
+    let values = <[f32; 3]>::decode(Some(PgValue::Text("{0.5,1,-2}"))).unwrap();
+    assert_eq!(values, [0.5, 1.0, -2.0]);
+
+    assert!(<[f32; 2]>::decode(Some(PgValue::Text("{0.5,1,-2}"))).is_err());
+    assert!(<[f32; 4]>::decode(Some(PgValue::Text("{0.5,1,-2}"))).is_err());
+}
@@ -39,13 +39,9 @@ impl TryFrom<BigDecimal> for PgNumeric {
         // exactly what our scale should be
         let scale: i16 = cmp::max(0, exp).try_into()?;
 
-        // there's an implicit +1 offset in the interpretation
-        let weight: i16 = if weight_10 <= 0 {
-            weight_10 / 4 - 1
-        } else {
-            weight_10 / 4
-        }
-        .try_into()?;
+        // there's an implicit +1 offset in the interpretation; a number with 1 to 4 integer
+        // digits has a weight of 0
+        let weight: i16 = (weight_10 - 1).div_euclid(4).try_into()?;
 
         let digits_len = if base_10.len() % 4 != 0 {
             base_10.len() / 4 + 1
@@ -53,26 +49,21 @@ impl TryFrom<BigDecimal> for PgNumeric {
             base_10.len() / 4
         };
 
-        let offset = if weight_10 < 0 {
-            4 - (-weight_10) % 4
-        } else {
-            weight_10 % 4
-        } as usize;
+        // the number of digits before the first boundary of a base-10000 digit; the first
+        // digit is padded with zeros on the right if there are fewer decimal digits than that
+        let offset = weight_10.rem_euclid(4) as usize;
+        let (first, rest) = base_10.split_at(cmp::min(offset, base_10.len()));
 
         let mut digits = Vec::with_capacity(digits_len);
 
-        if let Some(first) = base_10.get(..offset) {
-            if offset != 0 {
-                digits.push(base_10_to_10000(first));
-            }
+        if offset != 0 {
+            digits.push(base_10_to_10000(first) * 10i16.pow((offset - first.len()) as u32));
         }
 
-        if let Some(rest) = base_10.get(offset..) {
-            digits.extend(
-                rest.chunks(4)
-                    .map(|chunk| base_10_to_10000(chunk) * 10i16.pow(4 - chunk.len() as u32)),
-            );
-        }
+        digits.extend(
+            rest.chunks(4)
+                .map(|chunk| base_10_to_10000(chunk) * 10i16.pow(4 - chunk.len() as u32)),
+        );
 
         while let Some(&0) = digits.last() {
             digits.pop();
@@ -101,11 +92,7 @@ impl TryFrom<PgNumeric> for BigDecimal {
                 weight,
                 ..
             } => (digits, sign, weight),
-            PgNumeric::NotANumber => {
-                return Err(crate::Error::Decode(
-                    "BigDecimal does not support NaN values".into(),
-                ))
-            }
+            PgNumeric::NotANumber => return Err(crate::Error::Decode(ERR_NAN.into())),
         };
 
         let sign = match sign {
@@ -132,6 +119,8 @@ impl TryFrom<PgNumeric> for BigDecimal {
     }
 }
 
+const ERR_NAN: &str = "BigDecimal does not support NaN values; decode as `PgNumeric` instead";
+
 /// ### Panics
 /// If this `BigDecimal` cannot be represented by [PgNumeric].
 impl Encode<Postgres> for BigDecimal {
@@ -155,6 +144,8 @@ impl Decode<'_, Postgres> for BigDecimal {
     fn decode(value: Option<PgValue>) -> crate::Result<Self> {
         match value.try_into()? {
             PgValue::Binary(binary) => PgNumeric::from_bytes(binary)?.try_into(),
+            PgValue::Text("NaN") => Err(crate::Error::Decode(ERR_NAN.into())),
+
             PgValue::Text(text) => text
                 .parse::<BigDecimal>()
                 .map_err(|e| crate::Error::Decode(e.into())),
@@ -220,6 +211,29 @@ fn test_bigdecimal_to_pgnumeric() {
         }
     );
 
+    // a full group of integer digits does not add to the weight
+    let four_digits: BigDecimal = "1234".parse().unwrap();
+    assert_eq!(
+        PgNumeric::try_from(four_digits).unwrap(),
+        PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            scale: 0,
+            weight: 0,
+            digits: vec![1234]
+        }
+    );
+
+    let forty_digits: BigDecimal = "1234567890123456789012345678901234567890".parse().unwrap();
+    assert_eq!(
+        PgNumeric::try_from(forty_digits).unwrap(),
+        PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            scale: 0,
+            weight: 9,
+            digits: vec![1234, 5678, 9012, 3456, 7890, 1234, 5678, 9012, 3456, 7890]
+        }
+    );
+
     let one_tenth: BigDecimal = "0.1".parse().unwrap();
     assert_eq!(
         PgNumeric::try_from(one_tenth).unwrap(),
@@ -231,6 +245,52 @@ fn test_bigdecimal_to_pgnumeric() {
         }
     );
 
+    // the leading zeros fill a whole base-10000 digit
+    let one_hundred_thousandth: BigDecimal = "0.00001".parse().unwrap();
+    assert_eq!(
+        PgNumeric::try_from(one_hundred_thousandth).unwrap(),
+        PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            scale: 5,
+            weight: -2,
+            digits: vec![1000]
+        }
+    );
+
+    let twelve_millionths: BigDecimal = "0.000012".parse().unwrap();
+    assert_eq!(
+        PgNumeric::try_from(twelve_millionths).unwrap(),
+        PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            scale: 6,
+            weight: -2,
+            digits: vec![1200]
+        }
+    );
+
+    let one_ten_thousandth: BigDecimal = "0.0001".parse().unwrap();
+    assert_eq!(
+        PgNumeric::try_from(one_ten_thousandth).unwrap(),
+        PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            scale: 4,
+            weight: -1,
+            digits: vec![1]
+        }
+    );
+
+    // fewer decimal digits than the first base-10000 digit holds
+    let one_hundredth: BigDecimal = "0.01".parse().unwrap();
+    assert_eq!(
+        PgNumeric::try_from(one_hundredth).unwrap(),
+        PgNumeric::Number {
+            sign: PgNumericSign::Positive,
+            scale: 2,
+            weight: -1,
+            digits: vec![100]
+        }
+    );
+
     let decimal: BigDecimal = "1.2345".parse().unwrap();
     assert_eq!(
         PgNumeric::try_from(decimal).unwrap(),
@@ -4,7 +4,7 @@ use crate::decode::Decode;
 use crate::encode::Encode;
 use crate::postgres::protocol::TypeId;
 use crate::postgres::row::PgValue;
-use crate::postgres::types::PgTypeInfo;
+use crate::postgres::types::{HasArrayType, PgTypeInfo};
 use crate::postgres::Postgres;
 use crate::types::Type;
 
@@ -14,8 +14,8 @@ impl Type<Postgres> for bool {
     }
 }
 
-impl Type<Postgres> for [bool] {
-    fn type_info() -> PgTypeInfo {
+impl HasArrayType for bool {
+    fn array_type_info() -> PgTypeInfo {
         PgTypeInfo::new(TypeId::ARRAY_BOOL, "BOOL[]")
     }
 }
@@ -3,7 +3,7 @@ use std::convert::TryInto;
 use crate::decode::Decode;
 use crate::encode::Encode;
 use crate::postgres::protocol::TypeId;
-use crate::postgres::types::PgTypeInfo;
+use crate::postgres::types::{HasArrayType, PgTypeInfo};
 use crate::postgres::{PgValue, Postgres};
 use crate::types::Type;
 
@@ -13,8 +13,8 @@ impl Type<Postgres> for [u8] {
     }
 }
 
-impl Type<Postgres> for [&'_ [u8]] {
-    fn type_info() -> PgTypeInfo {
+impl HasArrayType for &'_ [u8] {
+    fn array_type_info() -> PgTypeInfo {
         PgTypeInfo::new(TypeId::ARRAY_BYTEA, "BYTEA[]")
     }
 }
@@ -25,28 +25,80 @@ impl Type<Postgres> for Vec<u8> {
     }
 }
 
+impl HasArrayType for Vec<u8> {
+    fn array_type_info() -> PgTypeInfo {
+        <&[u8] as HasArrayType>::array_type_info()
+    }
+}
+
 impl Encode<Postgres> for [u8] {
     fn encode(&self, buf: &mut Vec<u8>) {
         buf.extend_from_slice(self);
     }
+
+    fn size_hint(&self) -> usize {
+        self.len()
+    }
 }
 
 impl Encode<Postgres> for Vec<u8> {
     fn encode(&self, buf: &mut Vec<u8>) {
         <[u8] as Encode<Postgres>>::encode(self, buf);
     }
+
+    fn size_hint(&self) -> usize {
+        self.len()
+    }
 }
 
 impl<'de> Decode<'de, Postgres> for Vec<u8> {
     fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
         match value.try_into()? {
             PgValue::Binary(buf) => Ok(buf.to_vec()),
-            PgValue::Text(s) => {
-                // BYTEA is formatted as \x followed by hex characters
-                hex::decode(&s[2..]).map_err(crate::Error::decode)
+            PgValue::Text(s) => decode_text(s),
+        }
+    }
+}
+
+// https://www.postgresql.org/docs/current/datatype-binary.html
+fn decode_text(s: &str) -> crate::Result<Vec<u8>> {
+    // the hex format (the default since Postgres 9.0) is `\x` followed by hex characters
+    if let Some(hex) = s.strip_prefix("\\x") {
+        return hex::decode(hex).map_err(crate::Error::decode);
+    }
+
+    // the escape format (`bytea_output = 'escape'`) is the bytes themselves, with
+    // `\\` for a backslash and `\ooo` (octal) for non-printable bytes
+    let mut bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+
+    while let Some((&byte, rest)) = bytes.split_first() {
+        if byte != b'\\' {
+            decoded.push(byte);
+            bytes = rest;
+            continue;
+        }
+
+        match rest {
+            [b'\\', rest @ ..] => {
+                decoded.push(b'\\');
+                bytes = rest;
+            }
+
+            [a @ b'0'..=b'3', b @ b'0'..=b'7', c @ b'0'..=b'7', rest @ ..] => {
+                decoded.push(((a - b'0') << 6) | ((b - b'0') << 3) | (c - b'0'));
+                bytes = rest;
+            }
+
+            _ => {
+                return Err(crate::Error::Decode(
+                    format!("invalid escape sequence in BYTEA {:?}", s).into(),
+                ))
             }
         }
     }
+
+    Ok(decoded)
 }
 
 impl<'de> Decode<'de, Postgres> for &'de [u8] {
@@ -61,3 +113,62 @@ impl<'de> Decode<'de, Postgres> for &'de [u8] {
         }
     }
 }
+
+#[cfg(feature = "bytes")]
+impl Type<Postgres> for ::bytes::Bytes {
+    fn type_info() -> PgTypeInfo {
+        <[u8] as Type<Postgres>>::type_info()
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl HasArrayType for ::bytes::Bytes {
+    fn array_type_info() -> PgTypeInfo {
+        <&[u8] as HasArrayType>::array_type_info()
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl Encode<Postgres> for ::bytes::Bytes {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        <[u8] as Encode<Postgres>>::encode(self, buf);
+    }
+
+    fn size_hint(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<'de> Decode<'de, Postgres> for ::bytes::Bytes {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
+        match value.try_into()? {
+            PgValue::Binary(buf) => Ok(::bytes::Bytes::copy_from_slice(buf)),
+            value => <Vec<u8> as Decode<Postgres>>::decode(Some(value)).map(Into::into),
+        }
+    }
+}
+
+#[test]
+fn test_encode_bytea() {
+    let bytes = vec![0xDE_u8, 0xAD, 0xBE, 0xEF];
+
+    let mut buf = Vec::new();
+    Encode::<Postgres>::encode(&bytes, &mut buf);
+
+    assert_eq!(buf, bytes);
+    assert_eq!(Encode::<Postgres>::size_hint(&bytes), 4);
+    assert_eq!(Encode::<Postgres>::size_hint(&bytes[..2]), 2);
+    assert_eq!(<Vec<u8> as Type<Postgres>>::type_info().id, TypeId(17));
+}
+
+#[test]
+fn test_decode_text() {
+    assert_eq!(decode_text("\\x48656c6c6f").unwrap(), b"Hello");
+    assert_eq!(decode_text("\\x").unwrap(), b"");
+    assert!(decode_text("\\x4865zz").is_err());
+
+    assert_eq!(decode_text("Hello").unwrap(), b"Hello");
+    assert_eq!(decode_text("\\000a\\\\b\\377").unwrap(), b"\0a\\b\xFF");
+    assert!(decode_text("\\9").is_err());
+}
@@ -0,0 +1,100 @@
+use std::convert::TryInto;
+use std::str::from_utf8;
+
+use crate::decode::{decode_char, Decode};
+use crate::encode::Encode;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::row::PgValue;
+use crate::postgres::types::PgTypeInfo;
+use crate::postgres::Postgres;
+use crate::types::Type;
+
+// The internal, single-byte `"char"` type is used heavily in the system catalogs
+// (e.g., `pg_class.relkind`). It is not to be confused with `CHAR(N)` (`BPCHAR`).
+//
+// A Rust `char` is sent as `BPCHAR` so it can hold any character; it can be decoded from
+// any text type holding exactly one character, as well as from `"char"`.
+
+impl Type<Postgres> for u8 {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::CHAR, "CHAR")
+    }
+}
+
+impl Type<Postgres> for char {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::BPCHAR, "BPCHAR")
+    }
+}
+
+impl Encode<Postgres> for u8 {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.push(*self);
+    }
+}
+
+impl Encode<Postgres> for char {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        let mut bytes = [0; 4];
+
+        buf.extend_from_slice(self.encode_utf8(&mut bytes).as_bytes());
+    }
+
+    fn size_hint(&self) -> usize {
+        self.len_utf8()
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for u8 {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
+        match value.try_into()? {
+            PgValue::Binary(buf) => match buf {
+                [] => Ok(0),
+                [b] => Ok(*b),
+
+                _ => Err(crate::Error::Decode(
+                    format!("expected 1 byte for \"char\", got {} bytes", buf.len()).into(),
+                )),
+            },
+
+            PgValue::Text(s) => decode_char_text(s),
+        }
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for char {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
+        match value.try_into()? {
+            // a single byte may be a `"char"`, whose bytes are not necessarily UTF-8
+            PgValue::Binary([b]) => Ok(char::from(*b)),
+            PgValue::Binary(buf) => decode_char(from_utf8(buf).map_err(crate::Error::decode)?),
+
+            PgValue::Text(s) => decode_char(s),
+        }
+    }
+}
+
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/char.c
+// The text output of `"char"` is empty for `\0`, `\ooo` (octal) for bytes with the
+// high bit set, and otherwise the byte itself
+fn decode_char_text(s: &str) -> crate::Result<u8> {
+    let bytes = s.as_bytes();
+
+    match bytes.len() {
+        0 => Ok(0),
+        1 => Ok(bytes[0]),
+        4 if bytes[0] == b'\\' => u8::from_str_radix(&s[1..], 8).map_err(crate::Error::decode),
+
+        _ => Err(crate::Error::Decode(
+            format!("unexpected value {:?} for \"char\"", s).into(),
+        )),
+    }
+}
+
+#[test]
+fn test_decode_char_text() {
+    assert_eq!(decode_char_text("").unwrap(), 0);
+    assert_eq!(decode_char_text("r").unwrap(), b'r');
+    assert_eq!(decode_char_text("\\377").unwrap(), 0xFF);
+    assert!(decode_char_text("ab").is_err());
+}
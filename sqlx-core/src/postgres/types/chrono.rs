@@ -2,13 +2,16 @@ use std::convert::TryInto;
 use std::mem;
 
 use byteorder::{NetworkEndian, ReadBytesExt};
-use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono::{
+    DateTime, Duration, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone,
+    Timelike, Utc,
+};
 
 use crate::decode::Decode;
 use crate::encode::Encode;
 use crate::postgres::protocol::TypeId;
 use crate::postgres::row::PgValue;
-use crate::postgres::types::PgTypeInfo;
+use crate::postgres::types::{HasArrayType, PgInterval, PgTypeInfo};
 use crate::postgres::Postgres;
 use crate::types::Type;
 use crate::Error;
@@ -31,6 +34,11 @@ impl Type<Postgres> for NaiveDateTime {
     }
 }
 
+/// The equivalent Postgres type for `DateTime<Tz>` is `TIMESTAMPTZ`.
+///
+/// ### Note
+/// Postgres stores `TIMESTAMPTZ` as an instant in UTC; the offset of an encoded `DateTime`
+/// is not preserved, and a `DateTime<FixedOffset>` is decoded with an offset of `+00:00`.
 impl<Tz> Type<Postgres> for DateTime<Tz>
 where
     Tz: TimeZone,
@@ -40,30 +48,30 @@ where
     }
 }
 
-impl Type<Postgres> for [NaiveTime] {
-    fn type_info() -> PgTypeInfo {
+impl HasArrayType for NaiveTime {
+    fn array_type_info() -> PgTypeInfo {
         PgTypeInfo::new(TypeId::ARRAY_TIME, "TIME[]")
     }
 }
 
-impl Type<Postgres> for [NaiveDate] {
-    fn type_info() -> PgTypeInfo {
+impl HasArrayType for NaiveDate {
+    fn array_type_info() -> PgTypeInfo {
         PgTypeInfo::new(TypeId::ARRAY_DATE, "DATE[]")
     }
 }
 
-impl Type<Postgres> for [NaiveDateTime] {
-    fn type_info() -> PgTypeInfo {
+impl HasArrayType for NaiveDateTime {
+    fn array_type_info() -> PgTypeInfo {
         PgTypeInfo::new(TypeId::ARRAY_TIMESTAMP, "TIMESTAMP[]")
     }
 }
 
-impl<Tz> Type<Postgres> for [DateTime<Tz>]
+impl<Tz> HasArrayType for DateTime<Tz>
 where
     Tz: TimeZone,
 {
-    fn type_info() -> PgTypeInfo {
-        PgTypeInfo::new(TypeId::ARRAY_TIMESTAMPTZ, "TIMESTAMP[]")
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_TIMESTAMPTZ, "TIMESTAMPTZ[]")
     }
 }
 
@@ -166,7 +174,13 @@ impl<'de> Decode<'de, Postgres> for NaiveDateTime {
 
 impl Encode<Postgres> for NaiveDateTime {
     fn encode(&self, buf: &mut Vec<u8>) {
-        let micros = self
+        // Postgres stores microseconds; truncate any nanoseconds first so that times before
+        // the epoch are not rounded up
+        let truncated = self
+            .with_nanosecond(self.nanosecond() / 1000 * 1000)
+            .expect("truncated nanoseconds are in range");
+
+        let micros = truncated
             .signed_duration_since(postgres_epoch().naive_utc())
             .num_microseconds()
             .unwrap_or_else(|| panic!("NaiveDateTime out of range for Postgres: {:?}", self));
@@ -193,6 +207,13 @@ impl<'de> Decode<'de, Postgres> for DateTime<Local> {
     }
 }
 
+impl<'de> Decode<'de, Postgres> for DateTime<FixedOffset> {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
+        let date_time = Decode::<Postgres>::decode(value)?;
+        Ok(FixedOffset::east(0).from_utc_datetime(&date_time))
+    }
+}
+
 impl<Tz: TimeZone> Encode<Postgres> for DateTime<Tz>
 where
     Tz::Offset: Copy,
@@ -206,6 +227,56 @@ where
     }
 }
 
+// `INTERVAL` is sent as microseconds, days and months; as the length of a day or a month
+// depends on when it is applied, only intervals of microseconds are a fixed `Duration`
+
+impl Type<Postgres> for Duration {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::INTERVAL, "INTERVAL")
+    }
+}
+
+impl HasArrayType for Duration {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_INTERVAL, "INTERVAL[]")
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for Duration {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
+        let interval = PgInterval::decode(value)?;
+
+        if interval.days != 0 || interval.months != 0 {
+            return Err(Error::Decode(
+                format!(
+                    "interval of {} months, {} days and {} microseconds cannot be \
+                     decoded as Duration as it has a month or day component",
+                    interval.months, interval.days, interval.microseconds
+                )
+                .into(),
+            ));
+        }
+
+        Ok(Duration::microseconds(interval.microseconds))
+    }
+}
+
+impl Encode<Postgres> for Duration {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        let micros = self
+            .num_microseconds()
+            .unwrap_or_else(|| panic!("Duration out of range for Postgres INTERVAL: {}", self));
+
+        Encode::<Postgres>::encode(&micros, buf);
+        Encode::<Postgres>::encode(&0_i32, buf);
+        Encode::<Postgres>::encode(&0_i32, buf);
+    }
+
+    fn size_hint(&self) -> usize {
+        mem::size_of::<i64>() + 2 * mem::size_of::<i32>()
+    }
+}
+
 fn postgres_epoch() -> DateTime<Utc> {
     Utc.ymd(2000, 1, 1).and_hms(0, 0, 0)
 }
@@ -250,6 +321,38 @@ fn test_decode_datetime() {
     assert_eq!(date.to_string(), "2019-12-11 11:01:05");
 }
 
+#[test]
+fn test_encode_datetime_with_offset() {
+    let mut buf = Vec::new();
+
+    // the epoch, 5 hours east of UTC
+    let date = FixedOffset::east(5 * 3600).ymd(2000, 1, 1).and_hms(5, 0, 0);
+    Encode::<Postgres>::encode(&date, &mut buf);
+    assert_eq!(buf, [0; 8]);
+    buf.clear();
+
+    // nanoseconds are truncated
+    let date2 = FixedOffset::west(3600)
+        .ymd(1999, 12, 31)
+        .and_hms_nano(23, 0, 0, 1_999);
+    Encode::<Postgres>::encode(&date2, &mut buf);
+    assert_eq!(buf, 1i64.to_be_bytes());
+    buf.clear();
+
+    // before the epoch, truncation is towards the past
+    let date3 = Utc.ymd(1999, 12, 31).and_hms_nano(23, 59, 59, 999_999_500);
+    Encode::<Postgres>::encode(&date3, &mut buf);
+    assert_eq!(buf, (-1i64).to_be_bytes());
+}
+
+#[test]
+fn test_decode_datetime_with_offset() {
+    let buf = 3_600_000_000i64.to_be_bytes();
+    let date: DateTime<FixedOffset> =
+        Decode::<Postgres>::decode(Some(PgValue::Binary(&buf))).unwrap();
+    assert_eq!(date.to_rfc3339(), "2000-01-01T01:00:00+00:00");
+}
+
 #[test]
 fn test_encode_date() {
     let mut buf = Vec::new();
@@ -285,3 +388,53 @@ fn test_decode_date() {
     let date: NaiveDate = Decode::<Postgres>::decode(Some(PgValue::Binary(&buf))).unwrap();
     assert_eq!(date.to_string(), "2019-12-11");
 }
+
+#[test]
+fn test_encode_interval() {
+    let mut buf = Vec::new();
+
+    Encode::<Postgres>::encode(&Duration::microseconds(1_500_000), &mut buf);
+    assert_eq!(buf, [0, 0, 0, 0, 0, 22, 227, 96, 0, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(Encode::<Postgres>::size_hint(&Duration::zero()), buf.len());
+}
+
+#[test]
+fn test_decode_interval() {
+    let decode = |buf: &[u8]| <Duration as Decode<Postgres>>::decode(Some(PgValue::Binary(buf)));
+
+    // 1.5 seconds
+    let buf = [0, 0, 0, 0, 0, 22, 227, 96, 0, 0, 0, 0, 0, 0, 0, 0];
+    assert_eq!(decode(&buf).unwrap(), Duration::milliseconds(1500));
+
+    // 1 month
+    let buf = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+    assert!(decode(&buf).is_err());
+
+    // 1 day
+    let buf = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0];
+    assert!(decode(&buf).is_err());
+}
+
+#[test]
+fn test_decode_interval_text() {
+    let decode_interval_text =
+        |s: &str| <Duration as Decode<Postgres>>::decode(Some(PgValue::Text(s)));
+
+    assert_eq!(decode_interval_text("00:00:00").unwrap(), Duration::zero());
+    assert_eq!(
+        decode_interval_text("00:00:01.5").unwrap(),
+        Duration::milliseconds(1500)
+    );
+    assert_eq!(
+        decode_interval_text("-00:00:00.000001").unwrap(),
+        Duration::microseconds(-1)
+    );
+    assert_eq!(
+        decode_interval_text("123:04:05").unwrap(),
+        Duration::seconds(123 * 3600 + 4 * 60 + 5)
+    );
+
+    assert!(decode_interval_text("1 mon").is_err());
+    assert!(decode_interval_text("1 day 02:00:00").is_err());
+    assert!(decode_interval_text("-1 days +02:00:00").is_err());
+}
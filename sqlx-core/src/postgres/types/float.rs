@@ -1,24 +1,38 @@
 use std::convert::TryInto;
+use std::fmt::Display;
+use std::io::Write;
 use std::str::FromStr;
 
 use byteorder::{NetworkEndian, ReadBytesExt};
 
 use crate::decode::Decode;
-use crate::encode::Encode;
+use crate::encode::{Encode, IsNull};
 use crate::error::Error;
 use crate::postgres::protocol::TypeId;
-use crate::postgres::types::PgTypeInfo;
+use crate::postgres::types::{HasArrayType, PgTypeInfo};
 use crate::postgres::{PgValue, Postgres};
 use crate::types::Type;
 
+// `Display` writes the shortest representation that parses back to the same value, which
+// is also the text Postgres reads; only the spelling of infinity differs
+fn encode_float_text(value: impl Display, buf: &mut Vec<u8>) {
+    // writing to a `Vec` can not fail
+    let _ = write!(buf, "{}", value);
+
+    if buf.ends_with(b"inf") {
+        buf.truncate(buf.len() - 3);
+        buf.extend_from_slice(b"Infinity");
+    }
+}
+
 impl Type<Postgres> for f32 {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::new(TypeId::FLOAT4, "FLOAT4")
     }
 }
 
-impl Type<Postgres> for [f32] {
-    fn type_info() -> PgTypeInfo {
+impl HasArrayType for f32 {
+    fn array_type_info() -> PgTypeInfo {
         PgTypeInfo::new(TypeId::ARRAY_FLOAT4, "FLOAT4[]")
     }
 }
@@ -27,6 +41,11 @@ impl Encode<Postgres> for f32 {
     fn encode(&self, buf: &mut Vec<u8>) {
         <i32 as Encode<Postgres>>::encode(&(self.to_bits() as i32), buf)
     }
+
+    fn encode_text(&self, buf: &mut Vec<u8>) -> Option<IsNull> {
+        encode_float_text(*self, buf);
+        Some(IsNull::No)
+    }
 }
 
 impl<'de> Decode<'de, Postgres> for f32 {
@@ -48,8 +67,8 @@ impl Type<Postgres> for f64 {
     }
 }
 
-impl Type<Postgres> for [f64] {
-    fn type_info() -> PgTypeInfo {
+impl HasArrayType for f64 {
+    fn array_type_info() -> PgTypeInfo {
         PgTypeInfo::new(TypeId::ARRAY_FLOAT8, "FLOAT8[]")
     }
 }
@@ -58,6 +77,11 @@ impl Encode<Postgres> for f64 {
     fn encode(&self, buf: &mut Vec<u8>) {
         <i64 as Encode<Postgres>>::encode(&(self.to_bits() as i64), buf)
     }
+
+    fn encode_text(&self, buf: &mut Vec<u8>) -> Option<IsNull> {
+        encode_float_text(*self, buf);
+        Some(IsNull::No)
+    }
 }
 
 impl<'de> Decode<'de, Postgres> for f64 {
@@ -72,3 +96,20 @@ impl<'de> Decode<'de, Postgres> for f64 {
         }
     }
 }
+
+#[test]
+fn test_encode_float_text() {
+    fn text<T: Encode<Postgres>>(value: T) -> String {
+        let mut buf = Vec::new();
+        assert!(matches!(value.encode_text(&mut buf), Some(IsNull::No)));
+
+        String::from_utf8(buf).unwrap()
+    }
+
+    assert_eq!(text(1.5_f32), "1.5");
+    assert_eq!(text(-0.1_f64), "-0.1");
+    assert_eq!(text(1e20_f64), "100000000000000000000");
+    assert_eq!(text(f64::NAN), "NaN");
+    assert_eq!(text(f32::INFINITY), "Infinity");
+    assert_eq!(text(f64::NEG_INFINITY), "-Infinity");
+}
@@ -0,0 +1,370 @@
+use std::convert::TryInto;
+use std::str::FromStr;
+
+use byteorder::{NetworkEndian, ReadBytesExt};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::error::Error;
+use crate::io::BufMut;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::types::{HasArrayType, PgTypeInfo};
+use crate::postgres::{PgValue, Postgres};
+use crate::types::Type;
+
+// https://www.postgresql.org/docs/current/datatype-geometric.html
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/geo_ops.c
+
+/// A point on a plane, `(x,y)`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgPoint {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// An infinite line, represented by the linear equation `Ax + By + C = 0`, `{A,B,C}`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgLine {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+}
+
+/// A finite line segment, `[(x1,y1),(x2,y2)]`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgLSeg {
+    pub start: PgPoint,
+    pub end: PgPoint,
+}
+
+/// A rectangular box, `(x1,y1),(x2,y2)`.
+///
+/// Postgres reorders the corners on input so that `high` is the upper right corner
+/// and `low` is the lower left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PgBox {
+    pub high: PgPoint,
+    pub low: PgPoint,
+}
+
+/// A list of connected points, either open `[(x1,y1),...]` or closed `((x1,y1),...)`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PgPath {
+    pub closed: bool,
+    pub points: Vec<PgPoint>,
+}
+
+/// A closed path where the points are the vertices of a polygon, `((x1,y1),...)`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PgPolygon {
+    pub points: Vec<PgPoint>,
+}
+
+macro_rules! impl_geometry_type {
+    ($ty:ty, $id:ident, $name:literal, $array_id:ident, $array_name:literal) => {
+        impl Type<Postgres> for $ty {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::new(TypeId::$id, $name)
+            }
+        }
+
+        impl HasArrayType for $ty {
+            fn array_type_info() -> PgTypeInfo {
+                PgTypeInfo::new(TypeId::$array_id, $array_name)
+            }
+        }
+    };
+}
+
+impl_geometry_type!(PgPoint, POINT, "POINT", ARRAY_POINT, "POINT[]");
+impl_geometry_type!(PgLine, LINE, "LINE", ARRAY_LINE, "LINE[]");
+impl_geometry_type!(PgLSeg, LSEG, "LSEG", ARRAY_LSEG, "LSEG[]");
+impl_geometry_type!(PgBox, BOX, "BOX", ARRAY_BOX, "BOX[]");
+impl_geometry_type!(PgPath, PATH, "PATH", ARRAY_PATH, "PATH[]");
+impl_geometry_type!(PgPolygon, POLYGON, "POLYGON", ARRAY_POLYGON, "POLYGON[]");
+
+impl Encode<Postgres> for PgPoint {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.put_u64::<NetworkEndian>(self.x.to_bits());
+        buf.put_u64::<NetworkEndian>(self.y.to_bits());
+    }
+
+    fn size_hint(&self) -> usize {
+        16
+    }
+}
+
+impl Encode<Postgres> for PgLine {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.put_u64::<NetworkEndian>(self.a.to_bits());
+        buf.put_u64::<NetworkEndian>(self.b.to_bits());
+        buf.put_u64::<NetworkEndian>(self.c.to_bits());
+    }
+
+    fn size_hint(&self) -> usize {
+        24
+    }
+}
+
+impl Encode<Postgres> for PgLSeg {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        self.start.encode(buf);
+        self.end.encode(buf);
+    }
+
+    fn size_hint(&self) -> usize {
+        32
+    }
+}
+
+impl Encode<Postgres> for PgBox {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        self.high.encode(buf);
+        self.low.encode(buf);
+    }
+
+    fn size_hint(&self) -> usize {
+        32
+    }
+}
+
+impl Encode<Postgres> for PgPath {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.push(self.closed as u8);
+        buf.put_i32::<NetworkEndian>(self.points.len() as i32);
+
+        for point in &self.points {
+            point.encode(buf);
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        5 + self.points.len() * 16
+    }
+}
+
+impl Encode<Postgres> for PgPolygon {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.put_i32::<NetworkEndian>(self.points.len() as i32);
+
+        for point in &self.points {
+            point.encode(buf);
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        4 + self.points.len() * 16
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgPoint {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
+        match value.try_into()? {
+            PgValue::Binary(mut buf) => {
+                let point = read_point(&mut buf)?;
+                expect_end(buf, "POINT")?;
+
+                Ok(point)
+            }
+
+            PgValue::Text(s) => {
+                let [x, y] = parse_floats::<[f64; 2]>(s, "POINT")?;
+
+                Ok(PgPoint { x, y })
+            }
+        }
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgLine {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
+        let [a, b, c] = match value.try_into()? {
+            PgValue::Binary(mut buf) => {
+                let line = [
+                    read_f64(&mut buf)?,
+                    read_f64(&mut buf)?,
+                    read_f64(&mut buf)?,
+                ];
+
+                expect_end(buf, "LINE")?;
+
+                line
+            }
+
+            PgValue::Text(s) => parse_floats::<[f64; 3]>(s, "LINE")?,
+        };
+
+        Ok(PgLine { a, b, c })
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgLSeg {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
+        let (start, end) = decode_point_pair(value.try_into()?, "LSEG")?;
+
+        Ok(PgLSeg { start, end })
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgBox {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
+        let (high, low) = decode_point_pair(value.try_into()?, "BOX")?;
+
+        Ok(PgBox { high, low })
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgPath {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
+        match value.try_into()? {
+            PgValue::Binary(mut buf) => {
+                let closed = buf.read_u8().map_err(Error::decode)? != 0;
+                let points = read_points(&mut buf)?;
+                expect_end(buf, "PATH")?;
+
+                Ok(PgPath { closed, points })
+            }
+
+            PgValue::Text(s) => Ok(PgPath {
+                // an open path is written as `[...]`, a closed path as `(...)`
+                closed: !s.trim_start().starts_with('['),
+                points: parse_points(s, "PATH")?,
+            }),
+        }
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgPolygon {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
+        match value.try_into()? {
+            PgValue::Binary(mut buf) => {
+                let points = read_points(&mut buf)?;
+                expect_end(buf, "POLYGON")?;
+
+                Ok(PgPolygon { points })
+            }
+
+            PgValue::Text(s) => Ok(PgPolygon {
+                points: parse_points(s, "POLYGON")?,
+            }),
+        }
+    }
+}
+
+fn decode_point_pair(value: PgValue<'_>, name: &str) -> crate::Result<(PgPoint, PgPoint)> {
+    match value {
+        PgValue::Binary(mut buf) => {
+            let first = read_point(&mut buf)?;
+            let second = read_point(&mut buf)?;
+            expect_end(buf, name)?;
+
+            Ok((first, second))
+        }
+
+        PgValue::Text(s) => {
+            let [x1, y1, x2, y2] = parse_floats::<[f64; 4]>(s, name)?;
+
+            Ok((PgPoint { x: x1, y: y1 }, PgPoint { x: x2, y: y2 }))
+        }
+    }
+}
+
+fn read_f64(buf: &mut &[u8]) -> crate::Result<f64> {
+    buf.read_f64::<NetworkEndian>().map_err(Error::decode)
+}
+
+fn read_point(buf: &mut &[u8]) -> crate::Result<PgPoint> {
+    Ok(PgPoint {
+        x: read_f64(buf)?,
+        y: read_f64(buf)?,
+    })
+}
+
+fn read_points(buf: &mut &[u8]) -> crate::Result<Vec<PgPoint>> {
+    let len = buf.read_i32::<NetworkEndian>().map_err(Error::decode)?;
+
+    if len < 0 || len as usize * 16 > buf.len() {
+        return Err(Error::Decode(
+            format!("invalid number of points: {}", len).into(),
+        ));
+    }
+
+    (0..len).map(|_| read_point(buf)).collect()
+}
+
+fn expect_end(buf: &[u8], name: &str) -> crate::Result<()> {
+    if buf.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::Decode(
+            format!("{} trailing bytes after {}", buf.len(), name).into(),
+        ))
+    }
+}
+
+// The text forms nest the numbers in any of `(`, `[` or `{`, separated by `,`;
+// the brackets carry no information beyond the open/closed flag of a path
+fn split_floats(s: &str) -> impl Iterator<Item = &str> {
+    s.split(&['(', ')', '[', ']', '{', '}', ','][..])
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+}
+
+fn parse_floats<T>(s: &str, name: &str) -> crate::Result<T>
+where
+    T: Default + AsMut<[f64]>,
+{
+    let mut floats = T::default();
+    let mut parts = split_floats(s);
+
+    for float in floats.as_mut() {
+        *float = parts
+            .next()
+            .ok_or_else(|| invalid_text(s, name))
+            .and_then(|part| f64::from_str(part).map_err(Error::decode))?;
+    }
+
+    if parts.next().is_some() {
+        return Err(invalid_text(s, name));
+    }
+
+    Ok(floats)
+}
+
+fn parse_points(s: &str, name: &str) -> crate::Result<Vec<PgPoint>> {
+    let floats = split_floats(s)
+        .map(|part| f64::from_str(part).map_err(Error::decode))
+        .collect::<crate::Result<Vec<f64>>>()?;
+
+    if floats.len() % 2 != 0 {
+        return Err(invalid_text(s, name));
+    }
+
+    Ok(floats
+        .chunks(2)
+        .map(|xy| PgPoint { x: xy[0], y: xy[1] })
+        .collect())
+}
+
+fn invalid_text(s: &str, name: &str) -> Error {
+    Error::Decode(format!("unexpected value {:?} for {}", s, name).into())
+}
+
+#[test]
+fn test_decode_point_text() {
+    let point = PgPoint::decode(Some(PgValue::Text("(1.5,-2)"))).unwrap();
+
+    assert_eq!(point, PgPoint { x: 1.5, y: -2.0 });
+    assert!(PgPoint::decode(Some(PgValue::Text("(1,2,3)"))).is_err());
+}
+
+#[test]
+fn test_decode_path_text() {
+    let open = PgPath::decode(Some(PgValue::Text("[(1,2),(3,4)]"))).unwrap();
+    let closed = PgPath::decode(Some(PgValue::Text("((1,2),(3,4))"))).unwrap();
+
+    assert!(!open.closed);
+    assert!(closed.closed);
+    assert_eq!(open.points, closed.points);
+    assert_eq!(open.points[1], PgPoint { x: 3.0, y: 4.0 });
+}
@@ -1,12 +1,13 @@
-use std::convert::TryInto;
+use std::any::type_name;
+use std::convert::{TryFrom, TryInto};
 use std::str::FromStr;
 
 use byteorder::{NetworkEndian, ReadBytesExt};
 
 use crate::decode::Decode;
-use crate::encode::Encode;
+use crate::encode::{Encode, IsNull};
 use crate::postgres::protocol::TypeId;
-use crate::postgres::types::PgTypeInfo;
+use crate::postgres::types::{HasArrayType, PgTypeInfo};
 use crate::postgres::{PgValue, Postgres};
 use crate::types::Type;
 use crate::Error;
@@ -17,8 +18,8 @@ impl Type<Postgres> for i16 {
     }
 }
 
-impl Type<Postgres> for [i16] {
-    fn type_info() -> PgTypeInfo {
+impl HasArrayType for i16 {
+    fn array_type_info() -> PgTypeInfo {
         PgTypeInfo::new(TypeId::ARRAY_INT2, "INT2[]")
     }
 }
@@ -27,14 +28,16 @@ impl Encode<Postgres> for i16 {
     fn encode(&self, buf: &mut Vec<u8>) {
         buf.extend_from_slice(&self.to_be_bytes());
     }
+
+    fn encode_text(&self, buf: &mut Vec<u8>) -> Option<IsNull> {
+        encode_int_text((*self).into(), buf);
+        Some(IsNull::No)
+    }
 }
 
 impl<'de> Decode<'de, Postgres> for i16 {
     fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
-        match value.try_into()? {
-            PgValue::Binary(mut buf) => buf.read_i16::<NetworkEndian>().map_err(Error::decode),
-            PgValue::Text(s) => i16::from_str(s).map_err(Error::decode),
-        }
+        decode_int(value)
     }
 }
 
@@ -44,8 +47,8 @@ impl Type<Postgres> for i32 {
     }
 }
 
-impl Type<Postgres> for [i32] {
-    fn type_info() -> PgTypeInfo {
+impl HasArrayType for i32 {
+    fn array_type_info() -> PgTypeInfo {
         PgTypeInfo::new(TypeId::ARRAY_INT4, "INT4[]")
     }
 }
@@ -54,13 +57,50 @@ impl Encode<Postgres> for i32 {
     fn encode(&self, buf: &mut Vec<u8>) {
         buf.extend_from_slice(&self.to_be_bytes());
     }
+
+    fn encode_text(&self, buf: &mut Vec<u8>) -> Option<IsNull> {
+        encode_int_text((*self).into(), buf);
+        Some(IsNull::No)
+    }
 }
 
 impl<'de> Decode<'de, Postgres> for i32 {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
+        decode_int(value)
+    }
+}
+
+// The object identifier type is an unsigned, 4-byte integer; it is intentionally not
+// compatible with `INT4` as large OIDs would not fit into an `i32`
+
+impl Type<Postgres> for u32 {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::OID, "OID")
+    }
+}
+
+impl HasArrayType for u32 {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_OID, "OID[]")
+    }
+}
+
+impl Encode<Postgres> for u32 {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.to_be_bytes());
+    }
+
+    fn encode_text(&self, buf: &mut Vec<u8>) -> Option<IsNull> {
+        encode_int_text((*self).into(), buf);
+        Some(IsNull::No)
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for u32 {
     fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
         match value.try_into()? {
-            PgValue::Binary(mut buf) => buf.read_i32::<NetworkEndian>().map_err(Error::decode),
-            PgValue::Text(s) => i32::from_str(s).map_err(Error::decode),
+            PgValue::Binary(mut buf) => buf.read_u32::<NetworkEndian>().map_err(Error::decode),
+            PgValue::Text(s) => u32::from_str(s).map_err(Error::decode),
         }
     }
 }
@@ -71,8 +111,8 @@ impl Type<Postgres> for i64 {
     }
 }
 
-impl Type<Postgres> for [i64] {
-    fn type_info() -> PgTypeInfo {
+impl HasArrayType for i64 {
+    fn array_type_info() -> PgTypeInfo {
         PgTypeInfo::new(TypeId::ARRAY_INT8, "INT8[]")
     }
 }
@@ -81,13 +121,192 @@ impl Encode<Postgres> for i64 {
     fn encode(&self, buf: &mut Vec<u8>) {
         buf.extend_from_slice(&self.to_be_bytes());
     }
+
+    fn encode_text(&self, buf: &mut Vec<u8>) -> Option<IsNull> {
+        encode_int_text(*self, buf);
+        Some(IsNull::No)
+    }
 }
 
 impl<'de> Decode<'de, Postgres> for i64 {
     fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
-        match value.try_into()? {
-            PgValue::Binary(mut buf) => buf.read_i64::<NetworkEndian>().map_err(Error::decode),
-            PgValue::Text(s) => i64::from_str(s).map_err(Error::decode),
+        decode_int(value)
+    }
+}
+
+// Postgres has no unsigned 64-bit integer; a `u64` is stored as an `INT8` if it fits, and
+// negative `INT8` values fail to decode in `decode_int`
+
+impl Type<Postgres> for u64 {
+    fn type_info() -> PgTypeInfo {
+        <i64 as Type<Postgres>>::type_info()
+    }
+}
+
+impl HasArrayType for u64 {
+    fn array_type_info() -> PgTypeInfo {
+        <i64 as HasArrayType>::array_type_info()
+    }
+}
+
+impl Encode<Postgres> for u64 {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        // a bound value out of range fails in [check_encode] and is never encoded
+        <i64 as Encode<Postgres>>::encode(&(*self as i64), buf)
+    }
+
+    fn check_encode(&self) -> crate::Result<()> {
+        i64::try_from(*self).map(drop).map_err(|_| {
+            Error::Encode(format!("u64 value {} is out of range for INT8", self).into())
+        })
+    }
+
+    fn encode_text(&self, buf: &mut Vec<u8>) -> Option<IsNull> {
+        encode_int_text(*self as i64, buf);
+        Some(IsNull::No)
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for u64 {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
+        decode_int(value)
+    }
+}
+
+// Writes the decimal digits of `value` without going through a formatter
+fn encode_int_text(value: i64, buf: &mut Vec<u8>) {
+    // i64::MIN has 19 digits
+    let mut digits = [0_u8; 19];
+    let mut start = digits.len();
+
+    // the remainders of a negative value are negative; this avoids negating i64::MIN
+    let mut rest = value;
+
+    loop {
+        start -= 1;
+        digits[start] = b'0' + (rest % 10).abs() as u8;
+        rest /= 10;
+
+        if rest == 0 {
+            break;
         }
     }
+
+    if value < 0 {
+        buf.push(b'-');
+    }
+
+    buf.extend_from_slice(&digits[start..]);
+}
+
+// Integers are read in the width they were sent in and then converted, so a column of any
+// integer type can be decoded into any integer type as long as its value fits
+fn decode_int<T>(value: Option<PgValue<'_>>) -> crate::Result<T>
+where
+    T: TryFrom<i64>,
+{
+    let (value, source) = match value.try_into()? {
+        PgValue::Binary(mut buf) => match buf.len() {
+            2 => (
+                buf.read_i16::<NetworkEndian>()
+                    .map_err(Error::decode)?
+                    .into(),
+                "INT2",
+            ),
+            4 => (
+                buf.read_i32::<NetworkEndian>()
+                    .map_err(Error::decode)?
+                    .into(),
+                "INT4",
+            ),
+            8 => (
+                buf.read_i64::<NetworkEndian>().map_err(Error::decode)?,
+                "INT8",
+            ),
+
+            len => {
+                return Err(Error::Decode(
+                    format!("expected 2, 4 or 8 bytes for an integer, got {}", len).into(),
+                ))
+            }
+        },
+
+        PgValue::Text(s) => (i64::from_str(s).map_err(Error::decode)?, "integer"),
+    };
+
+    T::try_from(value).map_err(|_| {
+        Error::Decode(
+            format!(
+                "{} value {} is out of range for `{}`",
+                source,
+                value,
+                type_name::<T>()
+            )
+            .into(),
+        )
+    })
+}
+
+#[test]
+fn test_encode_int_text() {
+    fn text<T: Encode<Postgres>>(value: T) -> String {
+        let mut buf = Vec::new();
+        assert!(matches!(value.encode_text(&mut buf), Some(IsNull::No)));
+
+        String::from_utf8(buf).unwrap()
+    }
+
+    assert_eq!(text(0_i16), "0");
+    assert_eq!(text(7_i32), "7");
+    assert_eq!(text(-42_i32), "-42");
+    assert_eq!(text(-7_i64), "-7");
+    assert_eq!(text(1_000_000_i64), "1000000");
+    assert_eq!(text(i16::MIN), "-32768");
+    assert_eq!(text(i32::MAX), "2147483647");
+    assert_eq!(text(u32::MAX), "4294967295");
+    assert_eq!(text(i64::MIN), "-9223372036854775808");
+    assert_eq!(text(i64::MAX), "9223372036854775807");
+    assert_eq!(text(Some(-1_i64)), "-1");
+}
+
+#[test]
+fn test_u64_as_int8() {
+    let mut buf = Vec::new();
+    <u64 as Encode<Postgres>>::encode(&(i64::MAX as u64), &mut buf);
+    assert_eq!(buf, i64::MAX.to_be_bytes());
+
+    let value = <u64 as Decode<Postgres>>::decode(Some(PgValue::Binary(&buf))).unwrap();
+    assert_eq!(value, i64::MAX as u64);
+
+    let value = <u64 as Decode<Postgres>>::decode(Some(PgValue::Text("9223372036854775807")));
+    assert_eq!(value.unwrap(), i64::MAX as u64);
+
+    let err = <u64 as Decode<Postgres>>::decode(Some(PgValue::Binary(&(-1_i64).to_be_bytes())))
+        .unwrap_err();
+    assert_eq!(err.to_string(), "INT8 value -1 is out of range for `u64`");
+
+    assert!(<u64 as Decode<Postgres>>::decode(Some(PgValue::Text("-1"))).is_err());
+}
+
+#[test]
+fn test_u64_out_of_range_for_int8() {
+    use crate::arguments::Arguments;
+    use crate::postgres::PgArguments;
+
+    let err = <u64 as Encode<Postgres>>::check_encode(&(i64::MAX as u64 + 1)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "u64 value 9223372036854775808 is out of range for INT8"
+    );
+
+    assert!(<Vec<u64> as Encode<Postgres>>::check_encode(&vec![1, u64::MAX]).is_err());
+
+    // binding does not panic; the error is kept for when the query is executed
+    let mut args = PgArguments::default();
+    args.add(1_u64);
+    args.add(u64::MAX);
+    args.add(2_u64);
+
+    assert_eq!(args.len(), 3);
+    assert!(matches!(args.take_error(), Err(Error::Encode(_))));
 }
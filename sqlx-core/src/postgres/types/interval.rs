@@ -0,0 +1,470 @@
+use std::convert::TryInto;
+use std::mem;
+use std::str::FromStr;
+
+use byteorder::{NetworkEndian, ReadBytesExt};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::types::{HasArrayType, PgTypeInfo};
+use crate::postgres::{PgValue, Postgres};
+use crate::types::Type;
+use crate::Error;
+
+// https://www.postgresql.org/docs/current/datatype-datetime.html#DATATYPE-INTERVAL-OUTPUT
+
+/// An `INTERVAL`, as the months, days and microseconds Postgres stores it as.
+///
+/// The components are kept apart as the length of a month or a day depends on the date the
+/// interval is applied to. Each may be negative and they need not have the same sign.
+///
+/// Intervals are parsed from any of the `IntervalStyle` output formats Postgres uses for
+/// query results in the text format, except the year-month and day-time fields of
+/// `sql_standard`:
+///
+/// * `postgres`: `1 year 2 mons -3 days +04:05:06.5`
+/// * `postgres_verbose`: `@ 1 year 2 mons 3 days 4 hours 5 mins 6.5 secs ago`
+/// * `iso_8601`: `P1Y2M-3DT4H5M6.5S`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PgInterval {
+    pub months: i32,
+    pub days: i32,
+    pub microseconds: i64,
+}
+
+impl Type<Postgres> for PgInterval {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::INTERVAL, "INTERVAL")
+    }
+}
+
+impl HasArrayType for PgInterval {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_INTERVAL, "INTERVAL[]")
+    }
+}
+
+impl Encode<Postgres> for PgInterval {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        Encode::<Postgres>::encode(&self.microseconds, buf);
+        Encode::<Postgres>::encode(&self.days, buf);
+        Encode::<Postgres>::encode(&self.months, buf);
+    }
+
+    fn size_hint(&self) -> usize {
+        mem::size_of::<i64>() + 2 * mem::size_of::<i32>()
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgInterval {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
+        match value.try_into()? {
+            PgValue::Binary(mut buf) => {
+                let microseconds = buf.read_i64::<NetworkEndian>().map_err(Error::decode)?;
+                let days = buf.read_i32::<NetworkEndian>().map_err(Error::decode)?;
+                let months = buf.read_i32::<NetworkEndian>().map_err(Error::decode)?;
+
+                Ok(PgInterval {
+                    months,
+                    days,
+                    microseconds,
+                })
+            }
+
+            PgValue::Text(s) => s.parse(),
+        }
+    }
+}
+
+impl FromStr for PgInterval {
+    type Err = Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let parsed = match s.trim().strip_prefix('P') {
+            Some(iso) => parse_iso_8601(iso),
+            None => parse_postgres(s),
+        };
+
+        parsed.ok_or_else(|| Error::Decode(format!("invalid interval {:?}", s).into()))
+    }
+}
+
+// the sum of the components as they are parsed, in any order
+#[derive(Default)]
+struct Components {
+    months: i64,
+    days: i64,
+    microseconds: i64,
+}
+
+impl Components {
+    fn add(&mut self, unit: Unit, value: &str) -> Option<()> {
+        let (field, amount) = match unit {
+            Unit::Years => (&mut self.months, parse_int(value)?.checked_mul(12)?),
+            Unit::Months => (&mut self.months, parse_int(value)?),
+            Unit::Weeks => (&mut self.days, parse_int(value)?.checked_mul(7)?),
+            Unit::Days => (&mut self.days, parse_int(value)?),
+            Unit::Hours => (
+                &mut self.microseconds,
+                parse_int(value)?.checked_mul(3_600_000_000)?,
+            ),
+            Unit::Minutes => (
+                &mut self.microseconds,
+                parse_int(value)?.checked_mul(60_000_000)?,
+            ),
+            Unit::Seconds => (&mut self.microseconds, parse_seconds(value)?),
+        };
+
+        *field = field.checked_add(amount)?;
+
+        Some(())
+    }
+
+    fn into_interval(self, negate: bool) -> Option<PgInterval> {
+        let interval = PgInterval {
+            months: self.months.try_into().ok()?,
+            days: self.days.try_into().ok()?,
+            microseconds: self.microseconds,
+        };
+
+        if !negate {
+            return Some(interval);
+        }
+
+        Some(PgInterval {
+            months: interval.months.checked_neg()?,
+            days: interval.days.checked_neg()?,
+            microseconds: interval.microseconds.checked_neg()?,
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Unit {
+    Years,
+    Months,
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+}
+
+impl Unit {
+    fn from_word(word: &str) -> Option<Self> {
+        // accept the plural of each unit as well
+        let word = word.strip_suffix('s').unwrap_or(word);
+
+        Some(match word {
+            "year" => Unit::Years,
+            "mon" | "month" => Unit::Months,
+            "week" => Unit::Weeks,
+            "day" => Unit::Days,
+            "hour" => Unit::Hours,
+            "min" | "minute" => Unit::Minutes,
+            "sec" | "second" => Unit::Seconds,
+            _ => return None,
+        })
+    }
+
+    fn from_iso_designator(designator: char, time: bool) -> Option<Self> {
+        Some(match (designator, time) {
+            ('Y', false) => Unit::Years,
+            ('M', false) => Unit::Months,
+            ('W', false) => Unit::Weeks,
+            ('D', false) => Unit::Days,
+            ('H', true) => Unit::Hours,
+            ('M', true) => Unit::Minutes,
+            ('S', true) => Unit::Seconds,
+            _ => return None,
+        })
+    }
+}
+
+// parses the `postgres` and `postgres_verbose` styles: a number and a unit for each component,
+// where the hours, minutes and seconds may instead be written as `[+-]H:MM:SS[.ffffff]`
+fn parse_postgres(s: &str) -> Option<PgInterval> {
+    let mut tokens: Vec<&str> = s.split_whitespace().collect();
+
+    if tokens.first() == Some(&"@") {
+        tokens.remove(0);
+    }
+
+    // `ago` negates every component
+    let ago = tokens.last() == Some(&"ago");
+
+    if ago {
+        tokens.pop();
+    }
+
+    // `postgres_verbose` writes an empty interval as `@ 0`
+    if tokens == ["0"] {
+        return Some(PgInterval::default());
+    }
+
+    if tokens.is_empty() {
+        return None;
+    }
+
+    let mut components = Components::default();
+    let mut tokens = tokens.into_iter();
+
+    while let Some(token) = tokens.next() {
+        if token.contains(':') {
+            components.microseconds = components.microseconds.checked_add(parse_time(token)?)?;
+
+            continue;
+        }
+
+        let unit = Unit::from_word(&tokens.next()?.to_ascii_lowercase())?;
+
+        components.add(unit, token)?;
+    }
+
+    components.into_interval(ago)
+}
+
+// parses the `iso_8601` style after its `P`: a number and a designator for each component,
+// with the hours, minutes and seconds following a `T`
+fn parse_iso_8601(s: &str) -> Option<PgInterval> {
+    let mut components = Components::default();
+    let mut time = false;
+    let mut start = 0;
+
+    // `T` must be followed by at least one component
+    if s.is_empty() || s.ends_with('T') {
+        return None;
+    }
+
+    for (i, c) in s.char_indices() {
+        match c {
+            'T' if !time && start == i => {
+                time = true;
+                start = i + 1;
+            }
+
+            'A'..='Z' => {
+                components.add(Unit::from_iso_designator(c, time)?, &s[start..i])?;
+                start = i + 1;
+            }
+
+            _ => {}
+        }
+    }
+
+    // every number must be followed by its designator
+    if start != s.len() {
+        return None;
+    }
+
+    components.into_interval(false)
+}
+
+// parses `[+-]H:MM[:SS[.ffffff]]` as microseconds
+fn parse_time(s: &str) -> Option<i64> {
+    let (negative, time) = split_sign(s);
+
+    let mut parts = time.splitn(3, ':');
+    let hours = parse_digits(parts.next()?)?;
+    let minutes = parse_digits(parts.next()?)?;
+    let seconds = match parts.next() {
+        Some(seconds) => parse_seconds(seconds)?,
+        None => 0,
+    };
+
+    // only the hours carry the sign
+    if seconds < 0 {
+        return None;
+    }
+
+    let micros = hours
+        .checked_mul(3_600_000_000)?
+        .checked_add(minutes.checked_mul(60_000_000)?)?
+        .checked_add(seconds)?;
+
+    Some(if negative { -micros } else { micros })
+}
+
+// parses `[+-]S[.ffffff]` as microseconds
+fn parse_seconds(s: &str) -> Option<i64> {
+    let (negative, s) = split_sign(s);
+
+    let (seconds, fraction) = match s.find('.') {
+        Some(i) => (&s[..i], &s[i + 1..]),
+        None => (s, ""),
+    };
+
+    if fraction.len() > 6 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    // the fraction holds up to 6 digits; pad it out to microseconds
+    let fraction = fraction
+        .bytes()
+        .chain(std::iter::repeat(b'0'))
+        .take(6)
+        .fold(0, |micros, b| micros * 10 + (b - b'0') as i64);
+
+    let micros = parse_digits(seconds)?
+        .checked_mul(1_000_000)?
+        .checked_add(fraction)?;
+
+    Some(if negative { -micros } else { micros })
+}
+
+fn parse_int(s: &str) -> Option<i64> {
+    let (negative, s) = split_sign(s);
+    let value = parse_digits(s)?;
+
+    Some(if negative { -value } else { value })
+}
+
+fn parse_digits(s: &str) -> Option<i64> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    s.parse().ok()
+}
+
+fn split_sign(s: &str) -> (bool, &str) {
+    if let Some(s) = s.strip_prefix('-') {
+        (true, s)
+    } else {
+        (false, s.strip_prefix('+').unwrap_or(s))
+    }
+}
+
+#[test]
+fn test_encode_interval() {
+    let mut buf = Vec::new();
+
+    let interval = PgInterval {
+        months: 14,
+        days: -3,
+        microseconds: 1_500_000,
+    };
+
+    Encode::<Postgres>::encode(&interval, &mut buf);
+
+    assert_eq!(
+        buf,
+        [0, 0, 0, 0, 0, 22, 227, 96, 255, 255, 255, 253, 0, 0, 0, 14]
+    );
+    assert_eq!(Encode::<Postgres>::size_hint(&interval), buf.len());
+
+    let decoded = PgInterval::decode(Some(PgValue::Binary(&buf))).unwrap();
+
+    assert_eq!(decoded, interval);
+}
+
+#[test]
+fn test_parse_postgres_interval() {
+    let parse = |s: &str| s.parse::<PgInterval>().unwrap();
+
+    let interval = |months, days, microseconds| PgInterval {
+        months,
+        days,
+        microseconds,
+    };
+
+    assert_eq!(parse("00:00:00"), interval(0, 0, 0));
+    assert_eq!(
+        parse("1 year 2 mons 3 days 04:05:06"),
+        interval(14, 3, 14_706_000_000)
+    );
+    assert_eq!(parse("-1 years -2 mons"), interval(-14, 0, 0));
+    assert_eq!(parse("1 day -00:00:01.5"), interval(0, 1, -1_500_000));
+    assert_eq!(parse("-1 days +02:03:00"), interval(0, -1, 7_380_000_000));
+    assert_eq!(parse("-00:00:00.000001"), interval(0, 0, -1));
+    assert_eq!(parse("123:04:05"), interval(0, 0, 443_045_000_000));
+    assert_eq!(parse("1 mon"), interval(1, 0, 0));
+    assert_eq!(parse("2 weeks"), interval(0, 14, 0));
+}
+
+#[test]
+fn test_parse_postgres_verbose_interval() {
+    let parse = |s: &str| s.parse::<PgInterval>().unwrap();
+
+    assert_eq!(
+        parse("@ 1 year 2 mons 3 days 4 hours 5 mins 6.5 secs"),
+        PgInterval {
+            months: 14,
+            days: 3,
+            microseconds: 14_706_500_000,
+        }
+    );
+    assert_eq!(
+        parse("@ 1 year 2 mons -3 days 4 hours 5 mins 6 secs ago"),
+        PgInterval {
+            months: -14,
+            days: 3,
+            microseconds: -14_706_000_000,
+        }
+    );
+    assert_eq!(
+        parse("@ 1.5 secs ago"),
+        PgInterval {
+            months: 0,
+            days: 0,
+            microseconds: -1_500_000,
+        }
+    );
+    assert_eq!(parse("@ 0"), PgInterval::default());
+}
+
+#[test]
+fn test_parse_iso_8601_interval() {
+    let parse = |s: &str| s.parse::<PgInterval>().unwrap();
+
+    assert_eq!(
+        parse("P1Y2M3DT4H5M6S"),
+        PgInterval {
+            months: 14,
+            days: 3,
+            microseconds: 14_706_000_000,
+        }
+    );
+    assert_eq!(
+        parse("P-1Y-2M3DT-4H-5M-6.5S"),
+        PgInterval {
+            months: -14,
+            days: 3,
+            microseconds: -14_706_500_000,
+        }
+    );
+    assert_eq!(
+        parse("PT1M"),
+        PgInterval {
+            months: 0,
+            days: 0,
+            microseconds: 60_000_000,
+        }
+    );
+    assert_eq!(parse("PT0S"), PgInterval::default());
+}
+
+#[test]
+fn test_parse_invalid_interval() {
+    for s in &[
+        "",
+        "@",
+        "ago",
+        "1",
+        "1 fortnight",
+        "1.5 days",
+        "1 day 2",
+        "00:00:00.0000001",
+        "00:-01:00",
+        "P",
+        "P1",
+        "P1H",
+        "PT1D",
+        "P1YT",
+        "P1Y2",
+        "99999999999 years",
+    ] {
+        assert!(s.parse::<PgInterval>().is_err(), "{:?}", s);
+    }
+}
@@ -7,7 +7,7 @@ use crate::decode::Decode;
 use crate::encode::Encode;
 use crate::postgres::protocol::TypeId;
 use crate::postgres::row::PgValue;
-use crate::postgres::types::PgTypeInfo;
+use crate::postgres::types::{HasArrayType, PgTypeInfo};
 use crate::postgres::Postgres;
 use crate::types::Type;
 use crate::Error;
@@ -32,8 +32,8 @@ impl Type<Postgres> for IpNetwork {
     }
 }
 
-impl Type<Postgres> for [IpNetwork] {
-    fn type_info() -> PgTypeInfo {
+impl HasArrayType for IpNetwork {
+    fn array_type_info() -> PgTypeInfo {
         PgTypeInfo::new(TypeId::ARRAY_INET, "INET[]")
     }
 }
@@ -113,3 +113,47 @@ fn decode(bytes: &[u8]) -> crate::Result<IpNetwork> {
 
     return Err(Error::Decode("Invalid input".into()));
 }
+
+#[test]
+fn test_encode_ipnetwork() {
+    let mut buf = Vec::new();
+
+    let net: IpNetwork = "192.168.1.0/24".parse().unwrap();
+    net.encode(&mut buf);
+    assert_eq!(buf, [PGSQL_AF_INET, 24, INET_TYPE, 4, 192, 168, 1, 0]);
+    assert_eq!(buf.len(), net.size_hint());
+
+    buf.clear();
+
+    let net: IpNetwork = "2001:db8::/64".parse().unwrap();
+    net.encode(&mut buf);
+    assert_eq!(buf[..4], [PGSQL_AF_INET6, 64, INET_TYPE, 16]);
+    assert_eq!(buf[4..], "2001:db8::".parse::<Ipv6Addr>().unwrap().octets());
+    assert_eq!(buf.len(), net.size_hint());
+}
+
+#[test]
+fn test_decode_ipnetwork() {
+    let net: IpNetwork = "192.168.1.0/24".parse().unwrap();
+    let mut buf = Vec::new();
+    net.encode(&mut buf);
+
+    assert_eq!(decode(&buf).unwrap(), net);
+
+    // a `cidr` value only differs in the flag
+    buf[2] = CIDR_TYPE;
+    assert_eq!(decode(&buf).unwrap(), net);
+
+    let net: IpNetwork = "2001:db8::/64".parse().unwrap();
+    let mut buf = Vec::new();
+    net.encode(&mut buf);
+
+    assert_eq!(decode(&buf).unwrap(), net);
+
+    buf[2] = CIDR_TYPE;
+    assert_eq!(decode(&buf).unwrap(), net);
+
+    // the prefix length is checked against the address family
+    assert!(decode(&[PGSQL_AF_INET, 33, INET_TYPE, 4, 10, 0, 0, 0]).is_err());
+    assert!(decode(&[PGSQL_AF_INET, 24, INET_TYPE, 4, 10, 0, 0]).is_err());
+}
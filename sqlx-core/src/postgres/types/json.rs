@@ -0,0 +1,92 @@
+use std::convert::TryInto;
+
+use serde::{Deserialize, Serialize};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::row::PgValue;
+use crate::postgres::types::{HasArrayType, PgTypeInfo};
+use crate::postgres::Postgres;
+use crate::types::{Json, JsonValue, Type};
+use crate::Error;
+
+// The binary format of JSONB is the JSON text prefixed with a version byte
+// https://github.com/postgres/postgres/blob/master/src/backend/utils/adt/jsonb.c
+const JSONB_VERSION: u8 = 1;
+
+impl Type<Postgres> for JsonValue {
+    fn type_info() -> PgTypeInfo {
+        <Json<Self> as Type<Postgres>>::type_info()
+    }
+}
+
+impl HasArrayType for JsonValue {
+    fn array_type_info() -> PgTypeInfo {
+        <[Json<JsonValue>] as Type<Postgres>>::type_info()
+    }
+}
+
+impl Encode<Postgres> for JsonValue {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        <Json<&Self> as Encode<Postgres>>::encode(&Json(self), buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        <Json<&Self> as Encode<Postgres>>::size_hint(&Json(self))
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for JsonValue {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
+        <Json<Self> as Decode<Postgres>>::decode(value).map(|item| item.0)
+    }
+}
+
+impl<T> Type<Postgres> for Json<T> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::JSONB, "JSONB")
+    }
+}
+
+impl<T> HasArrayType for Json<T> {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_JSONB, "JSONB[]")
+    }
+}
+
+impl<T> Encode<Postgres> for Json<T>
+where
+    T: Serialize,
+{
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.push(JSONB_VERSION);
+
+        serde_json::to_writer(buf, &self.0)
+            .expect("failed to serialize json for encoding to database");
+    }
+
+    fn size_hint(&self) -> usize {
+        // the serialized length is not known up front; this is the version byte
+        // followed by the shortest possible JSON value
+        2
+    }
+}
+
+impl<'de, T> Decode<'de, Postgres> for Json<T>
+where
+    T: 'de,
+    T: Deserialize<'de>,
+{
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
+        let buf = match value.try_into()? {
+            // JSON is sent as its text in the binary format while JSONB has a leading version
+            // byte; as JSON text can never begin with `0x01` this is not ambiguous
+            PgValue::Binary(buf) if buf.first() == Some(&JSONB_VERSION) => &buf[1..],
+            PgValue::Binary(buf) => buf,
+            PgValue::Text(s) => s.as_bytes(),
+        };
+
+        serde_json::from_slice(buf).map(Json).map_err(Error::decode)
+    }
+}
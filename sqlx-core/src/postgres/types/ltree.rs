@@ -0,0 +1,178 @@
+use std::convert::TryInto;
+use std::fmt::{self, Display, Formatter};
+use std::str::{from_utf8, FromStr};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::postgres::types::PgTypeInfo;
+use crate::postgres::{PgValue, Postgres};
+use crate::types::Type;
+use crate::Error;
+
+// https://www.postgresql.org/docs/current/ltree.html
+// https://github.com/postgres/postgres/blob/master/contrib/ltree/ltree_io.c
+
+// the binary format is a version number followed by the text format
+const VERSION: u8 = 1;
+
+/// A label path of the `ltree` extension, e.g. `Top.Science.Astronomy`.
+///
+/// Labels consist of alphanumeric characters and underscores. As the OID of `LTREE` is
+/// assigned when the extension is created, parameters of this type are sent without a type
+/// and Postgres infers it from the query; a cast (`$1::ltree`) may be needed where it cannot.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PgLtree {
+    labels: Vec<String>,
+}
+
+impl PgLtree {
+    /// Creates an empty label path.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a label to the path, returning an error if it is not a valid label.
+    pub fn push(&mut self, label: &str) -> crate::Result<()> {
+        if label.is_empty() {
+            return Err(Error::Decode("ltree labels cannot be empty".into()));
+        }
+
+        if let Some(c) = label.chars().find(|&c| !c.is_alphanumeric() && c != '_') {
+            return Err(Error::Decode(
+                format!("invalid character {:?} in ltree label {:?}", c, label).into(),
+            ));
+        }
+
+        self.labels.push(label.to_owned());
+
+        Ok(())
+    }
+
+    /// Returns the labels of the path, from the root.
+    pub fn labels(&self) -> impl ExactSizeIterator<Item = &str> {
+        self.labels.iter().map(String::as_str)
+    }
+
+    /// Returns the number of labels in the path.
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Returns `true` if the path has no labels.
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+}
+
+impl FromStr for PgLtree {
+    type Err = Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let mut ltree = PgLtree::new();
+
+        if !s.is_empty() {
+            for label in s.split('.') {
+                ltree.push(label)?;
+            }
+        }
+
+        Ok(ltree)
+    }
+}
+
+impl Display for PgLtree {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, label) in self.labels.iter().enumerate() {
+            if i > 0 {
+                f.write_str(".")?;
+            }
+
+            f.write_str(label)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Type<Postgres> for PgLtree {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("LTREE")
+    }
+}
+
+impl Encode<Postgres> for PgLtree {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.push(VERSION);
+        buf.extend_from_slice(self.to_string().as_bytes());
+    }
+
+    fn size_hint(&self) -> usize {
+        // the version and each label along with the separator before it (or the version)
+        let len: usize = self.labels.iter().map(|label| label.len() + 1).sum();
+
+        len.max(1)
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgLtree {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
+        match value.try_into()? {
+            PgValue::Binary(buf) => match buf.split_first() {
+                Some((&VERSION, buf)) => from_utf8(buf).map_err(Error::decode)?.parse(),
+
+                Some((version, _)) => Err(Error::Decode(
+                    format!("unsupported ltree binary format version {}", version).into(),
+                )),
+
+                None => Err(Error::Decode("expected at least 1 byte for ltree".into())),
+            },
+
+            PgValue::Text(s) => s.parse(),
+        }
+    }
+}
+
+#[test]
+fn test_encode_ltree() {
+    let mut buf = Vec::new();
+
+    let ltree: PgLtree = "Top.Science.Astronomy".parse().unwrap();
+    ltree.encode(&mut buf);
+
+    assert_eq!(buf, b"\x01Top.Science.Astronomy");
+    assert_eq!(ltree.size_hint(), buf.len());
+
+    buf.clear();
+
+    PgLtree::new().encode(&mut buf);
+
+    assert_eq!(buf, b"\x01");
+}
+
+#[test]
+fn test_decode_ltree() {
+    let ltree = PgLtree::decode(Some(PgValue::Binary(b"\x01a.b_2.c"))).unwrap();
+
+    assert_eq!(ltree.labels().collect::<Vec<_>>(), ["a", "b_2", "c"]);
+
+    let ltree = PgLtree::decode(Some(PgValue::Text("a.b_2.c"))).unwrap();
+
+    assert_eq!(ltree.to_string(), "a.b_2.c");
+
+    assert!(PgLtree::decode(Some(PgValue::Text(""))).unwrap().is_empty());
+    assert!(PgLtree::decode(Some(PgValue::Binary(b"\x02a.b"))).is_err());
+    assert!(PgLtree::decode(Some(PgValue::Binary(b""))).is_err());
+}
+
+#[test]
+fn test_parse_invalid_ltree() {
+    assert!("a..b".parse::<PgLtree>().is_err());
+    assert!("a.b-c".parse::<PgLtree>().is_err());
+    assert!("a.b c".parse::<PgLtree>().is_err());
+    assert!("a.".parse::<PgLtree>().is_err());
+
+    let mut ltree = PgLtree::new();
+
+    assert!(ltree.push("a.b").is_err());
+    assert!(ltree.is_empty());
+}
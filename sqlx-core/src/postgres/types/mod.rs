@@ -5,13 +5,32 @@
 //! | Rust type                             | Postgres type(s)                                     |
 //! |---------------------------------------|------------------------------------------------------|
 //! | `bool`                                | BOOL                                                 |
+//! | `u8`                                  | "CHAR"                                               |
+//! | `char`                                | CHAR(1), BPCHAR, TEXT, VARCHAR, "CHAR"               |
 //! | `i16`                                 | SMALLINT, SMALLSERIAL, INT2                          |
 //! | `i32`                                 | INT, SERIAL, INT4                                    |
 //! | `i64`                                 | BIGINT, BIGSERIAL, INT8                              |
+//! | `u32`                                 | OID, REGCLASS, REGTYPE, REGPROC, REGCONFIG, ...      |
+//! | `u64`                                 | BIGINT, BIGSERIAL, INT8 (non-negative values only)   |
+//! | `PgRegValue`                          | REGCLASS, REGTYPE, REGPROC, REGCONFIG, ...           |
 //! | `f32`                                 | REAL, FLOAT4                                         |
 //! | `f64`                                 | DOUBLE PRECISION, FLOAT8                             |
-//! | `&str`, `String`                      | VARCHAR, CHAR(N), TEXT, CITEXT, NAME                 |
-//! | `&[u8]`, `Vec<u8>`                    | BYTEA                                                |
+//! | `&str`, `String`, `Box<str>`          | VARCHAR, CHAR(N), TEXT, CITEXT, NAME                 |
+//! | `&[u8]`, `Vec<u8>`, `Box<[u8]>`       | BYTEA                                                |
+//! | `PgRange<i32>`                        | INT4RANGE                                            |
+//! | `PgRange<i64>`                        | INT8RANGE                                            |
+//! | `PgRange<bigdecimal::BigDecimal>`     | NUMRANGE (requires the `bigdecimal` feature flag)    |
+//! | `PgInterval`                          | INTERVAL                                             |
+//! | `PgLtree`                             | LTREE (from the `ltree` extension)                   |
+//! | `PgXml`                               | XML                                                  |
+//! | `()`                                  | VOID (decode only)                                   |
+//!
+//! The `std::num::NonZero*` integers are supported wherever the integer they wrap is; decoding
+//! a zero is an error.
+//!
+//! Postgres has no unsigned 64-bit integer, so a `u64` is stored as an `INT8` and only values up
+//! to `i64::MAX` round-trip: encoding a larger value panics and decoding a negative value
+//! is an error.
 //!
 //! ### [`chrono`](https://crates.io/crates/chrono)
 //!
@@ -24,6 +43,10 @@
 //! | `chrono::NaiveDateTime`               | TIMESTAMP                                            |
 //! | `chrono::NaiveTime`                   | DATE                                                 |
 //! | `chrono::NaiveDate`                   | TIME                                                 |
+//! | `chrono::Duration`                    | INTERVAL (without a month or day component)          |
+//! | `PgRange<chrono::NaiveDate>`          | DATERANGE                                            |
+//! | `PgRange<chrono::NaiveDateTime>`      | TSRANGE                                              |
+//! | `PgRange<chrono::DateTime<Utc>>`      | TSTZRANGE                                            |
 //!
 //! ### [`uuid`](https://crates.io/crates/uuid)
 //!
@@ -41,6 +64,42 @@
 //! |---------------------------------------|------------------------------------------------------|
 //! | `ipnetwork::IpNetwork`                | INET, CIDR                                           |
 //!
+//! ### [`bytes`](https://crates.io/crates/bytes)
+//!
+//! Requires the `bytes` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `bytes::Bytes`                        | BYTEA                                                |
+//!
+//! ### [`json`](https://crates.io/crates/serde_json)
+//!
+//! Requires the `json` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `serde_json::Value`                   | JSON, JSONB                                          |
+//! | `Json<T>`                             | JSON, JSONB                                          |
+//!
+//! ### Geometry
+//!
+//! Requires the `geometry` Cargo feature flag.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `PgPoint`                             | POINT                                                |
+//! | `PgLine`                              | LINE                                                 |
+//! | `PgLSeg`                              | LSEG                                                 |
+//! | `PgBox`                               | BOX                                                  |
+//! | `PgPath`                              | PATH                                                 |
+//! | `PgPolygon`                           | POLYGON                                              |
+//!
+//! # Arrays
+//!
+//! One-dimensional arrays are supported as `&[T]`, `Vec<T>` and `[T; N]` for every type `T`
+//! above that implements [`HasArrayType`] (all but `u8`, as `&[u8]` is BYTEA). Use
+//! `Option<T>` as the element type if the array may contain `NULL`.
+//!
 //! # Composite types
 //!
 //! Anonymous composite types are represented as tuples.
@@ -59,11 +118,26 @@ use crate::postgres::protocol::TypeId;
 use crate::postgres::{PgValue, Postgres};
 use crate::types::TypeInfo;
 
+mod array;
 mod bool;
 mod bytes;
+mod char;
 mod float;
 mod int;
+mod interval;
+mod ltree;
+mod range;
+mod reg;
 mod str;
+mod void;
+mod xml;
+
+pub use array::HasArrayType;
+pub use interval::PgInterval;
+pub use ltree::PgLtree;
+pub use range::PgRange;
+pub use reg::PgRegValue;
+pub use xml::PgXml;
 
 // types we want to integration test but don't want to stabilize
 #[doc(hidden)]
@@ -81,8 +155,18 @@ mod uuid;
 #[cfg(feature = "ipnetwork")]
 mod ipnetwork;
 
+#[cfg(feature = "json")]
+mod json;
+
+#[cfg(feature = "geometry")]
+mod geometry;
+
+#[cfg(feature = "geometry")]
+pub use geometry::{PgBox, PgLSeg, PgLine, PgPath, PgPoint, PgPolygon};
+
 /// Type information for a Postgres SQL type.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "offline", derive(serde::Serialize, serde::Deserialize))]
 pub struct PgTypeInfo {
     pub(crate) id: TypeId,
     pub(crate) name: Option<SharedStr>,
@@ -107,19 +191,40 @@ impl PgTypeInfo {
         }
     }
 
+    /// Create a `PgTypeInfo` from a type's name, for types that do not have a fixed object
+    /// identifier such as enums, composite types and types from extensions.
+    ///
+    /// The object identifier is looked up by each connection the first time an argument of
+    /// this type is bound and is then cached for the life of the connection. The name is
+    /// read as it would be in SQL: it may be schema-qualified (`"inventory.mood"`) and is
+    /// folded to lowercase unless quoted (`"\"Mood\""`). If no such type is visible,
+    /// executing the query returns [`Error::TypeNotFound`](crate::Error::TypeNotFound).
+    pub fn with_name(name: &'static str) -> Self {
+        Self::new(TypeId(0), name)
+    }
+
     #[doc(hidden)]
     pub fn type_name(&self) -> &str {
-        self.name.as_deref().unwrap_or("<UNKNOWN>")
+        self.name.as_deref().unwrap_or("<unknown>")
     }
 
     #[doc(hidden)]
     pub fn type_feature_gate(&self) -> Option<&'static str> {
         match self.id {
             TypeId::DATE | TypeId::TIME | TypeId::TIMESTAMP | TypeId::TIMESTAMPTZ => Some("chrono"),
+            TypeId::INTERVAL => Some("chrono"),
+            TypeId::DATERANGE | TypeId::TSRANGE | TypeId::TSTZRANGE => Some("chrono"),
             TypeId::UUID => Some("uuid"),
             // we can support decoding `PgNumeric` but it's decidedly less useful to the layman
-            TypeId::NUMERIC => Some("bigdecimal"),
+            TypeId::NUMERIC | TypeId::NUMRANGE => Some("bigdecimal"),
             TypeId::CIDR | TypeId::INET => Some("ipnetwork"),
+            TypeId::JSON | TypeId::JSONB => Some("json"),
+            TypeId::POINT
+            | TypeId::LINE
+            | TypeId::LSEG
+            | TypeId::BOX
+            | TypeId::PATH
+            | TypeId::POLYGON => Some("geometry"),
             _ => None,
         }
     }
@@ -141,12 +246,36 @@ impl Display for PgTypeInfo {
 }
 
 impl TypeInfo for PgTypeInfo {
+    fn name(&self) -> &str {
+        // types read from a row only carry their OID; builtin types can be named anyway
+        self.name
+            .as_deref()
+            .or_else(|| self.id.name())
+            .unwrap_or("<unknown>")
+    }
+
     fn compatible(&self, other: &Self) -> bool {
         match (self.id, other.id) {
             (TypeId::CIDR, TypeId::INET)
             | (TypeId::INET, TypeId::CIDR)
             | (TypeId::ARRAY_CIDR, TypeId::ARRAY_INET)
             | (TypeId::ARRAY_INET, TypeId::ARRAY_CIDR) => true,
+
+            // `NAME` is a string type used throughout the system catalogs
+            (TypeId::TEXT, TypeId::NAME)
+            | (TypeId::NAME, TypeId::TEXT)
+            | (TypeId::ARRAY_TEXT, TypeId::ARRAY_NAME)
+            | (TypeId::ARRAY_NAME, TypeId::ARRAY_TEXT) => true,
+
+            // the `REG*` types are aliases of `OID` that are displayed as names in text
+            (TypeId::OID, other) | (other, TypeId::OID) if is_reg_type(other) => true,
+
+            // `JSONB` differs from `JSON` only in the storage and binary format
+            (TypeId::JSON, TypeId::JSONB)
+            | (TypeId::JSONB, TypeId::JSON)
+            | (TypeId::ARRAY_JSON, TypeId::ARRAY_JSONB)
+            | (TypeId::ARRAY_JSONB, TypeId::ARRAY_JSON) => true,
+
             _ => {
                 // TODO: 99% of postgres types are direct equality for [compatible]; when we add something that isn't (e.g, JSON/JSONB), fix this here
                 self.id.0 == other.id.0
@@ -155,6 +284,58 @@ impl TypeInfo for PgTypeInfo {
     }
 }
 
+// `TIMESTAMP` and `TIMESTAMPTZ` (and their arrays and ranges) have the same binary format
+pub(crate) fn is_time_zone_mismatch(expected: TypeId, found: TypeId) -> bool {
+    matches!(
+        (expected, found),
+        (TypeId::TIMESTAMP, TypeId::TIMESTAMPTZ)
+            | (TypeId::TIMESTAMPTZ, TypeId::TIMESTAMP)
+            | (TypeId::ARRAY_TIMESTAMP, TypeId::ARRAY_TIMESTAMPTZ)
+            | (TypeId::ARRAY_TIMESTAMPTZ, TypeId::ARRAY_TIMESTAMP)
+            | (TypeId::TSRANGE, TypeId::TSTZRANGE)
+            | (TypeId::TSTZRANGE, TypeId::TSRANGE)
+    )
+}
+
+// `OID` is unsigned; its values above `i32::MAX` would be read as negative numbers when
+// decoded into a signed integer, as every integer type is decoded the same way
+pub(crate) fn is_oid_mismatch(expected: TypeId, found: TypeId) -> bool {
+    matches!(
+        (expected, found),
+        (TypeId::INT2, TypeId::OID)
+            | (TypeId::INT4, TypeId::OID)
+            | (TypeId::INT8, TypeId::OID)
+            | (TypeId::ARRAY_INT2, TypeId::ARRAY_OID)
+            | (TypeId::ARRAY_INT4, TypeId::ARRAY_OID)
+            | (TypeId::ARRAY_INT8, TypeId::ARRAY_OID)
+    )
+}
+
+// A few builtin types (and so their arrays) have no binary output function; asking for one of
+// them in binary fails the whole query, so their columns are always received as text
+pub(crate) fn has_binary_output(id: TypeId) -> bool {
+    !matches!(
+        id,
+        TypeId::ACLITEM | TypeId::ARRAY_ACLITEM | TypeId::GTSVECTOR | TypeId::ARRAY_GTSVECTOR
+    )
+}
+
+fn is_reg_type(id: TypeId) -> bool {
+    [
+        TypeId::REGPROC,
+        TypeId::REGPROCEDURE,
+        TypeId::REGOPER,
+        TypeId::REGOPERATOR,
+        TypeId::REGCLASS,
+        TypeId::REGTYPE,
+        TypeId::REGCONFIG,
+        TypeId::REGDICTIONARY,
+        TypeId::REGNAMESPACE,
+        TypeId::REGROLE,
+    ]
+    .contains(&id)
+}
+
 impl<'de, T> Decode<'de, Postgres> for Option<T>
 where
     T: Decode<'de, Postgres>,
@@ -203,8 +384,40 @@ impl From<String> for SharedStr {
     }
 }
 
+#[cfg(feature = "offline")]
+impl serde::Serialize for SharedStr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self)
+    }
+}
+
+#[cfg(feature = "offline")]
+impl<'de> serde::Deserialize<'de> for SharedStr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(SharedStr::from)
+    }
+}
+
 impl fmt::Display for SharedStr {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         fmt.pad(self)
     }
 }
+
+#[test]
+fn test_type_info_name() {
+    use crate::types::Type;
+
+    assert_eq!(<i32 as Type<Postgres>>::type_info().name(), "INT4");
+
+    // builtin types are named from their OID
+    assert_eq!(PgTypeInfo::with_oid(25).name(), "TEXT");
+    assert_eq!(PgTypeInfo::with_oid(1016).name(), "INT8[]");
+    assert_eq!(PgTypeInfo::with_oid(123_456).name(), "<unknown>");
+}
@@ -0,0 +1,387 @@
+use std::convert::TryInto;
+use std::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+use byteorder::{NetworkEndian, ReadBytesExt};
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::error::Error;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::types::{HasArrayType, PgTypeInfo};
+use crate::postgres::{PgValue, Postgres};
+use crate::types::Type;
+
+// https://github.com/postgres/postgres/blob/master/src/include/utils/rangetypes.h
+const RANGE_EMPTY: u8 = 0x01;
+const RANGE_LB_INC: u8 = 0x02;
+const RANGE_UB_INC: u8 = 0x04;
+const RANGE_LB_INF: u8 = 0x08;
+const RANGE_UB_INF: u8 = 0x10;
+
+/// A Postgres range of values of `T`, e.g. `INT4RANGE` or `TSTZRANGE`.
+///
+/// Postgres normalizes ranges of discrete types (`INT4RANGE`, `INT8RANGE` and `DATERANGE`)
+/// to an inclusive lower bound and an exclusive upper bound, so `[1,5]` is read back
+/// as `[1,6)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PgRange<T> {
+    start: Bound<T>,
+    end: Bound<T>,
+    empty: bool,
+}
+
+impl<T> PgRange<T> {
+    /// Creates a range between `start` and `end`.
+    pub fn new(start: Bound<T>, end: Bound<T>) -> Self {
+        Self {
+            start,
+            end,
+            empty: false,
+        }
+    }
+
+    /// Creates an empty range, `'empty'` in Postgres.
+    pub fn empty() -> Self {
+        Self {
+            start: Bound::Unbounded,
+            end: Bound::Unbounded,
+            empty: true,
+        }
+    }
+
+    /// Returns `true` if this is the empty range.
+    pub fn is_empty(&self) -> bool {
+        self.empty
+    }
+
+    /// Returns the lower bound; `Unbounded` for the empty range.
+    pub fn start(&self) -> Bound<&T> {
+        as_ref(&self.start)
+    }
+
+    /// Returns the upper bound; `Unbounded` for the empty range.
+    pub fn end(&self) -> Bound<&T> {
+        as_ref(&self.end)
+    }
+}
+
+fn as_ref<T>(bound: &Bound<T>) -> Bound<&T> {
+    match bound {
+        Bound::Included(value) => Bound::Included(value),
+        Bound::Excluded(value) => Bound::Excluded(value),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+impl<T> From<(Bound<T>, Bound<T>)> for PgRange<T> {
+    fn from((start, end): (Bound<T>, Bound<T>)) -> Self {
+        Self::new(start, end)
+    }
+}
+
+impl<T> From<Range<T>> for PgRange<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::new(Bound::Included(range.start), Bound::Excluded(range.end))
+    }
+}
+
+impl<T> From<RangeInclusive<T>> for PgRange<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+
+        Self::new(Bound::Included(start), Bound::Included(end))
+    }
+}
+
+impl<T> From<RangeFrom<T>> for PgRange<T> {
+    fn from(range: RangeFrom<T>) -> Self {
+        Self::new(Bound::Included(range.start), Bound::Unbounded)
+    }
+}
+
+impl<T> From<RangeTo<T>> for PgRange<T> {
+    fn from(range: RangeTo<T>) -> Self {
+        Self::new(Bound::Unbounded, Bound::Excluded(range.end))
+    }
+}
+
+impl<T> From<RangeToInclusive<T>> for PgRange<T> {
+    fn from(range: RangeToInclusive<T>) -> Self {
+        Self::new(Bound::Unbounded, Bound::Included(range.end))
+    }
+}
+
+impl<T> From<RangeFull> for PgRange<T> {
+    fn from(_: RangeFull) -> Self {
+        Self::new(Bound::Unbounded, Bound::Unbounded)
+    }
+}
+
+macro_rules! impl_range_type {
+    ($(#[$meta:meta])* $ty:ty, $id:ident, $name:literal, $array_id:ident, $array_name:literal) => {
+        $(#[$meta])*
+        impl Type<Postgres> for PgRange<$ty> {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::new(TypeId::$id, $name)
+            }
+        }
+
+        $(#[$meta])*
+        impl HasArrayType for PgRange<$ty> {
+            fn array_type_info() -> PgTypeInfo {
+                PgTypeInfo::new(TypeId::$array_id, $array_name)
+            }
+        }
+    };
+}
+
+impl_range_type!(i32, INT4RANGE, "INT4RANGE", ARRAY_INT4RANGE, "INT4RANGE[]");
+impl_range_type!(i64, INT8RANGE, "INT8RANGE", ARRAY_INT8RANGE, "INT8RANGE[]");
+
+impl_range_type!(
+    #[cfg(feature = "bigdecimal_bigint")]
+    bigdecimal::BigDecimal,
+    NUMRANGE,
+    "NUMRANGE",
+    ARRAY_NUMRANGE,
+    "NUMRANGE[]"
+);
+
+impl_range_type!(
+    #[cfg(feature = "chrono")]
+    chrono::NaiveDate,
+    DATERANGE,
+    "DATERANGE",
+    ARRAY_DATERANGE,
+    "DATERANGE[]"
+);
+
+impl_range_type!(
+    #[cfg(feature = "chrono")]
+    chrono::NaiveDateTime,
+    TSRANGE,
+    "TSRANGE",
+    ARRAY_TSRANGE,
+    "TSRANGE[]"
+);
+
+#[cfg(feature = "chrono")]
+impl<Tz> Type<Postgres> for PgRange<chrono::DateTime<Tz>>
+where
+    Tz: chrono::TimeZone,
+{
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::TSTZRANGE, "TSTZRANGE")
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz> HasArrayType for PgRange<chrono::DateTime<Tz>>
+where
+    Tz: chrono::TimeZone,
+{
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_TSTZRANGE, "TSTZRANGE[]")
+    }
+}
+
+impl<T> Encode<Postgres> for PgRange<T>
+where
+    T: Encode<Postgres>,
+{
+    fn encode(&self, buf: &mut Vec<u8>) {
+        if self.empty {
+            buf.push(RANGE_EMPTY);
+            return;
+        }
+
+        let mut flags = 0;
+
+        flags |= match self.start {
+            Bound::Included(_) => RANGE_LB_INC,
+            Bound::Excluded(_) => 0,
+            Bound::Unbounded => RANGE_LB_INF,
+        };
+
+        flags |= match self.end {
+            Bound::Included(_) => RANGE_UB_INC,
+            Bound::Excluded(_) => 0,
+            Bound::Unbounded => RANGE_UB_INF,
+        };
+
+        buf.push(flags);
+
+        encode_bound(&self.start, buf);
+        encode_bound(&self.end, buf);
+    }
+
+    fn size_hint(&self) -> usize {
+        let bound_size = |bound: &Bound<T>| match bound {
+            Bound::Included(value) | Bound::Excluded(value) => 4 + value.size_hint(),
+            Bound::Unbounded => 0,
+        };
+
+        1 + bound_size(&self.start) + bound_size(&self.end)
+    }
+}
+
+fn encode_bound<T>(bound: &Bound<T>, buf: &mut Vec<u8>)
+where
+    T: Encode<Postgres>,
+{
+    if let Bound::Included(value) | Bound::Excluded(value) = bound {
+        // write zeros for length
+        buf.extend(&[0; 4]);
+
+        let start = buf.len();
+        value.encode(buf);
+        let size = buf.len() - start;
+
+        // replaces zeros with actual length
+        buf[start - 4..start].copy_from_slice(&(size as i32).to_be_bytes());
+    }
+}
+
+impl<'de, T> Decode<'de, Postgres> for PgRange<T>
+where
+    T: Decode<'de, Postgres>,
+{
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
+        match value.try_into()? {
+            PgValue::Binary(buf) => decode_binary(buf),
+            PgValue::Text(s) => decode_text(s),
+        }
+    }
+}
+
+fn decode_binary<'de, T>(mut buf: &'de [u8]) -> crate::Result<PgRange<T>>
+where
+    T: Decode<'de, Postgres>,
+{
+    let flags = buf.read_u8().map_err(Error::decode)?;
+
+    if flags & RANGE_EMPTY != 0 {
+        return Ok(PgRange::empty());
+    }
+
+    let start = decode_binary_bound(&mut buf, flags, RANGE_LB_INC, RANGE_LB_INF)?;
+    let end = decode_binary_bound(&mut buf, flags, RANGE_UB_INC, RANGE_UB_INF)?;
+
+    Ok(PgRange::new(start, end))
+}
+
+fn decode_binary_bound<'de, T>(
+    buf: &mut &'de [u8],
+    flags: u8,
+    inclusive: u8,
+    infinite: u8,
+) -> crate::Result<Bound<T>>
+where
+    T: Decode<'de, Postgres>,
+{
+    if flags & infinite != 0 {
+        return Ok(Bound::Unbounded);
+    }
+
+    let len = buf.read_i32::<NetworkEndian>().map_err(Error::decode)?;
+
+    if len < 0 || len as usize > buf.len() {
+        return Err(Error::Decode(
+            format!("invalid length of range bound: {}", len).into(),
+        ));
+    }
+
+    let (value, rest) = buf.split_at(len as usize);
+    *buf = rest;
+
+    let value = T::decode(Some(PgValue::Binary(value)))?;
+
+    Ok(if flags & inclusive != 0 {
+        Bound::Included(value)
+    } else {
+        Bound::Excluded(value)
+    })
+}
+
+// https://www.postgresql.org/docs/current/rangetypes.html#RANGETYPES-IO
+fn decode_text<'de, T>(s: &'de str) -> crate::Result<PgRange<T>>
+where
+    T: Decode<'de, Postgres>,
+{
+    if s.eq_ignore_ascii_case("empty") {
+        return Ok(PgRange::empty());
+    }
+
+    let invalid = || Error::Decode(format!("unexpected value {:?} for range", s).into());
+
+    let lower_inclusive = match s.as_bytes().first() {
+        Some(b'[') => true,
+        Some(b'(') => false,
+        _ => return Err(invalid()),
+    };
+
+    let upper_inclusive = match s.as_bytes().last() {
+        Some(b']') => true,
+        Some(b')') => false,
+        _ => return Err(invalid()),
+    };
+
+    let inner = &s[1..s.len() - 1];
+
+    // the bounds are separated by the first comma that is not inside a quoted bound
+    let mut in_quotes = false;
+    let comma = inner
+        .char_indices()
+        .find(|&(_, ch)| {
+            if ch == '"' {
+                in_quotes = !in_quotes;
+            }
+
+            ch == ',' && !in_quotes
+        })
+        .map(|(index, _)| index)
+        .ok_or_else(invalid)?;
+
+    let start = decode_text_bound(&inner[..comma], lower_inclusive)?;
+    let end = decode_text_bound(&inner[comma + 1..], upper_inclusive)?;
+
+    Ok(PgRange::new(start, end))
+}
+
+fn decode_text_bound<'de, T>(s: &'de str, inclusive: bool) -> crate::Result<Bound<T>>
+where
+    T: Decode<'de, Postgres>,
+{
+    if s.is_empty() {
+        return Ok(Bound::Unbounded);
+    }
+
+    // bounds such as timestamps are quoted; the types we support never need escaping
+    let s = if s.len() >= 2 && s.starts_with('"') && s.ends_with('"') {
+        &s[1..s.len() - 1]
+    } else {
+        s
+    };
+
+    let value = T::decode(Some(PgValue::Text(s)))?;
+
+    Ok(if inclusive {
+        Bound::Included(value)
+    } else {
+        Bound::Excluded(value)
+    })
+}
+
+#[test]
+fn test_decode_text() {
+    let range: PgRange<i32> = decode_text("[1,5)").unwrap();
+    assert_eq!(range, PgRange::from(1..5));
+
+    let range: PgRange<i32> = decode_text("(,5]").unwrap();
+    assert_eq!(range, PgRange::from(..=5));
+
+    let range: PgRange<i32> = decode_text("empty").unwrap();
+    assert!(range.is_empty());
+
+    assert!(decode_text::<i32>("1,5").is_err());
+}
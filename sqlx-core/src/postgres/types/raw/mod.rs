@@ -3,3 +3,5 @@ mod record;
 
 pub use numeric::{PgNumeric, PgNumericSign};
 pub use record::{PgRecordDecoder, PgRecordEncoder};
+
+pub(crate) use record::format_binary_field;
@@ -3,9 +3,11 @@ use crate::encode::{Encode, IsNull};
 use crate::io::Buf;
 use crate::postgres::protocol::TypeId;
 use crate::postgres::{PgTypeInfo, PgValue, Postgres};
+use crate::row::decode_column;
 use crate::types::Type;
 use byteorder::BigEndian;
 use std::convert::TryInto;
+use std::fmt::Write;
 
 pub struct PgRecordEncoder<'a> {
     buf: &'a mut Vec<u8>,
@@ -28,6 +30,37 @@ impl<'a> PgRecordEncoder<'a> {
     pub fn finish(&mut self) {
         // replaces zeros with actual length
         self.buf[self.beg - 4..self.beg].copy_from_slice(&self.num.to_be_bytes());
+
+        // every length prefix must lead to the next field and the last field must end the
+        // buffer; a field that rewrote bytes before its own start would break this
+        debug_assert_eq!(
+            self.fields_end(),
+            Some(self.buf.len()),
+            "the fields of the record do not add up to its encoded size"
+        );
+    }
+
+    // Walks the length prefixes of the encoded fields and returns the offset at which
+    // the last one ends
+    fn fields_end(&self) -> Option<usize> {
+        let mut offset = self.beg;
+
+        for _ in 0..self.num {
+            // skip the oid
+            offset += 4;
+
+            let len = self.buf.get(offset..offset + 4)?;
+            let len = i32::from_be_bytes(len.try_into().ok()?);
+
+            offset += 4;
+
+            // a length of -1 is a NULL with no data
+            if len > 0 {
+                offset += len as usize;
+            }
+        }
+
+        Some(offset)
     }
 
     pub fn encode<T>(&mut self, value: T) -> &mut Self
@@ -47,6 +80,15 @@ impl<'a> PgRecordEncoder<'a> {
             self.buf[start - 4..start].copy_from_slice(&(-1_i32).to_be_bytes());
         } else {
             let end = self.buf.len();
+
+            // nested encoders share the buffer but only ever append to it, so everything
+            // from `start` on belongs to this field
+            debug_assert!(end >= start, "encoding a record field truncated the buffer");
+            debug_assert!(
+                end - start <= i32::MAX as usize,
+                "record field is too large"
+            );
+
             let size = end - start;
 
             // replaces zeros with actual length
@@ -61,147 +103,294 @@ impl<'a> PgRecordEncoder<'a> {
 }
 
 pub struct PgRecordDecoder<'de> {
-    value: PgValue<'de>,
+    value: RecordValue<'de>,
+    len: usize,
+    decoded: usize,
+}
+
+enum RecordValue<'de> {
+    Binary(&'de [u8]),
+    Text(PgRecordFields<'de>),
 }
 
 impl<'de> PgRecordDecoder<'de> {
     pub fn new(value: Option<PgValue<'de>>) -> crate::Result<Self> {
-        let mut value: PgValue = value.try_into()?;
+        let (value, len) = match value.try_into()? {
+            PgValue::Binary(mut buf) => {
+                let len = buf.get_u32::<BigEndian>()? as usize;
 
-        match value {
-            PgValue::Binary(ref mut buf) => {
-                let _expected_len = buf.get_u32::<BigEndian>()?;
+                (RecordValue::Binary(buf), len)
             }
 
-            PgValue::Text(ref mut s) => {
+            PgValue::Text(s) => {
                 // remove outer ( ... )
-                *s = &s[1..(s.len() - 1)];
+                let fields = PgRecordFields::new(&s[1..(s.len() - 1)]);
+                let len = fields.clone().count();
+
+                (RecordValue::Text(fields), len)
             }
-        }
+        };
+
+        Ok(Self {
+            value,
+            len,
+            decoded: 0,
+        })
+    }
+
+    /// Returns the number of fields in the record.
+    ///
+    /// In the text format a record with no fields cannot be told apart from a record with
+    /// a single `NULL` field; both are counted as one field.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the record has no fields.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
 
-        Ok(Self { value })
+    /// Returns the number of fields that have not been decoded yet.
+    pub fn remaining_fields(&self) -> usize {
+        self.len.saturating_sub(self.decoded)
     }
 
+    /// Decodes the next field of the record.
+    ///
+    /// In the binary format each field carries the OID of its type, so fields of anonymous
+    /// records (`ROW(..)`) are decoded without knowing the type of the record ahead of time;
+    /// if decoding fails, the error names the type of the field.
     pub fn decode<T>(&mut self) -> crate::Result<T>
     where
+        T: Type<Postgres>,
         T: Decode<'de, Postgres>,
     {
+        self.decoded += 1;
+
         match self.value {
-            PgValue::Binary(ref mut buf) => {
-                // TODO: We should fail if this type is not _compatible_; but
-                //       I want to make sure we handle this _and_ the outer level
-                //       type mismatch errors at the same time
-                let _oid = buf.get_u32::<BigEndian>()?;
-                let len = buf.get_i32::<BigEndian>()? as isize;
+            RecordValue::Binary(ref mut buf) => {
+                let type_id = TypeId(buf.get_u32::<BigEndian>()?);
+                let len = buf.get_i32::<BigEndian>()?;
 
                 let value = if len < 0 {
-                    T::decode(None)?
+                    None
+                } else if buf.len() < len as usize {
+                    return Err(crate::Error::Decode(
+                        "record field is longer than the record".into(),
+                    ));
                 } else {
-                    let value_buf = &buf[..(len as usize)];
-                    *buf = &buf[(len as usize)..];
+                    let (value, rest) = buf.split_at(len as usize);
+                    *buf = rest;
 
-                    T::decode(Some(PgValue::Binary(value_buf)))?
+                    Some(PgValue::Binary(value))
                 };
 
-                Ok(value)
+                decode_column::<Postgres, T>(value, &PgTypeInfo::with_oid(type_id.0))
             }
 
-            PgValue::Text(ref mut s) => {
-                let mut in_quotes = false;
-                let mut in_escape = false;
-                let mut is_quoted = false;
-                let mut prev_ch = '\0';
-                let mut eos = false;
-                let mut prev_index = 0;
-                let mut value = String::new();
-
-                let index = 'outer: loop {
-                    let mut iter = s.char_indices();
-                    while let Some((index, ch)) = iter.next() {
-                        match ch {
-                            ',' if !in_quotes => {
-                                break 'outer Some(prev_index);
-                            }
-
-                            ',' if prev_ch == '\0' => {
-                                break 'outer None;
-                            }
-
-                            '"' if prev_ch == '"' && index != 1 => {
-                                // Quotes are escaped with another quote
-                                in_quotes = false;
-                                value.push('"');
-                            }
-
-                            '"' if in_quotes => {
-                                in_quotes = false;
-                            }
-
-                            '\'' if in_escape => {
-                                in_escape = false;
-                                value.push('\'');
-                            }
-
-                            '"' if in_escape => {
-                                in_escape = false;
-                                value.push('"');
-                            }
-
-                            '\\' if in_escape => {
-                                in_escape = false;
-                                value.push('\\');
-                            }
-
-                            '\\' => {
-                                in_escape = true;
-                            }
-
-                            '"' => {
-                                is_quoted = true;
-                                in_quotes = true;
-                            }
-
-                            ch => {
-                                value.push(ch);
-                            }
-                        }
-
-                        prev_index = index;
-                        prev_ch = ch;
-                    }
-
-                    eos = true;
-
-                    break 'outer if prev_ch == '\0' {
-                        // NULL values have zero characters
-                        // Empty strings are ""
-                        None
-                    } else {
-                        Some(prev_index)
-                    };
-                };
+            RecordValue::Text(ref mut fields) => match fields.next() {
+                Some(field) => T::decode(field.map(PgValue::Text)),
 
-                let value = index.map(|index| {
-                    let mut s = &s[..=index];
+                None => Err(crate::Error::Decode(
+                    "record has fewer fields than expected".into(),
+                )),
+            },
+        }
+    }
 
-                    if is_quoted {
-                        s = &s[1..s.len() - 1];
-                    }
+    /// Decodes each of the remaining fields as text, regardless of its type.
+    ///
+    /// In the text format, each field is returned as Postgres formats it. In the binary
+    /// format, fields of boolean, integer, floating-point, string and `BYTEA` types are
+    /// formatted the same way (except that floats are formatted by Rust); fields of any
+    /// other type are an error.
+    pub fn decode_remaining_as_text(&mut self) -> crate::Result<Vec<Option<String>>> {
+        let mut fields = Vec::with_capacity(self.remaining_fields());
+
+        while self.remaining_fields() > 0 {
+            fields.push(self.decode_as_text()?);
+        }
 
-                    PgValue::Text(s)
-                });
+        Ok(fields)
+    }
 
-                let value = T::decode(value)?;
+    fn decode_as_text(&mut self) -> crate::Result<Option<String>> {
+        self.decoded += 1;
 
-                if !eos {
-                    *s = &s[index.unwrap_or(0) + 2..];
-                } else {
-                    *s = "";
+        match self.value {
+            RecordValue::Binary(ref mut buf) => {
+                let type_id = TypeId(buf.get_u32::<BigEndian>()?);
+                let len = buf.get_i32::<BigEndian>()?;
+
+                if len < 0 {
+                    return Ok(None);
+                }
+
+                if buf.len() < len as usize {
+                    return Err(crate::Error::Decode(
+                        "record field is longer than the record".into(),
+                    ));
+                }
+
+                let (value, rest) = buf.split_at(len as usize);
+                *buf = rest;
+
+                format_binary_field(type_id, value).map(Some)
+            }
+
+            RecordValue::Text(ref mut fields) => match fields.next() {
+                Some(field) => Ok(field.map(unescape_field)),
+
+                None => Err(crate::Error::Decode(
+                    "record has fewer fields than expected".into(),
+                )),
+            },
+        }
+    }
+}
+
+// Formats a field in the binary format as Postgres does in the text format
+pub(crate) fn format_binary_field(type_id: TypeId, buf: &[u8]) -> crate::Result<String> {
+    let value = Some(PgValue::Binary(buf));
+
+    let text = match type_id {
+        TypeId::BOOL => if <bool as Decode<Postgres>>::decode(value)? {
+            "t"
+        } else {
+            "f"
+        }
+        .to_owned(),
+
+        TypeId::INT2 => <i16 as Decode<Postgres>>::decode(value)?.to_string(),
+        TypeId::INT4 => <i32 as Decode<Postgres>>::decode(value)?.to_string(),
+        TypeId::INT8 => <i64 as Decode<Postgres>>::decode(value)?.to_string(),
+        TypeId::OID => <u32 as Decode<Postgres>>::decode(value)?.to_string(),
+
+        TypeId::FLOAT4 => format_float(<f32 as Decode<Postgres>>::decode(value)?.into()),
+        TypeId::FLOAT8 => format_float(<f64 as Decode<Postgres>>::decode(value)?),
+
+        TypeId::TEXT | TypeId::VARCHAR | TypeId::BPCHAR | TypeId::NAME | TypeId::UNKNOWN => {
+            <String as Decode<Postgres>>::decode(value)?
+        }
+
+        TypeId::BYTEA => {
+            let mut text = String::with_capacity(2 + buf.len() * 2);
+            text.push_str("\\x");
+
+            for byte in buf {
+                let _ = write!(text, "{:02x}", byte);
+            }
+
+            text
+        }
+
+        _ => {
+            return Err(crate::Error::Decode(
+                format!(
+                    "record field of type {} cannot be decoded as text in the binary format",
+                    type_id
+                        .name()
+                        .map_or_else(|| type_id.0.to_string(), Into::into)
+                )
+                .into(),
+            ));
+        }
+    };
+
+    Ok(text)
+}
+
+fn format_float(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_owned()
+    } else if value.is_infinite() {
+        if value > 0.0 { "Infinity" } else { "-Infinity" }.to_owned()
+    } else {
+        value.to_string()
+    }
+}
+
+// Removes the escaping of a field in the text format; only quoted fields contain
+// backslashes and quotes, which are escaped by a backslash or by doubling the quote
+fn unescape_field(field: &str) -> String {
+    let mut text = String::with_capacity(field.len());
+    let mut chars = field.chars();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' | '"' => text.extend(chars.next()),
+            _ => text.push(ch),
+        }
+    }
+
+    text
+}
+
+/// Splits the fields of a record in the text format, without the outer parentheses.
+///
+/// Yields `None` for a `NULL` field, which has no characters, and the field with any
+/// surrounding quotes removed otherwise; an empty string is always quoted as `""`.
+#[derive(Clone)]
+pub(crate) struct PgRecordFields<'a> {
+    rest: Option<&'a str>,
+}
+
+impl<'a> PgRecordFields<'a> {
+    pub(crate) fn new(s: &'a str) -> Self {
+        Self { rest: Some(s) }
+    }
+}
+
+impl<'a> Iterator for PgRecordFields<'a> {
+    type Item = Option<&'a str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let s = self.rest?;
+
+        let mut in_quotes = false;
+        let mut in_escape = false;
+        let mut end = None;
+
+        for (index, ch) in s.char_indices() {
+            match ch {
+                _ if in_escape => in_escape = false,
+
+                '\\' => in_escape = true,
+
+                // a quote inside quotes is escaped by doubling it, which toggles twice
+                '"' => in_quotes = !in_quotes,
+
+                ',' if !in_quotes => {
+                    end = Some(index);
+                    break;
                 }
 
-                Ok(value)
+                _ => {}
             }
         }
+
+        let field = match end {
+            Some(end) => {
+                self.rest = Some(&s[end + 1..]);
+                &s[..end]
+            }
+
+            None => {
+                self.rest = None;
+                s
+            }
+        };
+
+        if field.is_empty() {
+            return Some(None);
+        }
+
+        if field.len() >= 2 && field.starts_with('"') && field.ends_with('"') {
+            return Some(Some(&field[1..field.len() - 1]));
+        }
+
+        Some(Some(field))
     }
 }
 
@@ -293,6 +482,89 @@ fn test_encode_field() {
     assert_eq!(raw_encoded, &field_encoded[12..]);
 }
 
+#[test]
+fn test_encode_nested_record() {
+    #[derive(Clone, Copy)]
+    struct Inner(i32, Option<i32>);
+
+    struct Outer(&'static str, Inner);
+
+    impl Type<Postgres> for Inner {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_oid(2249)
+        }
+    }
+
+    impl Encode<Postgres> for Inner {
+        fn encode(&self, buf: &mut Vec<u8>) {
+            let mut encoder = PgRecordEncoder::new(buf);
+            encoder.encode(self.0).encode(self.1);
+            encoder.finish();
+        }
+
+        fn size_hint(&self) -> usize {
+            2 * (4 + 4) + 4
+        }
+    }
+
+    impl Type<Postgres> for Outer {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_oid(2249)
+        }
+    }
+
+    impl Encode<Postgres> for Outer {
+        fn encode(&self, buf: &mut Vec<u8>) {
+            let mut encoder = PgRecordEncoder::new(buf);
+            encoder.encode(self.0).encode(self.1);
+            encoder.finish();
+        }
+
+        fn size_hint(&self) -> usize {
+            2 * (4 + 4) + self.0.len()
+        }
+    }
+
+    // a record nested in a record, written after a field so the inner record
+    // does not start at the beginning of the buffer
+    let mut buf = vec![0xFF; 3];
+    let mut encoder = PgRecordEncoder::new(&mut buf);
+    encoder
+        .encode("hello")
+        .encode(Outer("world", Inner(7, None)));
+    encoder.finish();
+
+    let read_u32 = |at: usize| u32::from_be_bytes(buf[at..at + 4].try_into().unwrap());
+
+    // the outermost record: a text field and a record field
+    assert_eq!(read_u32(3), 2);
+    assert_eq!(read_u32(11), 5);
+    assert_eq!(&buf[15..20], b"hello");
+
+    // the length of the middle record covers everything after its prefix
+    let outer = 28;
+    assert_eq!(read_u32(outer - 4) as usize, buf.len() - outer);
+    assert_eq!(read_u32(outer), 2);
+    assert_eq!(read_u32(outer + 8), 5);
+    assert_eq!(&buf[outer + 12..outer + 17], b"world");
+
+    // and so does the length of the innermost record
+    let inner = outer + 25;
+    assert_eq!(read_u32(inner - 4) as usize, buf.len() - inner);
+    assert_eq!(read_u32(inner), 2);
+    assert_eq!(read_u32(inner + 8), 4);
+    assert_eq!(read_u32(inner + 12), 7);
+    assert_eq!(read_u32(inner + 20), u32::MAX);
+    assert_eq!(inner + 24, buf.len());
+
+    // and it decodes back, level by level
+    let mut decoder = PgRecordDecoder::new(Some(PgValue::Binary(&buf[3..]))).unwrap();
+    assert_eq!(decoder.decode::<String>().unwrap(), "hello");
+
+    let outer: (String, (i32, Option<i32>)) = decoder.decode().unwrap();
+    assert_eq!(outer, ("world".to_owned(), (7, None)));
+}
+
 #[test]
 fn test_decode_field() {
     let value = "Foo Bar".to_string();
@@ -307,3 +579,143 @@ fn test_decode_field() {
     let value_decoded: String = decoder.decode().unwrap();
     assert_eq!(value_decoded, value);
 }
+
+#[test]
+fn test_decode_field_of_another_type() {
+    let mut buf = Vec::new();
+    let mut encoder = PgRecordEncoder::new(&mut buf);
+    encoder.encode(1_i32);
+    encoder.finish();
+
+    // the field's own OID names its type when it cannot be decoded
+    let mut decoder = PgRecordDecoder::new(Some(PgValue::Binary(&buf))).unwrap();
+    let err = decoder.decode::<f64>().unwrap_err().to_string();
+
+    assert!(err.contains("FLOAT8"), "{}", err);
+    assert!(err.contains("INT4"), "{}", err);
+
+    // a field cannot extend past the end of the record
+    let mut decoder = PgRecordDecoder::new(Some(PgValue::Binary(&buf[..13]))).unwrap();
+
+    assert!(decoder.decode::<i32>().is_err());
+}
+
+#[test]
+fn test_record_fields() {
+    let fields = |s| PgRecordFields::new(s).collect::<Vec<_>>();
+
+    // NULL has no characters; an empty string is quoted
+    assert_eq!(fields(","), [None, None]);
+    assert_eq!(fields("\"\","), [Some(""), None]);
+    assert_eq!(fields(",\"\""), [None, Some("")]);
+
+    assert_eq!(fields("t,23512"), [Some("t"), Some("23512")]);
+    assert_eq!(
+        fields("t,\"Hello, World!\""),
+        [Some("t"), Some("Hello, World!")]
+    );
+    assert_eq!(fields("\"a\"\",\",b"), [Some("a\"\","), Some("b")]);
+    assert_eq!(fields("a\\,b,c"), [Some("a\\,b"), Some("c")]);
+}
+
+#[test]
+fn test_decode_text_fields() {
+    let mut decoder = PgRecordDecoder::new(Some(PgValue::Text("(,\"\")"))).unwrap();
+
+    assert_eq!(decoder.decode::<Option<String>>().unwrap(), None);
+    assert_eq!(decoder.decode::<String>().unwrap(), "");
+    assert!(decoder.decode::<Option<String>>().is_err());
+}
+
+#[test]
+fn test_decode_remaining_fields() {
+    for len in 0..4 {
+        let mut buf = Vec::new();
+        let mut encoder = PgRecordEncoder::new(&mut buf);
+
+        for i in 0..len {
+            encoder.encode(i);
+        }
+
+        encoder.finish();
+
+        let mut decoder = PgRecordDecoder::new(Some(PgValue::Binary(&buf))).unwrap();
+        assert_eq!(decoder.len(), len as usize);
+
+        let mut values = Vec::new();
+
+        while decoder.remaining_fields() > 0 {
+            values.push(decoder.decode::<i32>().unwrap());
+        }
+
+        assert_eq!(values, (0..len).collect::<Vec<_>>());
+    }
+
+    let mut decoder = PgRecordDecoder::new(Some(PgValue::Text("(1,,\"3\")"))).unwrap();
+    assert_eq!(decoder.len(), 3);
+
+    let mut values = Vec::new();
+
+    while decoder.remaining_fields() > 0 {
+        values.push(decoder.decode::<Option<i32>>().unwrap());
+    }
+
+    assert_eq!(values, [Some(1), None, Some(3)]);
+}
+
+#[test]
+fn test_decode_remaining_as_text() {
+    let mut buf = Vec::new();
+    let mut encoder = PgRecordEncoder::new(&mut buf);
+
+    encoder
+        .encode(1_i32)
+        .encode("hi")
+        .encode(true)
+        .encode(None::<i64>)
+        .encode(-0.5_f32)
+        .encode(&[0x0a_u8, 0xff][..]);
+
+    encoder.finish();
+
+    let mut decoder = PgRecordDecoder::new(Some(PgValue::Binary(&buf))).unwrap();
+    assert_eq!(
+        decoder.decode_remaining_as_text().unwrap(),
+        [
+            Some("1".to_owned()),
+            Some("hi".to_owned()),
+            Some("t".to_owned()),
+            None,
+            Some("-0.5".to_owned()),
+            Some("\\x0aff".to_owned()),
+        ]
+    );
+
+    let mut decoder = PgRecordDecoder::new(Some(PgValue::Text(
+        "(1,hi,t,,\"a \"\"b\"\"\",\"\\\\x0aff\")",
+    )))
+    .unwrap();
+
+    assert_eq!(
+        decoder.decode_remaining_as_text().unwrap(),
+        [
+            Some("1".to_owned()),
+            Some("hi".to_owned()),
+            Some("t".to_owned()),
+            None,
+            Some("a \"b\"".to_owned()),
+            Some("\\x0aff".to_owned()),
+        ]
+    );
+
+    // types without a text conversion are an error in the binary format
+    let mut buf = Vec::new();
+    PgRecordEncoder::new(&mut buf)
+        .encode(1.5_f64)
+        .encode(1_u32)
+        .finish();
+    buf[4..8].copy_from_slice(&TypeId::POINT.0.to_be_bytes());
+
+    let mut decoder = PgRecordDecoder::new(Some(PgValue::Binary(&buf))).unwrap();
+    assert!(decoder.decode_remaining_as_text().is_err());
+}
@@ -0,0 +1,66 @@
+use std::convert::TryInto;
+
+use byteorder::{NetworkEndian, ReadBytesExt};
+
+use crate::decode::Decode;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::types::PgTypeInfo;
+use crate::postgres::{PgValue, Postgres};
+use crate::types::Type;
+use crate::Error;
+
+/// A value of one of the object identifier alias types; `REGCLASS`, `REGTYPE`, `REGPROC`,
+/// `REGCONFIG`, etc.
+///
+/// What Postgres sends for these types depends on the format of the result:
+///
+///  * Prepared queries receive results in the binary format, which only contains the
+///    object identifier; these decode to [`PgRegValue::Oid`].
+///
+///  * Unprepared (simple) queries receive results in the text format, which contains the
+///    (possibly schema-qualified) name of the object; these decode to [`PgRegValue::Name`].
+///    If the object does not exist, Postgres sends the numeric OID as the name.
+///
+/// In either case, a `u32` or `String` can be decoded directly when the format is known.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PgRegValue {
+    Oid(u32),
+    Name(String),
+}
+
+impl PgRegValue {
+    /// Returns the object identifier, if the value was received in the binary format.
+    pub fn oid(&self) -> Option<u32> {
+        match self {
+            PgRegValue::Oid(oid) => Some(*oid),
+            PgRegValue::Name(_) => None,
+        }
+    }
+
+    /// Returns the name of the object, if the value was received in the text format.
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            PgRegValue::Oid(_) => None,
+            PgRegValue::Name(name) => Some(name),
+        }
+    }
+}
+
+impl Type<Postgres> for PgRegValue {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::OID, "OID")
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgRegValue {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
+        match value.try_into()? {
+            PgValue::Binary(mut buf) => buf
+                .read_u32::<NetworkEndian>()
+                .map(PgRegValue::Oid)
+                .map_err(Error::decode),
+
+            PgValue::Text(s) => Ok(PgRegValue::Name(s.to_owned())),
+        }
+    }
+}
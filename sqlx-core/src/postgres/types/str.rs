@@ -5,7 +5,7 @@ use crate::decode::Decode;
 use crate::encode::Encode;
 use crate::postgres::protocol::TypeId;
 use crate::postgres::row::PgValue;
-use crate::postgres::types::PgTypeInfo;
+use crate::postgres::types::{HasArrayType, PgTypeInfo};
 use crate::postgres::Postgres;
 use crate::types::Type;
 use crate::Error;
@@ -16,8 +16,8 @@ impl Type<Postgres> for str {
     }
 }
 
-impl Type<Postgres> for [&'_ str] {
-    fn type_info() -> PgTypeInfo {
+impl HasArrayType for &'_ str {
+    fn array_type_info() -> PgTypeInfo {
         PgTypeInfo::new(TypeId::ARRAY_TEXT, "TEXT[]")
     }
 }
@@ -28,6 +28,12 @@ impl Type<Postgres> for String {
     }
 }
 
+impl HasArrayType for String {
+    fn array_type_info() -> PgTypeInfo {
+        <&str as HasArrayType>::array_type_info()
+    }
+}
+
 impl Encode<Postgres> for str {
     fn encode(&self, buf: &mut Vec<u8>) {
         buf.extend_from_slice(self.as_bytes());
@@ -56,9 +62,13 @@ impl<'de> Decode<'de, Postgres> for String {
 
 impl<'de> Decode<'de, Postgres> for &'de str {
     fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
-        match value.try_into()? {
-            PgValue::Binary(buf) => from_utf8(buf).map_err(Error::decode),
-            PgValue::Text(s) => Ok(s),
-        }
+        let s = match value.try_into()? {
+            PgValue::Binary(buf) => from_utf8(buf).map_err(Error::decode)?,
+            PgValue::Text(s) => s,
+        };
+
+        // `NAME` is a fixed-width (NAMEDATALEN) type and may arrive NUL-padded;
+        // `TEXT` and friends can never contain a NUL so this is otherwise a no-op
+        Ok(s.trim_end_matches('\0'))
     }
 }
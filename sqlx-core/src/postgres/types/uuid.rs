@@ -7,7 +7,7 @@ use crate::decode::Decode;
 use crate::encode::Encode;
 use crate::postgres::protocol::TypeId;
 use crate::postgres::row::PgValue;
-use crate::postgres::types::PgTypeInfo;
+use crate::postgres::types::{HasArrayType, PgTypeInfo};
 use crate::postgres::Postgres;
 use crate::types::Type;
 
@@ -17,8 +17,8 @@ impl Type<Postgres> for Uuid {
     }
 }
 
-impl Type<Postgres> for [Uuid] {
-    fn type_info() -> PgTypeInfo {
+impl HasArrayType for Uuid {
+    fn array_type_info() -> PgTypeInfo {
         PgTypeInfo::new(TypeId::ARRAY_UUID, "UUID[]")
     }
 }
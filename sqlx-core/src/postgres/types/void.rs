@@ -0,0 +1,36 @@
+use crate::decode::Decode;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::types::PgTypeInfo;
+use crate::postgres::{PgValue, Postgres};
+use crate::types::Type;
+
+// Functions that return nothing, such as `pg_advisory_lock`, still produce a column of
+// type `VOID`, whose value is empty in both the binary and the text format.
+
+impl Type<Postgres> for () {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::VOID, "VOID")
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for () {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
+        match value {
+            None | Some(PgValue::Binary([])) | Some(PgValue::Text("")) => Ok(()),
+
+            Some(_) => Err(crate::Error::Decode(
+                "expected an empty value for VOID".into(),
+            )),
+        }
+    }
+}
+
+#[test]
+fn test_decode_void() {
+    assert!(<() as Decode<Postgres>>::decode(Some(PgValue::Binary(&[]))).is_ok());
+    assert!(<() as Decode<Postgres>>::decode(Some(PgValue::Text(""))).is_ok());
+    assert!(<() as Decode<Postgres>>::decode(None).is_ok());
+
+    assert!(<() as Decode<Postgres>>::decode(Some(PgValue::Binary(&[0, 0, 0, 1]))).is_err());
+    assert!(<() as Decode<Postgres>>::decode(Some(PgValue::Text("1"))).is_err());
+}
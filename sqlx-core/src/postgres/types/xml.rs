@@ -0,0 +1,76 @@
+use std::convert::TryInto;
+use std::ops::Deref;
+use std::str::from_utf8;
+
+use crate::decode::Decode;
+use crate::encode::Encode;
+use crate::postgres::protocol::TypeId;
+use crate::postgres::types::{HasArrayType, PgTypeInfo};
+use crate::postgres::{PgValue, Postgres};
+use crate::types::Type;
+use crate::Error;
+
+/// An `XML` document or content fragment.
+///
+/// The value is passed through as text; it is neither parsed nor validated here, though
+/// Postgres rejects malformed XML on input.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PgXml(pub String);
+
+impl PgXml {
+    /// Consumes the `PgXml` wrapper and returns the XML text.
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl From<String> for PgXml {
+    fn from(xml: String) -> Self {
+        PgXml(xml)
+    }
+}
+
+impl Deref for PgXml {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Type<Postgres> for PgXml {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::XML, "XML")
+    }
+}
+
+impl HasArrayType for PgXml {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::new(TypeId::ARRAY_XML, "XML[]")
+    }
+}
+
+// the binary format is the text of the document in the client encoding, which is always
+// UTF-8 for SQLx connections
+
+impl Encode<Postgres> for PgXml {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self.0.as_bytes());
+    }
+
+    fn size_hint(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgXml {
+    fn decode(value: Option<PgValue<'de>>) -> crate::Result<Self> {
+        match value.try_into()? {
+            PgValue::Binary(buf) => from_utf8(buf)
+                .map(|s| PgXml(s.to_owned()))
+                .map_err(Error::decode),
+
+            PgValue::Text(s) => Ok(PgXml(s.to_owned())),
+        }
+    }
+}
@@ -8,8 +8,8 @@ use futures_util::TryFutureExt;
 use crate::arguments::Arguments;
 use crate::cursor::Cursor;
 use crate::database::{Database, HasCursor, HasRow};
-use crate::encode::Encode;
-use crate::executor::{Execute, Executor, RefExecutor};
+use crate::encode::{DynEncode, Encode};
+use crate::executor::{Execute, Executor, QueryResult, RefExecutor};
 use crate::types::Type;
 
 /// Raw SQL query with bind parameters. Returned by [`query`][crate::query::query].
@@ -71,6 +71,15 @@ where
         self
     }
 
+    /// Bind a value whose type is only known at runtime, such as one of a list of
+    /// heterogeneous values assembled by a query builder.
+    ///
+    /// See [`bind`](Query::bind).
+    pub fn bind_dyn(mut self, value: &dyn DynEncode<DB>) -> Self {
+        self.arguments.add_dyn(value);
+        self
+    }
+
     #[doc(hidden)]
     pub fn bind_all(self, arguments: DB::Arguments) -> Query<'q, DB> {
         Query {
@@ -81,6 +90,22 @@ where
     }
 }
 
+#[cfg(feature = "postgres")]
+#[cfg_attr(docsrs, doc(cfg(feature = "postgres")))]
+impl<'q> Query<'q, crate::postgres::Postgres> {
+    /// Bind a value to be sent in the text format of Postgres instead of the binary format.
+    ///
+    /// See [`PgArguments::add_text`](crate::postgres::PgArguments::add_text).
+    pub fn bind_text<T>(mut self, value: T) -> Self
+    where
+        T: Type<crate::postgres::Postgres>,
+        T: Encode<crate::postgres::Postgres>,
+    {
+        self.arguments.add_text(value);
+        self
+    }
+}
+
 impl<'q, DB> Query<'q, DB>
 where
     DB: Database,
@@ -118,7 +143,7 @@ where
     DB: Database,
     Self: Execute<'q, DB>,
 {
-    pub async fn execute<E>(self, mut executor: E) -> crate::Result<u64>
+    pub async fn execute<E>(self, mut executor: E) -> crate::Result<QueryResult>
     where
         E: Executor<Database = DB>,
     {
@@ -0,0 +1,159 @@
+//! Retrying transactions that fail under contention.
+
+use std::borrow::Cow;
+use std::future::Future;
+use std::time::Duration;
+
+use crate::runtime::sleep;
+use crate::Error;
+
+/// The SQLSTATE codes retried by default: `40001` (`serialization_failure`), which MySQL also
+/// reports for a deadlock, and `40P01` (`deadlock_detected`) from Postgres.
+const DEFAULT_CODES: &[&str] = &["40001", "40P01"];
+
+/// Runs `transaction` until it succeeds, retrying it on a serialization failure or a deadlock
+/// with the default [`Retry`] options.
+///
+/// See [`Retry::run`].
+pub async fn retry<F, Fut, T>(transaction: F) -> crate::Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = crate::Result<T>>,
+{
+    Retry::new().run(transaction).await
+}
+
+/// Options for retrying a transaction that failed with a transient database error.
+///
+/// Deadlocks and serialization failures are expected when concurrent transactions contend
+/// for the same rows, and the only way to recover is to run the transaction again from the
+/// start. A transaction is retried when it fails with a [database error](Error::Database) whose
+/// [SQLSTATE code](crate::error::DatabaseError::code) is one of the codes to retry on (see
+/// [`code`](#method.code)), after waiting for a backoff that doubles with each attempt.
+///
+/// ```rust,ignore
+/// let total = Retry::new()
+///     .max_attempts(10)
+///     .run(|| async {
+///         let mut tx = pool.begin().await?;
+///
+///         let (total,): (i64,) = sqlx::query_as("SELECT SUM(balance) FROM accounts")
+///             .fetch_one(&mut tx)
+///             .await?;
+///
+///         tx.commit().await?;
+///
+///         Ok(total)
+///     })
+///     .await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct Retry {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    codes: Vec<Cow<'static, str>>,
+}
+
+impl Retry {
+    /// Returns the default options.
+    ///
+    /// See the source of this method for current defaults.
+    pub fn new() -> Self {
+        Self {
+            // give up after the fifth failure
+            max_attempts: 5,
+            // wait 10ms, 20ms, 40ms and 80ms between attempts
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_secs(1),
+            // serialization failures and deadlocks
+            codes: DEFAULT_CODES.iter().copied().map(Cow::Borrowed).collect(),
+        }
+    }
+
+    /// Sets the number of times the transaction is run before its error is returned.
+    ///
+    /// A value of `0` is treated as `1`. Defaults to `5`.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Sets how long to wait before the first retry; each following retry waits twice as
+    /// long as the one before, up to [`max_backoff`](#method.max_backoff).
+    ///
+    /// Defaults to 10 milliseconds.
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Sets the longest time to wait between two attempts.
+    ///
+    /// Defaults to 1 second.
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Adds a SQLSTATE code to retry on, in addition to `40001` and `40P01`.
+    pub fn code(mut self, code: impl Into<Cow<'static, str>>) -> Self {
+        self.codes.push(code.into());
+        self
+    }
+
+    /// Returns `true` if `error` is a database error with one of the codes to retry on.
+    pub fn is_retryable(&self, error: &Error) -> bool {
+        match error {
+            Error::Database(error) | Error::LockTimeout(error) => match error.code() {
+                Some(code) => self.codes.iter().any(|retry| retry == code),
+                None => false,
+            },
+
+            _ => false,
+        }
+    }
+
+    /// Runs `transaction` until it succeeds, fails with an error that is not
+    /// [retryable](#method.is_retryable), or has been run
+    /// [`max_attempts`](#method.max_attempts) times.
+    ///
+    /// The closure is called again for each attempt and must run the whole transaction,
+    /// from `BEGIN` to `COMMIT`, as a failed transaction cannot be resumed. The error of the
+    /// last attempt is returned if every attempt fails.
+    pub async fn run<F, Fut, T>(&self, mut transaction: F) -> crate::Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = crate::Result<T>>,
+    {
+        let mut attempt = 1;
+        let mut backoff = self.initial_backoff.min(self.max_backoff);
+
+        loop {
+            match transaction().await {
+                Err(error) if attempt < self.max_attempts && self.is_retryable(&error) => {
+                    log::debug!(
+                        "retrying transaction after attempt {} failed: {}",
+                        attempt,
+                        error
+                    );
+
+                    sleep(backoff).await;
+
+                    attempt += 1;
+                    backoff = backoff
+                        .checked_mul(2)
+                        .map_or(self.max_backoff, |next| next.min(self.max_backoff));
+                }
+
+                result => return result,
+            }
+        }
+    }
+}
+
+impl Default for Retry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
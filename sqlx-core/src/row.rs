@@ -1,5 +1,8 @@
 //! Contains the Row and FromRow traits.
 
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
+
 use crate::database::{Database, HasRawValue, HasRow};
 use crate::decode::Decode;
 use crate::types::Type;
@@ -12,6 +15,56 @@ where
     fn resolve<'c>(self, row: &<DB as HasRow<'c>>::Row) -> crate::Result<usize>;
 }
 
+/// A column of a [`Row`].
+///
+/// Returned by [`Row::column`].
+pub struct Column<'r, DB>
+where
+    DB: Database,
+{
+    pub(crate) ordinal: usize,
+    pub(crate) name: Option<&'r str>,
+    pub(crate) type_info: Option<DB::TypeInfo>,
+}
+
+impl<'r, DB> Column<'r, DB>
+where
+    DB: Database,
+{
+    /// Returns the 0-based position of the column in the row.
+    pub fn ordinal(&self) -> usize {
+        self.ordinal
+    }
+
+    /// Returns the name by which the column can be retrieved.
+    ///
+    /// This is `None` in the same cases as [`Row::column_name`].
+    pub fn name(&self) -> Option<&'r str> {
+        self.name
+    }
+
+    /// Returns the type of the column.
+    ///
+    /// SQLite has no column types, so this is the type of the value in the row, which is
+    /// `None` if the value is `NULL`.
+    pub fn type_info(&self) -> Option<&DB::TypeInfo> {
+        self.type_info.as_ref()
+    }
+}
+
+impl<DB> Debug for Column<'_, DB>
+where
+    DB: Database,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Column")
+            .field("ordinal", &self.ordinal)
+            .field("name", &self.name)
+            .field("type_info", &self.type_info)
+            .finish()
+    }
+}
+
 /// Represents a single row of the result set.
 pub trait Row<'c>: Unpin + Send {
     type Database: Database + ?Sized;
@@ -24,6 +77,17 @@ pub trait Row<'c>: Unpin + Send {
     /// Returns the number of values in the row.
     fn len(&self) -> usize;
 
+    /// Returns the name by which the column at `index` can be retrieved.
+    ///
+    /// Returns `None` if the column has no name, if `index` is out of bounds, or if a later
+    /// column in the row has the same name; retrieving a value by name returns the last
+    /// column with that name.
+    fn column_name(&self, index: usize) -> Option<&str>;
+
+    /// Returns the position, name and type of the column at `index`, or `None` if `index`
+    /// is out of bounds.
+    fn column(&self, index: usize) -> Option<Column<'_, Self::Database>>;
+
     fn get<'r, T, I>(&'r self, index: I) -> T
     where
         'c: 'r,
@@ -44,6 +108,38 @@ pub trait Row<'c>: Unpin + Send {
         Ok(Decode::decode(self.try_get_raw(index)?)?)
     }
 
+    /// Decodes the JSON value at `index` into any type that implements
+    /// [`Deserialize`](serde::Deserialize).
+    ///
+    /// Panics if the column does not exist or the value cannot be decoded; see
+    /// [`try_get_json`](Row::try_get_json) for a non-panicking version.
+    #[cfg(feature = "json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+    fn get_json<'r, T, I>(&'r self, index: I) -> T
+    where
+        'c: 'r,
+        I: ColumnIndex<Self::Database>,
+        crate::types::Json<T>: Type<Self::Database>,
+        crate::types::Json<T>: Decode<'r, Self::Database>,
+    {
+        self.try_get_json::<T, I>(index).unwrap()
+    }
+
+    /// Decodes the JSON value at `index` into any type that implements
+    /// [`Deserialize`](serde::Deserialize).
+    #[cfg(feature = "json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+    fn try_get_json<'r, T, I>(&'r self, index: I) -> crate::Result<T>
+    where
+        'c: 'r,
+        I: ColumnIndex<Self::Database>,
+        crate::types::Json<T>: Type<Self::Database>,
+        crate::types::Json<T>: Decode<'r, Self::Database>,
+    {
+        self.try_get::<crate::types::Json<T>, I>(index)
+            .map(crate::types::Json::into_inner)
+    }
+
     fn try_get_raw<'r, I>(
         &'r self,
         index: I,
@@ -51,6 +147,52 @@ pub trait Row<'c>: Unpin + Send {
     where
         'c: 'r,
         I: ColumnIndex<Self::Database>;
+
+    /// Decodes every value in the row into a map keyed by column name.
+    ///
+    /// `duplicates` decides what happens if more than one column has the same name; a column
+    /// without a name is treated as a duplicate. A `HashMap<String, T>` decoded with
+    /// [`FromRow`] keeps the last column with each name.
+    fn try_get_map<'r, T>(
+        &'r self,
+        duplicates: DuplicateColumns,
+    ) -> crate::Result<HashMap<String, T>>
+    where
+        'c: 'r,
+        T: Type<Self::Database>,
+        T: Decode<'r, Self::Database>,
+        usize: ColumnIndex<Self::Database>,
+    {
+        let mut map = HashMap::with_capacity(self.len());
+
+        for index in 0..self.len() {
+            let name = match (self.column_name(index), duplicates) {
+                (Some(name), _) => name,
+                (None, DuplicateColumns::LastWins) => continue,
+
+                (None, DuplicateColumns::Error) => {
+                    return Err(crate::Error::Decode(
+                        format!("column {} does not have a unique name", index).into(),
+                    ));
+                }
+            };
+
+            map.insert(name.to_owned(), self.try_get(index)?);
+        }
+
+        Ok(map)
+    }
+}
+
+/// How [`Row::try_get_map`] handles columns that share a name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicateColumns {
+    /// Keep the value of the last column with each name, which is the column that would be
+    /// returned when retrieving the value by name.
+    LastWins,
+
+    /// Return an error.
+    Error,
 }
 
 /// A **record** that can be built from a row returned from by the database.
@@ -62,6 +204,90 @@ where
     fn from_row(row: R) -> crate::Result<Self>;
 }
 
+/// The names of the columns of a result, looked up by name when retrieving a value and by
+/// index for [`Row::column_name`].
+///
+/// Only the last of several columns with the same name can be retrieved by name, so the others
+/// have no name here.
+#[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+#[derive(Debug, Default)]
+pub(crate) struct ColumnNames {
+    indexes: HashMap<Box<str>, usize>,
+    names: Vec<Option<Box<str>>>,
+}
+
+#[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+impl ColumnNames {
+    /// Takes the name of each column in order, `None` for a column without a name.
+    pub(crate) fn new<'a, I>(names: I) -> Self
+    where
+        I: IntoIterator<Item = Option<&'a str>>,
+    {
+        let mut indexes = HashMap::new();
+        let mut by_index = Vec::new();
+
+        for (index, name) in names.into_iter().enumerate() {
+            let name = name.map(Box::<str>::from);
+
+            if let Some(name) = &name {
+                if let Some(shadowed) = indexes.insert(name.clone(), index) {
+                    by_index[shadowed] = None;
+                }
+            }
+
+            by_index.push(name);
+        }
+
+        Self {
+            indexes,
+            names: by_index,
+        }
+    }
+
+    pub(crate) fn index(&self, name: &str) -> Option<usize> {
+        self.indexes.get(name).copied()
+    }
+
+    pub(crate) fn name(&self, index: usize) -> Option<&str> {
+        self.names.get(index)?.as_deref()
+    }
+}
+
+/// Decodes a value read from a column of type `found`, naming both types in the error
+/// if decoding fails because `T` is not compatible with the column.
+///
+/// SQLite values name their stored type in their own decode errors, so this is not used there.
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+pub(crate) fn decode_column<'r, DB, T>(
+    value: <DB as HasRawValue<'r>>::RawValue,
+    found: &DB::TypeInfo,
+) -> crate::Result<T>
+where
+    DB: Database,
+    T: Type<DB>,
+    T: Decode<'r, DB>,
+{
+    use crate::error::MismatchedTypeError;
+    use crate::types::TypeInfo;
+
+    T::decode(value).map_err(|err| {
+        let expected = T::type_info();
+
+        match err {
+            crate::Error::Decode(source) if !expected.compatible(found) => {
+                crate::Error::decode(MismatchedTypeError {
+                    rust_type: std::any::type_name::<T>(),
+                    expected: expected.name().into(),
+                    found: found.name().into(),
+                    source,
+                })
+            }
+
+            err => err,
+        }
+    })
+}
+
 // Macros to help unify the internal implementations as a good chunk
 // is very similar
 
@@ -77,6 +303,13 @@ macro_rules! impl_from_row_for_tuple {
             fn from_row(row: $r<'c>) -> crate::Result<Self> {
                 use crate::row::Row;
 
+                let expected = [$(stringify!($T)),+].len();
+                let found = row.len();
+
+                if found != expected {
+                    return Err(crate::Error::ColumnCountMismatch { expected, found });
+                }
+
                 Ok(($(row.try_get($idx as usize)?,)+))
             }
         }
@@ -86,6 +319,14 @@ macro_rules! impl_from_row_for_tuple {
 #[allow(unused_macros)]
 macro_rules! impl_from_row_for_tuples {
     ($db:ident, $r:ident) => {
+        // the unit type ignores the columns; for checking whether a row exists
+        impl<'c> crate::row::FromRow<'c, $r<'c>> for () {
+            #[inline]
+            fn from_row(_: $r<'c>) -> crate::Result<Self> {
+                Ok(())
+            }
+        }
+
         impl_from_row_for_tuple!($db, $r;
             (0) -> T1;
         );
@@ -200,9 +441,51 @@ macro_rules! impl_column_index_for_row {
                 row: &<$DB as crate::database::HasRow<'c>>::Row,
             ) -> crate::Result<usize> {
                 row.columns
-                    .get(self)
+                    .index(self)
                     .ok_or_else(|| crate::Error::ColumnNotFound((*self).into()))
-                    .map(|&index| index as usize)
+            }
+        }
+
+        impl_column_index_for_string!($DB);
+    };
+}
+
+// column names held in a `String` resolve the same as the `&str` they deref to
+#[allow(unused_macros)]
+macro_rules! impl_column_index_for_string {
+    ($DB:ident) => {
+        impl crate::row::ColumnIndex<$DB> for &'_ String {
+            fn resolve<'c>(
+                self,
+                row: &<$DB as crate::database::HasRow<'c>>::Row,
+            ) -> crate::Result<usize> {
+                crate::row::ColumnIndex::<$DB>::resolve(&**self, row)
+            }
+        }
+
+        impl crate::row::ColumnIndex<$DB> for String {
+            fn resolve<'c>(
+                self,
+                row: &<$DB as crate::database::HasRow<'c>>::Row,
+            ) -> crate::Result<usize> {
+                crate::row::ColumnIndex::<$DB>::resolve(&*self, row)
+            }
+        }
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_from_row_for_map {
+    ($db:ident, $r:ident) => {
+        impl<'c, T> crate::row::FromRow<'c, $r<'c>> for std::collections::HashMap<String, T>
+        where
+            T: crate::types::Type<$db>,
+            T: for<'r> crate::decode::Decode<'r, $db>,
+        {
+            fn from_row(row: $r<'c>) -> crate::Result<Self> {
+                use crate::row::Row;
+
+                row.try_get_map(crate::row::DuplicateColumns::LastWins)
             }
         }
     };
@@ -219,3 +502,20 @@ macro_rules! impl_from_row_for_row {
         }
     };
 }
+
+#[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+#[test]
+fn test_column_names() {
+    let columns = ColumnNames::new(vec![Some("id"), None, Some("name"), Some("id")]);
+
+    assert_eq!(columns.index("id"), Some(3));
+    assert_eq!(columns.index("name"), Some(2));
+    assert_eq!(columns.index("email"), None);
+
+    // the first `id` is shadowed by the last
+    assert_eq!(columns.name(0), None);
+    assert_eq!(columns.name(1), None);
+    assert_eq!(columns.name(2), Some("name"));
+    assert_eq!(columns.name(3), Some("id"));
+    assert_eq!(columns.name(4), None);
+}
@@ -5,11 +5,13 @@ use std::os::raw::c_int;
 
 use libsqlite3_sys::{
     sqlite3_bind_blob, sqlite3_bind_double, sqlite3_bind_int, sqlite3_bind_int64,
-    sqlite3_bind_null, sqlite3_bind_text, SQLITE_OK, SQLITE_TRANSIENT,
+    sqlite3_bind_null, sqlite3_bind_text, sqlite3_context, sqlite3_result_blob,
+    sqlite3_result_double, sqlite3_result_int, sqlite3_result_int64, sqlite3_result_null,
+    sqlite3_result_text, SQLITE_OK, SQLITE_TRANSIENT,
 };
 
 use crate::arguments::Arguments;
-use crate::encode::{Encode, IsNull};
+use crate::encode::{DynEncode, Encode, IsNull};
 use crate::sqlite::statement::Statement;
 use crate::sqlite::Sqlite;
 use crate::sqlite::SqliteError;
@@ -39,6 +41,16 @@ pub struct SqliteArguments {
 }
 
 impl SqliteArguments {
+    /// Returns the number of bound arguments.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if no arguments have been bound.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
     pub(crate) fn next(&mut self) -> Option<SqliteArgumentValue> {
         if self.index >= self.values.len() {
             return None;
@@ -61,12 +73,19 @@ impl Arguments for SqliteArguments {
 
     fn add<T>(&mut self, value: T)
     where
-        T: Encode<Self::Database> + Type<Self::Database>,
+        T: Type<Self::Database>,
+        T: Encode<Self::Database>,
     {
         if let IsNull::Yes = value.encode_nullable(&mut self.values) {
             self.values.push(SqliteArgumentValue::Null);
         }
     }
+
+    fn add_dyn(&mut self, value: &dyn DynEncode<Sqlite>) {
+        if let IsNull::Yes = value.encode_dyn(&mut self.values) {
+            self.values.push(SqliteArgumentValue::Null);
+        }
+    }
 }
 
 impl SqliteArgumentValue {
@@ -132,4 +151,33 @@ impl SqliteArgumentValue {
 
         Ok(())
     }
+
+    // Sets the value as the result of a user-defined function.
+    pub(super) fn result(&self, context: *mut sqlite3_context) {
+        // https://www.sqlite.org/c3ref/result_blob.html
+        #[allow(unsafe_code)]
+        match self {
+            SqliteArgumentValue::Blob(value) => {
+                let bytes_ptr = value.as_ptr() as *const c_void;
+                let bytes_len = value.len() as i32;
+
+                unsafe { sqlite3_result_blob(context, bytes_ptr, bytes_len, SQLITE_TRANSIENT()) }
+            }
+
+            SqliteArgumentValue::Text(value) => {
+                let bytes_ptr = value.as_ptr() as *const i8;
+                let bytes_len = value.len() as i32;
+
+                unsafe { sqlite3_result_text(context, bytes_ptr, bytes_len, SQLITE_TRANSIENT()) }
+            }
+
+            SqliteArgumentValue::Double(value) => unsafe { sqlite3_result_double(context, *value) },
+
+            SqliteArgumentValue::Int(value) => unsafe { sqlite3_result_int(context, *value) },
+
+            SqliteArgumentValue::Int64(value) => unsafe { sqlite3_result_int64(context, *value) },
+
+            SqliteArgumentValue::Null => unsafe { sqlite3_result_null(context) },
+        }
+    }
 }
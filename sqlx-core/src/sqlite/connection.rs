@@ -1,21 +1,27 @@
 use core::ptr::{null, null_mut, NonNull};
 
-use std::collections::HashMap;
 use std::convert::TryInto;
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_int;
 
 use futures_core::future::BoxFuture;
 use futures_util::future;
 use libsqlite3_sys::{
-    sqlite3, sqlite3_close, sqlite3_extended_result_codes, sqlite3_open_v2, SQLITE_OK,
-    SQLITE_OPEN_CREATE, SQLITE_OPEN_NOMUTEX, SQLITE_OPEN_READWRITE, SQLITE_OPEN_SHAREDCACHE,
+    sqlite3, sqlite3_close, sqlite3_extended_result_codes, sqlite3_finalize,
+    sqlite3_get_autocommit, sqlite3_open_v2, sqlite3_prepare_v2, sqlite3_step, SQLITE_DONE,
+    SQLITE_OK, SQLITE_OPEN_CREATE, SQLITE_OPEN_NOMUTEX, SQLITE_OPEN_READWRITE,
+    SQLITE_OPEN_SHAREDCACHE, SQLITE_ROW,
 };
 
 use crate::connection::{Connect, Connection};
+use crate::cursor::Cursor;
 use crate::executor::Executor;
+use crate::row::Row;
+use crate::sqlite::hook::UpdateHook;
 use crate::sqlite::statement::Statement;
+use crate::sqlite::statement_cache::{SqliteStatementCacheStats, StatementCache};
 use crate::sqlite::worker::Worker;
-use crate::sqlite::SqliteError;
+use crate::sqlite::{Sqlite, SqliteError};
 use crate::url::Url;
 
 /// Thin wrapper around [sqlite3] to impl `Send`.
@@ -29,8 +35,12 @@ pub struct SqliteConnection {
     // Storage of the most recently prepared, non-persistent statement
     pub(super) statement: Option<Statement>,
     // Storage of persistent statements
-    pub(super) statements: Vec<Statement>,
-    pub(super) statement_by_query: HashMap<String, usize>,
+    pub(super) statements: StatementCache,
+    pub(super) strict_types: bool,
+    // Schema names of the databases attached with [SqliteConnection::attach]
+    pub(super) attached: Vec<String>,
+    // The hook set with [SqliteConnection::set_update_hook]
+    pub(super) update_hook: Option<Box<UpdateHook>>,
 }
 
 // A SQLite3 handle is safe to send between threads, provided not more than
@@ -100,13 +110,22 @@ async fn establish(url: crate::Result<Url>) -> crate::Result<SqliteConnection> {
         })
         .await?;
 
-    Ok(SqliteConnection {
+    #[cfg_attr(not(feature = "regexp"), allow(unused_mut))]
+    let mut conn = SqliteConnection {
         worker,
         handle,
         statement: None,
-        statements: Vec::with_capacity(10),
-        statement_by_query: HashMap::with_capacity(10),
-    })
+        // cache up to 100 statements; each holds onto its compiled program
+        statements: StatementCache::new(100),
+        strict_types: false,
+        attached: Vec::new(),
+        update_hook: None,
+    };
+
+    #[cfg(feature = "regexp")]
+    super::regexp::register(&mut conn)?;
+
+    Ok(conn)
 }
 
 impl SqliteConnection {
@@ -114,6 +133,229 @@ impl SqliteConnection {
     pub(super) fn handle(&mut self) -> *mut sqlite3 {
         self.handle.0.as_ptr()
     }
+
+    /// Sets whether values must be stored with the SQL type of the Rust type they are
+    /// decoded as.
+    ///
+    /// SQLite stores each value with its own type, regardless of the declared type of its
+    /// column. By default, values are converted where that is lossless in meaning, e.g. a
+    /// numeric TEXT value decodes as an `i64` and an INTEGER value decodes as a `String`;
+    /// values that cannot be converted are an error. In strict mode, decoding a value of
+    /// another type (or a NULL value, unless decoding an `Option`) is always an error, except
+    /// that INTEGER values decode as floats.
+    ///
+    /// Defaults to `false`.
+    pub fn strict_types(&mut self, strict: bool) {
+        self.strict_types = strict;
+    }
+
+    /// Returns whether `table` is a [`STRICT`](https://www.sqlite.org/stricttables.html) table,
+    /// whose columns only store values of their declared type.
+    ///
+    /// Values read from a `STRICT` table always have the type of their column, so
+    /// [`strict_types`](#method.strict_types) can be enabled to turn any mismatch between
+    /// the column and the Rust type into an error. This only reports on the table; strict
+    /// types are opt-in and have to be enabled on the connection either way.
+    ///
+    /// The table is looked up in the main and temporary databases; `false` is returned if
+    /// there is no such table.
+    pub async fn is_strict_table(&mut self, table: &str) -> crate::Result<bool> {
+        let query = crate::query::query(
+            "SELECT sql FROM sqlite_master WHERE type = 'table' AND name = ?1 \
+             UNION ALL \
+             SELECT sql FROM sqlite_temp_master WHERE type = 'table' AND name = ?1",
+        )
+        .bind(table);
+
+        let mut cursor = self.fetch(query);
+
+        match cursor.next().await? {
+            Some(row) => Ok(has_strict_option(&row.try_get::<String, _>(0)?)),
+            None => Ok(false),
+        }
+    }
+
+    /// Sets the maximum number of prepared statements this connection keeps for reuse.
+    ///
+    /// Executing a query with arguments compiles it into a statement that is kept so the
+    /// query can be executed again without compiling it. Once this many statements are
+    /// kept, the least recently used one is discarded. A capacity of `0` disables the cache.
+    ///
+    /// Defaults to `100`.
+    pub fn statement_cache_capacity(&mut self, capacity: usize) {
+        self.statements.set_capacity(capacity);
+    }
+
+    /// Returns counters for the prepared statements cached by this connection.
+    pub fn statement_cache_stats(&self) -> SqliteStatementCacheStats {
+        SqliteStatementCacheStats {
+            size: self.statements.len(),
+            ..self.statements.stats
+        }
+    }
+
+    /// Attaches the database file at `path` to this connection as the schema `alias`.
+    ///
+    /// Tables of the attached database are referred to as `alias.table` and can be
+    /// queried (and joined) along with the tables of the main database. A `path` of
+    /// `:memory:` attaches a new in-memory database.
+    pub async fn attach(&mut self, path: &str, alias: &str) -> crate::Result<()> {
+        let query = format!("ATTACH DATABASE ? AS {}", quote_alias(alias)?);
+
+        self.execute(crate::query::query(&query).bind(path)).await?;
+        self.attached.push(alias.to_owned());
+
+        Ok(())
+    }
+
+    /// Detaches a database previously attached with [`attach`](#method.attach).
+    pub async fn detach(&mut self, alias: &str) -> crate::Result<()> {
+        let query = format!("DETACH DATABASE {}", quote_alias(alias)?);
+
+        self.execute(&*query).await?;
+
+        if let Some(pos) = self.attached.iter().position(|s| s == alias) {
+            self.attached.remove(pos);
+        }
+
+        Ok(())
+    }
+
+    /// Executes a script of SQL statements, such as a schema with triggers and views.
+    ///
+    /// SQLite splits the script into statements itself, so semicolons within a statement (e.g.
+    /// in the body of a trigger) and comments are handled exactly as SQLite handles them. No
+    /// values can be bound and any rows returned are discarded. If a statement fails, its error
+    /// is returned and the statements before it remain executed; the error names the 1-based
+    /// index and the text of the statement, and its [`position`] is where the statement starts
+    /// in `sql`.
+    ///
+    /// [`position`]: ../error/trait.DatabaseError.html#method.position
+    pub async fn execute_batch(&mut self, sql: &str) -> crate::Result<()> {
+        let sql = CString::new(sql)
+            .map_err(|_| crate::Error::Protocol("SQL must not contain a NUL character".into()))?;
+
+        let handle = self.handle;
+
+        self.worker.run(move || execute_batch(handle, &sql)).await
+    }
+
+    /// Returns the schema names of the databases currently attached to this connection.
+    pub fn attached(&self) -> &[String] {
+        &self.attached
+    }
+}
+
+// Prepares and steps each statement of `sql` in turn, as `sqlite3_exec` would, keeping track of
+// which statement is being executed
+fn execute_batch(handle: SqliteConnectionHandle, sql: &CStr) -> crate::Result<()> {
+    let conn = handle.0.as_ptr();
+    let bytes = sql.to_bytes();
+
+    let mut offset = 0;
+    let mut index = 0;
+
+    while offset < bytes.len() {
+        let mut statement = null_mut();
+        let mut tail = null();
+
+        // <https://www.sqlite.org/c3ref/prepare.html>
+        #[allow(unsafe_code)]
+        let mut status = unsafe {
+            sqlite3_prepare_v2(
+                conn,
+                sql.as_ptr().add(offset),
+                (bytes.len() - offset) as c_int,
+                &mut statement,
+                &mut tail,
+            )
+        };
+
+        // the tail points past the end of the statement that was (or failed to be) prepared
+        let start = offset;
+        let end = if tail.is_null() {
+            bytes.len()
+        } else {
+            tail as usize - sql.as_ptr() as usize
+        };
+
+        if status == SQLITE_OK && statement.is_null() {
+            // only whitespace or comments were left
+            if end <= start {
+                break;
+            }
+
+            offset = end;
+            continue;
+        }
+
+        index += 1;
+
+        if status == SQLITE_OK {
+            // <https://www.sqlite.org/c3ref/step.html>
+            #[allow(unsafe_code)]
+            loop {
+                status = unsafe { sqlite3_step(statement) };
+
+                if status != SQLITE_ROW {
+                    break;
+                }
+            }
+
+            if status == SQLITE_DONE {
+                status = SQLITE_OK;
+            }
+        }
+
+        let error = if status != SQLITE_OK {
+            Some(SqliteError::from_connection(conn))
+        } else {
+            None
+        };
+
+        // <https://www.sqlite.org/c3ref/finalize.html>
+        #[allow(unsafe_code)]
+        unsafe {
+            sqlite3_finalize(statement);
+        }
+
+        if let Some(error) = error {
+            // `sql` came from a `&str` and statements end on a character boundary
+            let text = std::str::from_utf8(&bytes[start..end]).unwrap_or_default();
+            let trimmed = text.trim_start();
+            let skipped = text.len() - trimmed.len();
+
+            let position = std::str::from_utf8(&bytes[..start + skipped])
+                .map_or(0, |before| before.chars().count())
+                + 1;
+
+            let statement = trimmed.trim_end().trim_end_matches(';');
+
+            return Err(error.in_statement(index, statement, position).into());
+        }
+
+        offset = end;
+    }
+
+    Ok(())
+}
+
+// Checks the table options that follow the column definitions in a `CREATE TABLE`
+// statement, e.g. `) WITHOUT ROWID, STRICT`
+fn has_strict_option(create_table: &str) -> bool {
+    match create_table.rfind(')') {
+        Some(end) => create_table[end + 1..]
+            .split(',')
+            .any(|option| option.trim().eq_ignore_ascii_case("STRICT")),
+
+        None => false,
+    }
+}
+
+fn quote_alias(alias: &str) -> crate::Result<String> {
+    Sqlite::quote_identifier(alias).ok_or_else(|| {
+        crate::Error::Protocol("database alias must not contain a NUL character".into())
+    })
 }
 
 impl Connect for SqliteConnection {
@@ -153,6 +395,26 @@ impl Connection for SqliteConnection {
         // For SQLite connections, PING does effectively nothing
         Box::pin(future::ok(()))
     }
+
+    fn reset_with<'c>(&'c mut self, query: &'c str) -> BoxFuture<'c, crate::Result<()>> {
+        Box::pin(reset_with(self, query))
+    }
+}
+
+async fn reset_with(conn: &mut SqliteConnection, query: &str) -> crate::Result<()> {
+    // `ROLLBACK` fails unless a transaction is open
+    // https://www.sqlite.org/c3ref/get_autocommit.html
+    #[allow(unsafe_code)]
+    let in_transaction = unsafe { sqlite3_get_autocommit(conn.handle()) } == 0;
+
+    if in_transaction {
+        conn.execute("ROLLBACK").await?;
+    }
+
+    conn.execute(query).await?;
+
+    // compiled statements are prepared again by SQLite itself if the schema changes
+    Ok(())
 }
 
 impl Drop for SqliteConnection {
@@ -160,6 +422,11 @@ impl Drop for SqliteConnection {
         // Drop all statements first
         self.statements.clear();
 
+        // Unregister the update hook before it is dropped along with the connection
+        if self.update_hook.is_some() {
+            self.clear_update_hook();
+        }
+
         // Next close the statement
         // https://sqlite.org/c3ref/close.html
         #[allow(unsafe_code)]
@@ -168,3 +435,18 @@ impl Drop for SqliteConnection {
         }
     }
 }
+
+#[test]
+fn test_has_strict_option() {
+    assert!(has_strict_option("CREATE TABLE t (id INTEGER) STRICT"));
+    assert!(has_strict_option("CREATE TABLE t (id INTEGER)\nstrict"));
+    assert!(has_strict_option(
+        "CREATE TABLE t (id INTEGER PRIMARY KEY, n INT) WITHOUT ROWID, STRICT"
+    ));
+
+    assert!(!has_strict_option("CREATE TABLE t (id INTEGER)"));
+    assert!(!has_strict_option(
+        "CREATE TABLE t (id INTEGER) WITHOUT ROWID"
+    ));
+    assert!(!has_strict_option("CREATE TABLE strict (id INTEGER)"));
+}
@@ -3,6 +3,8 @@ use futures_core::future::BoxFuture;
 use crate::connection::ConnectionSource;
 use crate::cursor::Cursor;
 use crate::executor::Execute;
+#[cfg(feature = "log-queries")]
+use crate::logger::QueryLogger;
 use crate::pool::Pool;
 use crate::sqlite::statement::Step;
 use crate::sqlite::{Sqlite, SqliteArguments, SqliteConnection, SqliteRow};
@@ -12,6 +14,8 @@ pub struct SqliteCursor<'c, 'q> {
     query: &'q str,
     arguments: Option<SqliteArguments>,
     pub(super) statement: Option<Option<usize>>,
+    #[cfg(feature = "log-queries")]
+    logger: Option<QueryLogger<'q>>,
 }
 
 impl<'c, 'q> Cursor<'c, 'q> for SqliteCursor<'c, 'q> {
@@ -30,6 +34,8 @@ impl<'c, 'q> Cursor<'c, 'q> for SqliteCursor<'c, 'q> {
             statement: None,
             query,
             arguments,
+            #[cfg(feature = "log-queries")]
+            logger: None,
         }
     }
 
@@ -46,6 +52,8 @@ impl<'c, 'q> Cursor<'c, 'q> for SqliteCursor<'c, 'q> {
             statement: None,
             query,
             arguments,
+            #[cfg(feature = "log-queries")]
+            logger: None,
         }
     }
 
@@ -57,34 +65,84 @@ impl<'c, 'q> Cursor<'c, 'q> for SqliteCursor<'c, 'q> {
 async fn next<'a, 'c: 'a, 'q: 'a>(
     cursor: &'a mut SqliteCursor<'c, 'q>,
 ) -> crate::Result<Option<SqliteRow<'a>>> {
+    let key = match advance(cursor).await {
+        Ok(Some(key)) => key,
+        Ok(None) => return Ok(None),
+
+        Err(error) => {
+            #[cfg(feature = "log-queries")]
+            if let Some(logger) = cursor.logger.take() {
+                logger.fail(&error);
+            }
+
+            return Err(error);
+        }
+    };
+
+    let conn = cursor.source.resolve().await?;
+
+    Ok(Some(SqliteRow {
+        values: conn.statement_mut(key).data_count(),
+        statement: key,
+        connection: conn,
+    }))
+}
+
+// Steps to the next row, returning the key of the statement that holds it or `None` at the
+// end of the query
+async fn advance(cursor: &mut SqliteCursor<'_, '_>) -> crate::Result<Option<Option<usize>>> {
     let conn = cursor.source.resolve().await?;
 
     loop {
         if cursor.statement.is_none() {
+            #[cfg(feature = "log-queries")]
+            if cursor.logger.is_none() {
+                let arguments = cursor.arguments.as_ref().map_or(0, SqliteArguments::len);
+                cursor.logger = Some(QueryLogger::new(cursor.query, arguments));
+            }
+
             let key = conn.prepare(&mut cursor.query, cursor.arguments.is_some())?;
 
             if let Some(arguments) = &mut cursor.arguments {
-                conn.statement_mut(key).bind(arguments)?;
+                if let Err(error) = conn.statement_mut(key).bind(arguments) {
+                    conn.discard_statement(key);
+                    return Err(error);
+                }
             }
 
             cursor.statement = Some(key);
         }
 
         let key = cursor.statement.unwrap();
-        let statement = conn.statement_mut(key);
 
-        let step = statement.step().await?;
+        let step = match conn.statement_mut(key).step().await {
+            Ok(step) => step,
+
+            Err(error) => {
+                // the cursor can not be resumed after an error
+                cursor.statement = None;
+                cursor.query = "";
+                conn.discard_statement(key);
+
+                return Err(error);
+            }
+        };
 
         match step {
             Step::Row => {
-                return Ok(Some(SqliteRow {
-                    values: statement.data_count(),
-                    statement: key,
-                    connection: conn,
-                }));
+                #[cfg(feature = "log-queries")]
+                if let Some(logger) = &mut cursor.logger {
+                    logger.increment_rows();
+                }
+
+                return Ok(Some(key));
             }
 
             Step::Done if cursor.query.is_empty() => {
+                // the query is complete; log it
+                #[cfg(feature = "log-queries")]
+                drop(cursor.logger.take());
+
                 return Ok(None);
             }
 
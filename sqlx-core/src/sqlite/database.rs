@@ -15,6 +15,33 @@ impl Database for Sqlite {
     type RawBuffer = Vec<super::SqliteArgumentValue>;
 }
 
+impl Sqlite {
+    /// Quotes `ident` so it can be interpolated into a query as a table, column, schema or
+    /// other identifier.
+    ///
+    /// The identifier is wrapped in double quotes with any embedded double quotes doubled.
+    /// Returns `None` if `ident` contains a NUL character, which would end the SQL text
+    /// SQLite sees.
+    ///
+    /// ```rust
+    /// # use sqlx_core::sqlite::Sqlite;
+    /// assert_eq!(Sqlite::quote_identifier("my \"table\"").unwrap(), r#""my ""table""""#);
+    /// ```
+    pub fn quote_identifier(ident: &str) -> Option<String> {
+        if ident.contains('\0') {
+            return None;
+        }
+
+        let mut quoted = String::with_capacity(ident.len() + 2);
+
+        quoted.push('"');
+        quoted.push_str(&ident.replace('"', "\"\""));
+        quoted.push('"');
+
+        Some(quoted)
+    }
+}
+
 impl<'c> HasRow<'c> for Sqlite {
     type Database = Sqlite;
 
@@ -30,3 +57,14 @@ impl<'c, 'q> HasCursor<'c, 'q> for Sqlite {
 impl<'c> HasRawValue<'c> for Sqlite {
     type RawValue = super::SqliteValue<'c>;
 }
+
+#[test]
+fn test_quote_identifier() {
+    assert_eq!(Sqlite::quote_identifier("users").unwrap(), "\"users\"");
+    assert_eq!(
+        Sqlite::quote_identifier("a\"; DROP TABLE users; --").unwrap(),
+        "\"a\"\"; DROP TABLE users; --\""
+    );
+    assert_eq!(Sqlite::quote_identifier("üñí©ødé").unwrap(), "\"üñí©ødé\"");
+    assert!(Sqlite::quote_identifier("a\0b").is_none());
+}
@@ -9,6 +9,10 @@ use std::os::raw::c_int;
 pub struct SqliteError {
     code: String,
     message: String,
+    // The 1-based index and the text of the failing statement of a batch, and the
+    // position it starts at
+    statement: Option<(usize, Box<str>)>,
+    position: Option<usize>,
 }
 
 // Error Codes And Messages
@@ -30,13 +34,31 @@ impl SqliteError {
         Self {
             code: code.to_string(),
             message: message.to_owned(),
+            statement: None,
+            position: None,
         }
     }
+
+    // Records that the error was raised by the `index`th statement of a batch, which starts at
+    // the 1-based character `position`
+    pub(super) fn in_statement(mut self, index: usize, statement: &str, position: usize) -> Self {
+        self.statement = Some((index, statement.into()));
+        self.position = Some(position);
+        self
+    }
 }
 
 impl Display for SqliteError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.pad(self.message())
+        match self.statement {
+            Some((index, ref statement)) => write!(
+                f,
+                "{} (in statement {} of the batch: {})",
+                self.message, index, statement
+            ),
+
+            None => f.pad(self.message()),
+        }
     }
 }
 
@@ -48,4 +70,8 @@ impl DatabaseError for SqliteError {
     fn code(&self) -> Option<&str> {
         Some(&self.code)
     }
+
+    fn position(&self) -> Option<usize> {
+        self.position
+    }
 }
@@ -1,14 +1,16 @@
 use futures_core::future::BoxFuture;
 
-use libsqlite3_sys::sqlite3_changes;
+use libsqlite3_sys::{sqlite3_changes, sqlite3_last_insert_rowid, sqlite3_total_changes};
 
 use crate::cursor::Cursor;
 use crate::describe::{Column, Describe};
-use crate::executor::{Execute, Executor, RefExecutor};
+use crate::executor::{Execute, Executor, QueryResult, RefExecutor};
+#[cfg(feature = "log-queries")]
+use crate::logger::QueryLogger;
 use crate::sqlite::cursor::SqliteCursor;
 use crate::sqlite::statement::{Statement, Step};
-use crate::sqlite::types::SqliteType;
-use crate::sqlite::{Sqlite, SqliteConnection, SqliteTypeInfo};
+use crate::sqlite::types::{SqliteType, SqliteTypeAffinity};
+use crate::sqlite::{Sqlite, SqliteArguments, SqliteConnection, SqliteTypeInfo};
 
 impl SqliteConnection {
     pub(super) fn prepare(
@@ -16,18 +18,15 @@ impl SqliteConnection {
         query: &mut &str,
         persistent: bool,
     ) -> crate::Result<Option<usize>> {
-        // TODO: Revisit statement caching and allow cache expiration by using a
-        //       generational index
-
-        if !persistent {
+        if !persistent || self.statements.capacity() == 0 {
             // A non-persistent query will be immediately prepared and returned,
             // regardless of the current state of the cache
             self.statement = Some(Statement::new(self, query, false)?);
             return Ok(None);
         }
 
-        if let Some(key) = self.statement_by_query.get(&**query) {
-            let statement = &mut self.statements[*key];
+        if let Some(key) = self.statements.get(query) {
+            let statement = self.statements.statement_mut(key);
 
             // Adjust the passed in query string as if [string3_prepare]
             // did the tail parsing
@@ -37,7 +36,7 @@ impl SqliteConnection {
             // it to clear the bindings and its program state
             statement.reset();
 
-            return Ok(Some(*key));
+            return Ok(Some(key));
         }
 
         // Prepare a new statement object; ensuring to tell SQLite that this will be stored
@@ -46,12 +45,15 @@ impl SqliteConnection {
         let query_key = query.to_owned();
         let statement = Statement::new(self, query, true)?;
 
-        let key = self.statements.len();
-
-        self.statement_by_query.insert(query_key, key);
-        self.statements.push(statement);
+        Ok(Some(self.statements.insert(query_key, statement)))
+    }
 
-        Ok(Some(key))
+    // A statement that failed to bind or execute is finalized rather than kept for reuse
+    pub(super) fn discard_statement(&mut self, key: Option<usize>) {
+        match key {
+            Some(key) => self.statements.remove(key),
+            None => self.statement = None,
+        }
     }
 
     // This is used for [affected_rows] in the public API.
@@ -65,10 +67,48 @@ impl SqliteConnection {
         changes as u64
     }
 
+    fn last_insert_rowid(&mut self) -> i64 {
+        // Returns the rowid of the most recent successful INSERT into a rowid table.
+
+        // https://www.sqlite.org/c3ref/last_insert_rowid.html
+        #[allow(unsafe_code)]
+        unsafe {
+            sqlite3_last_insert_rowid(self.handle())
+        }
+    }
+
+    fn total_changes(&mut self) -> u64 {
+        // Returns the number of rows modified, inserted or deleted by all INSERT, UPDATE
+        // or DELETE statements completed since the connection was opened.
+
+        // https://www.sqlite.org/c3ref/total_changes.html
+        #[allow(unsafe_code)]
+        let changes = unsafe { sqlite3_total_changes(self.handle()) };
+        changes as u64
+    }
+
+    async fn run_to_completion(
+        &mut self,
+        key: Option<usize>,
+        arguments: Option<&mut SqliteArguments>,
+    ) -> crate::Result<()> {
+        let statement = self.statement_mut(key);
+
+        if let Some(arguments) = arguments {
+            statement.bind(arguments)?;
+        }
+
+        while let Step::Row = statement.step().await? {
+            // We only care about the rows modified; ignore
+        }
+
+        Ok(())
+    }
+
     #[inline]
     pub(super) fn statement(&self, key: Option<usize>) -> &Statement {
         match key {
-            Some(key) => &self.statements[key],
+            Some(key) => self.statements.statement(key),
             None => self.statement.as_ref().unwrap(),
         }
     }
@@ -76,7 +116,7 @@ impl SqliteConnection {
     #[inline]
     pub(super) fn statement_mut(&mut self, key: Option<usize>) -> &mut Statement {
         match key {
-            Some(key) => &mut self.statements[key],
+            Some(key) => self.statements.statement_mut(key),
             None => self.statement.as_mut().unwrap(),
         }
     }
@@ -88,31 +128,60 @@ impl Executor for SqliteConnection {
     fn execute<'e, 'q: 'e, 'c: 'e, E: 'e>(
         &'c mut self,
         query: E,
-    ) -> BoxFuture<'e, crate::Result<u64>>
+    ) -> BoxFuture<'e, crate::Result<QueryResult>>
     where
         E: Execute<'q, Self::Database>,
     {
         let (mut query, mut arguments) = query.into_parts();
 
         Box::pin(async move {
-            loop {
-                let key = self.prepare(&mut query, arguments.is_some())?;
-                let statement = self.statement_mut(key);
-
-                if let Some(arguments) = &mut arguments {
-                    statement.bind(arguments)?;
+            #[cfg(feature = "log-queries")]
+            let mut logger =
+                QueryLogger::new(query, arguments.as_ref().map_or(0, SqliteArguments::len));
+
+            let result = async {
+                let mut rows = 0;
+
+                loop {
+                    let key = self.prepare(&mut query, arguments.is_some())?;
+                    let total_changes = self.total_changes();
+
+                    if let Err(error) = self.run_to_completion(key, arguments.as_mut()).await {
+                        self.discard_statement(key);
+                        return Err(error);
+                    }
+
+                    // [changes] is only updated by INSERT, UPDATE or DELETE; a statement that
+                    // did not change the total (e.g., DDL) must not report the count left
+                    // behind by an earlier statement
+                    if self.total_changes() != total_changes {
+                        rows += self.changes();
+                    }
+
+                    if query.is_empty() {
+                        return Ok(rows);
+                    }
                 }
+            };
 
-                while let Step::Row = statement.step().await? {
-                    // We only care about the rows modified; ignore
-                }
+            let rows = match result.await {
+                Ok(rows) => rows,
 
-                if query.is_empty() {
-                    break;
+                Err(error) => {
+                    #[cfg(feature = "log-queries")]
+                    logger.fail(&error);
+                    return Err(error);
                 }
-            }
+            };
+
+            #[cfg(feature = "log-queries")]
+            logger.set_rows_affected(rows);
 
-            Ok(self.changes())
+            Ok(QueryResult {
+                rows_affected: rows,
+                last_insert_rowid: Some(self.last_insert_rowid()),
+                ..QueryResult::default()
+            })
         })
     }
 
@@ -148,15 +217,24 @@ impl Executor for SqliteConnection {
                 let decl = statement.column_decltype(i);
 
                 let r#type = match decl {
-                    None => None,
-                    Some(decl) => match &*decl.to_ascii_lowercase() {
-                        "bool" | "boolean" => Some(SqliteType::Boolean),
-                        "clob" | "text" => Some(SqliteType::Text),
-                        "blob" => Some(SqliteType::Blob),
-                        "real" | "double" | "double precision" | "float" => Some(SqliteType::Float),
-                        decl @ _ if decl.contains("int") => Some(SqliteType::Integer),
-                        decl @ _ if decl.contains("char") => Some(SqliteType::Text),
-                        _ => None,
+                    None | Some("") => None,
+
+                    Some(decl)
+                        if decl.eq_ignore_ascii_case("bool")
+                            || decl.eq_ignore_ascii_case("boolean") =>
+                    {
+                        Some(SqliteType::Boolean)
+                    }
+
+                    Some(decl) => match SqliteTypeAffinity::from_decltype(decl) {
+                        SqliteTypeAffinity::Integer => Some(SqliteType::Integer),
+                        SqliteTypeAffinity::Text => Some(SqliteType::Text),
+                        SqliteTypeAffinity::Blob => Some(SqliteType::Blob),
+                        SqliteTypeAffinity::Real => Some(SqliteType::Float),
+
+                        // a NUMERIC column stores each value as an integer, a real or text,
+                        // whichever holds it exactly, so it has no single type
+                        SqliteTypeAffinity::Numeric => None,
                     },
                 };
 
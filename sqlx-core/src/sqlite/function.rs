@@ -0,0 +1,133 @@
+use core::ffi::c_void;
+use core::ptr::NonNull;
+use core::slice;
+
+use std::ffi::CString;
+use std::os::raw::c_int;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use libsqlite3_sys::{
+    sqlite3_context, sqlite3_create_function_v2, sqlite3_result_error, sqlite3_user_data,
+    sqlite3_value, SQLITE_OK, SQLITE_UTF8,
+};
+
+use crate::encode::Encode;
+use crate::sqlite::{Sqlite, SqliteArgumentValue, SqliteConnection, SqliteError, SqliteValue};
+
+impl SqliteConnection {
+    /// Registers `function` as the scalar SQL function `name` on this connection with
+    /// [`sqlite3_create_function_v2`](https://www.sqlite.org/c3ref/create_function.html).
+    ///
+    /// The function is called with the arguments of each call in a query and returns the
+    /// result of the call; an error fails the query with the message of the error. `n_args`
+    /// is the number of arguments the function takes, or `-1` for any number of arguments.
+    /// Registering a function with the same name and number of arguments replaces it.
+    ///
+    /// ```rust,ignore
+    /// conn.create_scalar_function("double", 1, |args| {
+    ///     let x = <i64 as Decode<Sqlite>>::decode(args[0])?;
+    ///
+    ///     Ok(x * 2)
+    /// })?;
+    /// ```
+    pub fn create_scalar_function<F, R>(
+        &mut self,
+        name: &str,
+        n_args: i32,
+        function: F,
+    ) -> crate::Result<()>
+    where
+        F: Fn(&[SqliteValue<'_>]) -> crate::Result<R> + Send + 'static,
+        R: Encode<Sqlite>,
+    {
+        let name = CString::new(name).map_err(|_| {
+            crate::Error::Protocol("function name must not contain a NUL character".into())
+        })?;
+
+        let function = Box::into_raw(Box::new(function));
+
+        // the function is dropped by `destroy` when it is replaced, when the connection is
+        // closed, or right away if registering it fails
+        #[allow(unsafe_code)]
+        let status = unsafe {
+            sqlite3_create_function_v2(
+                self.handle(),
+                name.as_ptr(),
+                n_args,
+                SQLITE_UTF8,
+                function as *mut c_void,
+                Some(call::<F, R>),
+                None,
+                None,
+                Some(destroy::<F>),
+            )
+        };
+
+        if status != SQLITE_OK {
+            return Err(SqliteError::from_connection(self.handle()).into());
+        }
+
+        Ok(())
+    }
+}
+
+#[allow(unsafe_code)]
+unsafe extern "C" fn call<F, R>(
+    context: *mut sqlite3_context,
+    argc: c_int,
+    argv: *mut *mut sqlite3_value,
+) where
+    F: Fn(&[SqliteValue<'_>]) -> crate::Result<R>,
+    R: Encode<Sqlite>,
+{
+    let function = &*(sqlite3_user_data(context) as *const F);
+
+    let args: Vec<SqliteValue<'_>> = if argc > 0 {
+        slice::from_raw_parts(argv, argc as usize)
+            .iter()
+            .filter_map(|&value| NonNull::new(value))
+            .map(SqliteValue::from_raw)
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    // unwinding into SQLite is undefined behavior
+    let result = catch_unwind(AssertUnwindSafe(
+        || -> crate::Result<Vec<SqliteArgumentValue>> {
+            let value = function(&args)?;
+
+            let mut buf = Vec::with_capacity(1);
+            value.encode_nullable(&mut buf);
+
+            Ok(buf)
+        },
+    ));
+
+    match result {
+        // a NULL value writes nothing
+        Ok(Ok(buf)) => buf
+            .first()
+            .unwrap_or(&SqliteArgumentValue::Null)
+            .result(context),
+
+        Ok(Err(error)) => result_error(context, &error.to_string()),
+
+        Err(_) => result_error(context, "user-defined function panicked"),
+    }
+}
+
+#[allow(unsafe_code)]
+unsafe extern "C" fn destroy<F>(function: *mut c_void) {
+    drop(Box::from_raw(function as *mut F));
+}
+
+// SQLite copies the message
+#[allow(unsafe_code)]
+unsafe fn result_error(context: *mut sqlite3_context, message: &str) {
+    sqlite3_result_error(
+        context,
+        message.as_ptr() as *const i8,
+        message.len() as c_int,
+    );
+}
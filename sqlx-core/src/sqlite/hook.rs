@@ -0,0 +1,97 @@
+use core::ffi::c_void;
+use core::ptr::null_mut;
+
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use libsqlite3_sys::{
+    sqlite3_int64, sqlite3_update_hook, SQLITE_DELETE, SQLITE_INSERT, SQLITE_UPDATE,
+};
+
+use crate::sqlite::SqliteConnection;
+
+/// The kind of change to a row reported to an update hook.
+///
+/// See [`SqliteConnection::set_update_hook`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SqliteOperation {
+    Insert,
+    Update,
+    Delete,
+}
+
+pub(super) type UpdateHook = Box<dyn FnMut(SqliteOperation, &str, &str, i64) + Send>;
+
+impl SqliteConnection {
+    /// Sets `hook` to be called for each row inserted, updated or deleted through this
+    /// connection, with [`sqlite3_update_hook`](https://www.sqlite.org/c3ref/update_hook.html).
+    ///
+    /// The hook is called with the kind of change, the schema name of the database (`main`,
+    /// `temp` or the alias of an attached database), the name of the table and the rowid of
+    /// the row. It runs synchronously within the statement making the change, before the
+    /// statement completes, so it should be quick and must not use the connection; a panic
+    /// in the hook is caught and ignored. Changes to `WITHOUT ROWID` tables and changes made
+    /// to clear a table by a `DELETE` without a `WHERE` clause (the truncate optimization)
+    /// are not reported.
+    ///
+    /// Setting a hook replaces the previous one, which is dropped.
+    ///
+    /// ```rust,ignore
+    /// conn.set_update_hook(|operation, _database, table, rowid| {
+    ///     println!("{:?} of row {} in {}", operation, rowid, table);
+    /// });
+    /// ```
+    pub fn set_update_hook<F>(&mut self, hook: F)
+    where
+        F: FnMut(SqliteOperation, &str, &str, i64) + Send + 'static,
+    {
+        // boxed twice for a thin pointer to pass to SQLite
+        let mut hook: Box<UpdateHook> = Box::new(Box::new(hook));
+        let data = &mut *hook as *mut UpdateHook as *mut c_void;
+
+        #[allow(unsafe_code)]
+        unsafe {
+            sqlite3_update_hook(self.handle(), Some(call_update_hook), data);
+        }
+
+        // the previous hook is no longer reachable from SQLite
+        self.update_hook = Some(hook);
+    }
+
+    /// Removes the hook set with [`set_update_hook`](#method.set_update_hook), if any.
+    pub fn clear_update_hook(&mut self) {
+        #[allow(unsafe_code)]
+        unsafe {
+            sqlite3_update_hook(self.handle(), None, null_mut());
+        }
+
+        self.update_hook = None;
+    }
+}
+
+#[allow(unsafe_code)]
+unsafe extern "C" fn call_update_hook(
+    data: *mut c_void,
+    operation: c_int,
+    database: *const c_char,
+    table: *const c_char,
+    rowid: sqlite3_int64,
+) {
+    let hook = &mut *(data as *mut UpdateHook);
+
+    let operation = match operation {
+        SQLITE_INSERT => SqliteOperation::Insert,
+        SQLITE_UPDATE => SqliteOperation::Update,
+        SQLITE_DELETE => SqliteOperation::Delete,
+        _ => return,
+    };
+
+    let database = CStr::from_ptr(database).to_string_lossy();
+    let table = CStr::from_ptr(table).to_string_lossy();
+
+    // unwinding into SQLite is undefined behavior
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        hook(operation, &database, &table, rowid)
+    }));
+}
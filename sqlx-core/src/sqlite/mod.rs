@@ -6,8 +6,13 @@ mod cursor;
 mod database;
 mod error;
 mod executor;
+mod function;
+mod hook;
+#[cfg(feature = "regexp")]
+mod regexp;
 mod row;
 mod statement;
+mod statement_cache;
 mod types;
 mod value;
 mod worker;
@@ -17,7 +22,9 @@ pub use connection::SqliteConnection;
 pub use cursor::SqliteCursor;
 pub use database::Sqlite;
 pub use error::SqliteError;
+pub use hook::SqliteOperation;
 pub use row::SqliteRow;
+pub use statement_cache::SqliteStatementCacheStats;
 pub use types::SqliteTypeInfo;
 pub use value::SqliteValue;
 
@@ -27,4 +34,6 @@ pub type SqlitePool = crate::pool::Pool<SqliteConnection>;
 
 make_query_as!(SqliteQueryAs, Sqlite, SqliteRow);
 impl_map_row_for_row!(Sqlite, SqliteRow);
+impl_column_index_for_string!(Sqlite);
 impl_from_row_for_tuples!(Sqlite, SqliteRow);
+impl_from_row_for_map!(Sqlite, SqliteRow);
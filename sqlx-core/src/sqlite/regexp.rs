@@ -0,0 +1,41 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use regex::Regex;
+
+use crate::decode::Decode;
+use crate::sqlite::{Sqlite, SqliteConnection};
+
+// The number of compiled patterns kept by a connection; the cache is emptied when full
+const CACHE_CAPACITY: usize = 64;
+
+// Registers `regexp(pattern, text)`, which SQLite calls for `text REGEXP pattern`.
+// <https://www.sqlite.org/lang_expr.html#regexp>
+pub(super) fn register(conn: &mut SqliteConnection) -> crate::Result<()> {
+    let cache = RefCell::new(HashMap::<String, Regex>::new());
+
+    conn.create_scalar_function("regexp", 2, move |args| {
+        let pattern = <Option<&str> as Decode<Sqlite>>::decode(args[0])?;
+        let text = <Option<&str> as Decode<Sqlite>>::decode(args[1])?;
+
+        // like the other operators, a NULL operand makes the result NULL
+        let (pattern, text) = match (pattern, text) {
+            (Some(pattern), Some(text)) => (pattern, text),
+            _ => return Ok(None),
+        };
+
+        let mut cache = cache.borrow_mut();
+
+        if !cache.contains_key(pattern) {
+            let regex = Regex::new(pattern).map_err(crate::Error::decode)?;
+
+            if cache.len() >= CACHE_CAPACITY {
+                cache.clear();
+            }
+
+            cache.insert(pattern.to_owned(), regex);
+        }
+
+        Ok(Some(cache[pattern].is_match(text)))
+    })
+}
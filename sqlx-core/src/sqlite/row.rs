@@ -1,8 +1,8 @@
 use crate::database::HasRow;
-use crate::row::{ColumnIndex, Row};
+use crate::row::{Column, ColumnIndex, Row};
 use crate::sqlite::statement::Statement;
 use crate::sqlite::value::SqliteValue;
-use crate::sqlite::{Sqlite, SqliteConnection};
+use crate::sqlite::{Sqlite, SqliteConnection, SqliteTypeInfo};
 
 pub struct SqliteRow<'c> {
     pub(super) values: usize,
@@ -24,13 +24,35 @@ impl<'c> Row<'c> for SqliteRow<'c> {
         self.values
     }
 
+    fn column_name(&self, index: usize) -> Option<&str> {
+        self.statement().columns.name(index)
+    }
+
+    fn column(&self, index: usize) -> Option<Column<'_, Self::Database>> {
+        if index >= self.values {
+            return None;
+        }
+
+        // values are dynamically typed, so this is the type of the value in this row
+        let value = SqliteValue::new(self.statement(), index, self.connection.strict_types);
+
+        Some(Column {
+            ordinal: index,
+            name: self.column_name(index),
+            type_info: value.r#type().map(|r#type| SqliteTypeInfo {
+                r#type,
+                affinity: None,
+            }),
+        })
+    }
+
     fn try_get_raw<'r, I>(&'r self, index: I) -> crate::Result<SqliteValue<'r>>
     where
         'c: 'r,
         I: ColumnIndex<Self::Database>,
     {
         let index = index.resolve(self)?;
-        let value = SqliteValue::new(self.statement(), index);
+        let value = SqliteValue::new(self.statement(), index, self.connection.strict_types);
 
         Ok(value)
     }
@@ -52,8 +74,7 @@ impl ColumnIndex<Sqlite> for &'_ str {
     fn resolve(self, row: &<Sqlite as HasRow>::Row) -> crate::Result<usize> {
         row.statement()
             .columns
-            .get(self)
+            .index(self)
             .ok_or_else(|| crate::Error::ColumnNotFound((*self).into()))
-            .map(|&index| index as usize)
     }
 }
@@ -1,6 +1,5 @@
 use core::ptr::{null, null_mut, NonNull};
 
-use std::collections::HashMap;
 use std::ffi::CStr;
 use std::os::raw::c_int;
 
@@ -11,6 +10,7 @@ use libsqlite3_sys::{
     SQLITE_PREPARE_NO_VTAB, SQLITE_PREPARE_PERSISTENT, SQLITE_ROW,
 };
 
+use crate::row::ColumnNames;
 use crate::sqlite::connection::SqliteConnectionHandle;
 use crate::sqlite::worker::Worker;
 use crate::sqlite::SqliteError;
@@ -38,7 +38,7 @@ pub(super) struct Statement {
     pub(super) connection: SqliteConnectionHandle,
     pub(super) worker: Worker,
     pub(super) tail: usize,
-    pub(super) columns: HashMap<String, usize>,
+    pub(super) columns: ColumnNames,
 }
 
 // SQLite3 statement objects are safe to send between threads, but *not* safe
@@ -95,18 +95,16 @@ impl Statement {
             worker: conn.worker.clone(),
             connection: conn.handle,
             handle: SqliteStatementHandle(NonNull::new(statement_handle).unwrap()),
-            columns: HashMap::new(),
+            columns: ColumnNames::default(),
             tail,
         };
 
-        // Prepare a column hash map for use in pulling values from a column by name
-        let count = self_.column_count();
-        self_.columns.reserve(count);
+        // Prepare the column names for use in pulling values from a column by name
+        let names: Vec<String> = (0..self_.column_count())
+            .map(|i| self_.column_name(i).to_owned())
+            .collect();
 
-        for i in 0..count {
-            let name = self_.column_name(i).to_owned();
-            self_.columns.insert(name, i);
-        }
+        self_.columns = ColumnNames::new(names.iter().map(|name| Some(&**name)));
 
         Ok(self_)
     }
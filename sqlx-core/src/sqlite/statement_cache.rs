@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+
+use crate::sqlite::statement::Statement;
+
+// Keeps the persistent statements of a connection so executing the same query again can
+// skip compiling it. Statements are indexed by a key that stays valid until the statement
+// is evicted; when the cache is full, the least recently used statement is finalized and
+// its slot is reused.
+pub(super) struct StatementCache {
+    slots: Vec<Option<Slot>>,
+    by_query: HashMap<String, usize>,
+    capacity: usize,
+    // incremented on every use; a slot remembers the value of its last use
+    clock: u64,
+    pub(super) stats: SqliteStatementCacheStats,
+}
+
+struct Slot {
+    statement: Statement,
+    query: String,
+    last_used: u64,
+}
+
+impl StatementCache {
+    pub(super) fn new(capacity: usize) -> Self {
+        Self {
+            slots: Vec::new(),
+            by_query: HashMap::new(),
+            capacity,
+            clock: 0,
+            stats: SqliteStatementCacheStats::default(),
+        }
+    }
+
+    pub(super) fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub(super) fn len(&self) -> usize {
+        self.by_query.len()
+    }
+
+    // Returns the key of the statement cached for `query`, marking it as used.
+    pub(super) fn get(&mut self, query: &str) -> Option<usize> {
+        let key = *self.by_query.get(query)?;
+
+        self.clock += 1;
+        self.stats.hits += 1;
+
+        if let Some(slot) = &mut self.slots[key] {
+            slot.last_used = self.clock;
+        }
+
+        Some(key)
+    }
+
+    // Caches a newly prepared statement, evicting the least recently used statement
+    // if the cache is full.
+    pub(super) fn insert(&mut self, query: String, statement: Statement) -> usize {
+        if self.len() >= self.capacity {
+            self.evict_lru();
+        }
+
+        self.clock += 1;
+        self.stats.prepared += 1;
+
+        let slot = Slot {
+            statement,
+            query: query.clone(),
+            last_used: self.clock,
+        };
+
+        let key = match self.slots.iter().position(Option::is_none) {
+            Some(key) => {
+                self.slots[key] = Some(slot);
+                key
+            }
+
+            None => {
+                self.slots.push(Some(slot));
+                self.slots.len() - 1
+            }
+        };
+
+        self.by_query.insert(query, key);
+
+        key
+    }
+
+    // Finalizes the statement at `key`.
+    pub(super) fn remove(&mut self, key: usize) {
+        if let Some(slot) = self.slots.get_mut(key).and_then(Option::take) {
+            self.by_query.remove(&slot.query);
+        }
+    }
+
+    pub(super) fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+
+        while self.len() > capacity {
+            self.evict_lru();
+        }
+    }
+
+    pub(super) fn clear(&mut self) {
+        self.slots.clear();
+        self.by_query.clear();
+    }
+
+    pub(super) fn statement(&self, key: usize) -> &Statement {
+        &self.slots[key].as_ref().expect(ERR_EVICTED).statement
+    }
+
+    pub(super) fn statement_mut(&mut self, key: usize) -> &mut Statement {
+        &mut self.slots[key].as_mut().expect(ERR_EVICTED).statement
+    }
+
+    fn evict_lru(&mut self) {
+        let lru = self
+            .slots
+            .iter()
+            .enumerate()
+            .filter_map(|(key, slot)| Some((key, slot.as_ref()?.last_used)))
+            .min_by_key(|&(_, last_used)| last_used)
+            .map(|(key, _)| key);
+
+        if let Some(key) = lru {
+            self.remove(key);
+        }
+    }
+}
+
+const ERR_EVICTED: &str = "(bug) statement evicted while in use";
+
+/// Counters for the prepared statements cached by a [`SqliteConnection`].
+///
+/// [`SqliteConnection`]: crate::sqlite::SqliteConnection
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SqliteStatementCacheStats {
+    /// The number of statements compiled to be cached.
+    pub prepared: u64,
+
+    /// The number of executions that reused a cached statement.
+    pub hits: u64,
+
+    /// The number of statements currently cached.
+    pub size: usize,
+}
@@ -1,12 +1,13 @@
 use crate::decode::Decode;
 use crate::encode::Encode;
-use crate::sqlite::types::{SqliteType, SqliteTypeAffinity};
+use crate::sqlite::types::int::decode_integer;
+use crate::sqlite::types::{mismatched_type, value_type, SqliteType, SqliteTypeAffinity};
 use crate::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValue};
 use crate::types::Type;
 
 impl Type<Sqlite> for bool {
     fn type_info() -> SqliteTypeInfo {
-        SqliteTypeInfo::new(SqliteType::Boolean, SqliteTypeAffinity::Numeric)
+        SqliteTypeInfo::new(SqliteType::Boolean, SqliteTypeAffinity::Integer)
     }
 }
 
@@ -16,8 +17,42 @@ impl Encode<Sqlite> for bool {
     }
 }
 
+// Booleans are stored as the integers 0 and 1; any other integer is true. Unless the value
+// is strict, TEXT written by other tools as '0'/'1', 'true'/'false' or 't'/'f' is accepted.
 impl<'a> Decode<'a, Sqlite> for bool {
     fn decode(value: SqliteValue<'a>) -> crate::Result<bool> {
-        Ok(value.int() != 0)
+        match value_type(&value)? {
+            Some(SqliteType::Text) if !value.is_strict() => {
+                decode_text(value.text().unwrap_or_default()).ok_or_else(|| {
+                    mismatched_type::<bool>(SqliteType::Text, "expected 0, 1, true, false, t or f")
+                })
+            }
+
+            _ => decode_integer::<bool>(&value).map(|int| int != 0),
+        }
     }
 }
+
+fn decode_text(text: &str) -> Option<bool> {
+    let text = text.trim();
+
+    if text == "1" || text.eq_ignore_ascii_case("true") || text.eq_ignore_ascii_case("t") {
+        Some(true)
+    } else if text == "0" || text.eq_ignore_ascii_case("false") || text.eq_ignore_ascii_case("f") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+#[test]
+fn test_decode_text() {
+    assert_eq!(decode_text("1"), Some(true));
+    assert_eq!(decode_text("TRUE"), Some(true));
+    assert_eq!(decode_text("t"), Some(true));
+    assert_eq!(decode_text("0"), Some(false));
+    assert_eq!(decode_text(" false "), Some(false));
+    assert_eq!(decode_text("F"), Some(false));
+    assert_eq!(decode_text("yes"), None);
+    assert_eq!(decode_text(""), None);
+}
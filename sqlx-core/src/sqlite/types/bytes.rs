@@ -1,6 +1,10 @@
+use std::convert::TryFrom;
+
 use crate::decode::Decode;
 use crate::encode::Encode;
-use crate::sqlite::types::{SqliteType, SqliteTypeAffinity};
+use crate::sqlite::types::{
+    conversion_error, mismatched_type, value_type, SqliteType, SqliteTypeAffinity,
+};
 use crate::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValue};
 use crate::types::Type;
 
@@ -16,6 +20,12 @@ impl Type<Sqlite> for Vec<u8> {
     }
 }
 
+impl<const N: usize> Type<Sqlite> for [u8; N] {
+    fn type_info() -> SqliteTypeInfo {
+        <[u8] as Type<Sqlite>>::type_info()
+    }
+}
+
 impl Encode<Sqlite> for [u8] {
     fn encode(&self, values: &mut Vec<SqliteArgumentValue>) {
         // TODO: look into a way to remove this allocation
@@ -29,9 +39,21 @@ impl Encode<Sqlite> for Vec<u8> {
     }
 }
 
+impl<const N: usize> Encode<Sqlite> for [u8; N] {
+    fn encode(&self, values: &mut Vec<SqliteArgumentValue>) {
+        <[u8] as Encode<Sqlite>>::encode(self, values)
+    }
+}
+
 impl<'de> Decode<'de, Sqlite> for &'de [u8] {
     fn decode(value: SqliteValue<'de>) -> crate::Result<&'de [u8]> {
-        Ok(value.blob())
+        // unless the value is strict, the bytes of any other value are returned as its
+        // TEXT representation
+        match value_type(&value)? {
+            None | Some(SqliteType::Blob) => Ok(value.blob()),
+            _ if !value.is_strict() => Ok(value.blob()),
+            Some(found) => Err(mismatched_type::<[u8]>(found, conversion_error(&value))),
+        }
     }
 }
 
@@ -40,3 +62,36 @@ impl<'de> Decode<'de, Sqlite> for Vec<u8> {
         <&[u8] as Decode<Sqlite>>::decode(value).map(ToOwned::to_owned)
     }
 }
+
+impl<'de, const N: usize> Decode<'de, Sqlite> for [u8; N] {
+    fn decode(value: SqliteValue<'de>) -> crate::Result<[u8; N]> {
+        let blob = <&[u8] as Decode<Sqlite>>::decode(value)?;
+
+        <[u8; N]>::try_from(blob).map_err(|_| {
+            crate::Error::Decode(
+                format!("expected a BLOB of {} bytes, got {} bytes", N, blob.len()).into(),
+            )
+        })
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl Type<Sqlite> for ::bytes::Bytes {
+    fn type_info() -> SqliteTypeInfo {
+        <[u8] as Type<Sqlite>>::type_info()
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl Encode<Sqlite> for ::bytes::Bytes {
+    fn encode(&self, values: &mut Vec<SqliteArgumentValue>) {
+        <[u8] as Encode<Sqlite>>::encode(self, values)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<'de> Decode<'de, Sqlite> for ::bytes::Bytes {
+    fn decode(value: SqliteValue<'de>) -> crate::Result<::bytes::Bytes> {
+        <&[u8] as Decode<Sqlite>>::decode(value).map(::bytes::Bytes::copy_from_slice)
+    }
+}
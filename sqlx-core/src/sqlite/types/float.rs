@@ -1,6 +1,8 @@
 use crate::decode::Decode;
 use crate::encode::Encode;
-use crate::sqlite::types::{SqliteType, SqliteTypeAffinity};
+use crate::sqlite::types::{
+    conversion_error, mismatched_type, value_type, SqliteType, SqliteTypeAffinity,
+};
 use crate::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValue};
 use crate::types::Type;
 
@@ -18,7 +20,7 @@ impl Encode<Sqlite> for f32 {
 
 impl<'a> Decode<'a, Sqlite> for f32 {
     fn decode(value: SqliteValue<'a>) -> crate::Result<f32> {
-        Ok(value.double() as f32)
+        decode_float::<f32>(&value).map(|float| float as f32)
     }
 }
 
@@ -36,6 +38,26 @@ impl Encode<Sqlite> for f64 {
 
 impl<'a> Decode<'a, Sqlite> for f64 {
     fn decode(value: SqliteValue<'a>) -> crate::Result<f64> {
-        Ok(value.double())
+        decode_float::<f64>(&value)
+    }
+}
+
+// Decodes a REAL or INTEGER value as `T`; unless the value is strict, a TEXT value holding a
+// number is converted
+fn decode_float<T>(value: &SqliteValue<'_>) -> crate::Result<f64>
+where
+    T: Type<Sqlite>,
+{
+    match value_type(value)? {
+        None | Some(SqliteType::Float) | Some(SqliteType::Integer) => Ok(value.double()),
+
+        Some(SqliteType::Text) if !value.is_strict() => value
+            .text()
+            .unwrap_or_default()
+            .trim()
+            .parse()
+            .map_err(|err| mismatched_type::<T>(SqliteType::Text, err)),
+
+        Some(found) => Err(mismatched_type::<T>(found, conversion_error(value))),
     }
 }
@@ -1,6 +1,10 @@
+use std::convert::TryFrom;
+
 use crate::decode::Decode;
 use crate::encode::Encode;
-use crate::sqlite::types::{SqliteType, SqliteTypeAffinity};
+use crate::sqlite::types::{
+    conversion_error, mismatched_type, value_type, SqliteType, SqliteTypeAffinity,
+};
 use crate::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValue};
 use crate::types::Type;
 
@@ -18,7 +22,9 @@ impl Encode<Sqlite> for i32 {
 
 impl<'a> Decode<'a, Sqlite> for i32 {
     fn decode(value: SqliteValue<'a>) -> crate::Result<i32> {
-        Ok(value.int())
+        let int = decode_integer::<i32>(&value)?;
+
+        i32::try_from(int).map_err(|err| mismatched_type::<i32>(SqliteType::Integer, err))
     }
 }
 
@@ -36,6 +42,39 @@ impl Encode<Sqlite> for i64 {
 
 impl<'a> Decode<'a, Sqlite> for i64 {
     fn decode(value: SqliteValue<'a>) -> crate::Result<i64> {
-        Ok(value.int64())
+        decode_integer::<i64>(&value)
+    }
+}
+
+// Decodes an INTEGER value as `T`; unless the value is strict, a REAL value without a
+// fractional part or a TEXT value holding an integer is converted
+pub(super) fn decode_integer<T>(value: &SqliteValue<'_>) -> crate::Result<i64>
+where
+    T: Type<Sqlite>,
+{
+    match value_type(value)? {
+        None | Some(SqliteType::Integer) => Ok(value.int64()),
+
+        Some(SqliteType::Float) if !value.is_strict() => {
+            let float = value.double();
+
+            if float.fract() == 0.0 && float >= i64::MIN as f64 && float < i64::MAX as f64 {
+                Ok(float as i64)
+            } else {
+                Err(mismatched_type::<T>(
+                    SqliteType::Float,
+                    format!("{} is not an integer", float),
+                ))
+            }
+        }
+
+        Some(SqliteType::Text) if !value.is_strict() => value
+            .text()
+            .unwrap_or_default()
+            .trim()
+            .parse()
+            .map_err(|err| mismatched_type::<T>(SqliteType::Text, err)),
+
+        Some(found) => Err(mismatched_type::<T>(found, conversion_error(value))),
     }
 }
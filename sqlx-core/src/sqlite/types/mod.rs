@@ -1,9 +1,12 @@
+use std::any::type_name;
+use std::error::Error as StdError;
 use std::fmt::{self, Display};
 
 use crate::decode::Decode;
+use crate::error::{MismatchedTypeError, UnexpectedNullError};
 use crate::sqlite::value::SqliteValue;
 use crate::sqlite::Sqlite;
-use crate::types::TypeInfo;
+use crate::types::{Type, TypeInfo};
 
 mod bool;
 mod bytes;
@@ -13,6 +16,7 @@ mod str;
 
 // https://www.sqlite.org/c3ref/c_blob.html
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "offline", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum SqliteType {
     Integer = 1,
     Float = 2,
@@ -25,6 +29,7 @@ pub(crate) enum SqliteType {
 
 // https://www.sqlite.org/datatype3.html#type_affinity
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "offline", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) enum SqliteTypeAffinity {
     Text,
     Numeric,
@@ -33,7 +38,28 @@ pub(crate) enum SqliteTypeAffinity {
     Blob,
 }
 
+impl SqliteTypeAffinity {
+    // Determines the affinity of a column from its declared type, by the rules SQLite follows
+    // https://www.sqlite.org/datatype3.html#determination_of_column_affinity
+    pub(crate) fn from_decltype(decl: &str) -> Self {
+        let decl = decl.to_ascii_uppercase();
+
+        if decl.contains("INT") {
+            SqliteTypeAffinity::Integer
+        } else if decl.contains("CHAR") || decl.contains("CLOB") || decl.contains("TEXT") {
+            SqliteTypeAffinity::Text
+        } else if decl.contains("BLOB") || decl.is_empty() {
+            SqliteTypeAffinity::Blob
+        } else if decl.contains("REAL") || decl.contains("FLOA") || decl.contains("DOUB") {
+            SqliteTypeAffinity::Real
+        } else {
+            SqliteTypeAffinity::Numeric
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "offline", derive(serde::Serialize, serde::Deserialize))]
 pub struct SqliteTypeInfo {
     pub(crate) r#type: SqliteType,
     pub(crate) affinity: Option<SqliteTypeAffinity>,
@@ -50,22 +76,65 @@ impl SqliteTypeInfo {
 
 impl Display for SqliteTypeInfo {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(match self.r#type {
+        f.write_str(self.name())
+    }
+}
+
+impl TypeInfo for SqliteTypeInfo {
+    fn name(&self) -> &str {
+        match self.r#type {
             SqliteType::Text => "TEXT",
             SqliteType::Boolean => "BOOLEAN",
             SqliteType::Integer => "INTEGER",
             SqliteType::Float => "DOUBLE",
             SqliteType::Blob => "BLOB",
-        })
+        }
     }
-}
 
-impl TypeInfo for SqliteTypeInfo {
     fn compatible(&self, other: &Self) -> bool {
         self.r#type == other.r#type || self.affinity == other.affinity
     }
 }
 
+// Returns the type the value is stored as; NULL is only an error in strict mode and is
+// otherwise decoded as SQLite converts it, to zero or an empty value
+fn value_type(value: &SqliteValue<'_>) -> crate::Result<Option<SqliteType>> {
+    match value.r#type() {
+        None if value.is_strict() => Err(crate::Error::decode(UnexpectedNullError)),
+        r#type => Ok(r#type),
+    }
+}
+
+// Returns the error for a value stored as `found` that cannot be decoded as `T`
+fn mismatched_type<T>(
+    found: SqliteType,
+    source: impl Into<Box<dyn StdError + Send + Sync>>,
+) -> crate::Error
+where
+    T: Type<Sqlite> + ?Sized,
+{
+    let found = SqliteTypeInfo {
+        r#type: found,
+        affinity: None,
+    };
+
+    crate::Error::decode(MismatchedTypeError {
+        rust_type: type_name::<T>(),
+        expected: T::type_info().name().into(),
+        found: found.name().into(),
+        source: source.into(),
+    })
+}
+
+// Explains why a value stored as another type is not converted
+fn conversion_error(value: &SqliteValue<'_>) -> &'static str {
+    if value.is_strict() {
+        "values are not converted between types in strict mode"
+    } else {
+        "the value cannot be converted"
+    }
+}
+
 impl<'de, T> Decode<'de, Sqlite> for Option<T>
 where
     T: Decode<'de, Sqlite>,
@@ -78,3 +147,27 @@ where
         }
     }
 }
+
+#[test]
+fn test_type_info_name() {
+    use crate::types::Type;
+
+    assert_eq!(<i64 as Type<Sqlite>>::type_info().name(), "INTEGER");
+    assert_eq!(<f64 as Type<Sqlite>>::type_info().name(), "DOUBLE");
+    assert_eq!(<Vec<u8> as Type<Sqlite>>::type_info().name(), "BLOB");
+}
+
+#[test]
+fn test_affinity_from_decltype() {
+    use SqliteTypeAffinity::*;
+
+    assert_eq!(SqliteTypeAffinity::from_decltype("BIGINT"), Integer);
+    assert_eq!(SqliteTypeAffinity::from_decltype("varchar(255)"), Text);
+    assert_eq!(SqliteTypeAffinity::from_decltype(""), Blob);
+    assert_eq!(SqliteTypeAffinity::from_decltype("DOUBLE PRECISION"), Real);
+    assert_eq!(SqliteTypeAffinity::from_decltype("DECIMAL(10,5)"), Numeric);
+    assert_eq!(SqliteTypeAffinity::from_decltype("BOOLEAN"), Numeric);
+
+    // the rules are applied in order, so "POINT" has integer affinity
+    assert_eq!(SqliteTypeAffinity::from_decltype("FLOATING POINT"), Integer);
+}
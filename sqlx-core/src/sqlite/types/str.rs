@@ -1,7 +1,11 @@
-use crate::decode::Decode;
+use std::str::from_utf8;
+
+use crate::decode::{decode_char, Decode};
 use crate::encode::Encode;
 use crate::error::UnexpectedNullError;
-use crate::sqlite::types::{SqliteType, SqliteTypeAffinity};
+use crate::sqlite::types::{
+    conversion_error, mismatched_type, value_type, SqliteType, SqliteTypeAffinity,
+};
 use crate::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValue};
 use crate::types::Type;
 
@@ -32,6 +36,21 @@ impl Encode<Sqlite> for String {
 
 impl<'de> Decode<'de, Sqlite> for &'de str {
     fn decode(value: SqliteValue<'de>) -> crate::Result<&'de str> {
+        // unless the value is strict, INTEGER and REAL values are converted to TEXT by SQLite
+        // and a BLOB value is converted if it holds UTF-8
+        match value_type(&value)? {
+            None | Some(SqliteType::Text) => {}
+
+            Some(SqliteType::Blob) if !value.is_strict() => {
+                return from_utf8(value.blob())
+                    .map_err(|err| mismatched_type::<str>(SqliteType::Blob, err));
+            }
+
+            Some(SqliteType::Integer) | Some(SqliteType::Float) if !value.is_strict() => {}
+
+            Some(found) => return Err(mismatched_type::<str>(found, conversion_error(&value))),
+        }
+
         value
             .text()
             .ok_or_else(|| crate::Error::decode(UnexpectedNullError))
@@ -43,3 +62,21 @@ impl<'de> Decode<'de, Sqlite> for String {
         <&str as Decode<Sqlite>>::decode(value).map(ToOwned::to_owned)
     }
 }
+
+impl Type<Sqlite> for char {
+    fn type_info() -> SqliteTypeInfo {
+        <str as Type<Sqlite>>::type_info()
+    }
+}
+
+impl Encode<Sqlite> for char {
+    fn encode(&self, values: &mut Vec<SqliteArgumentValue>) {
+        values.push(SqliteArgumentValue::Text(self.to_string()));
+    }
+}
+
+impl<'de> Decode<'de, Sqlite> for char {
+    fn decode(value: SqliteValue<'de>) -> crate::Result<char> {
+        <&str as Decode<Sqlite>>::decode(value).and_then(decode_char)
+    }
+}
@@ -1,76 +1,134 @@
+use core::marker::PhantomData;
+use core::ptr::NonNull;
 use core::slice;
 
 use std::ffi::CStr;
+use std::io::Read;
 use std::str::from_utf8_unchecked;
 
 use libsqlite3_sys::{
-    sqlite3_column_blob, sqlite3_column_bytes, sqlite3_column_double, sqlite3_column_int,
-    sqlite3_column_int64, sqlite3_column_text, sqlite3_column_type, SQLITE_NULL,
+    sqlite3_column_blob, sqlite3_column_bytes, sqlite3_column_double, sqlite3_column_int64,
+    sqlite3_column_text, sqlite3_column_type, sqlite3_value, sqlite3_value_blob,
+    sqlite3_value_bytes, sqlite3_value_double, sqlite3_value_int64, sqlite3_value_text,
+    sqlite3_value_type, SQLITE_BLOB, SQLITE_FLOAT, SQLITE_INTEGER, SQLITE_NULL, SQLITE_TEXT,
 };
 
 use crate::sqlite::statement::Statement;
+use crate::sqlite::types::SqliteType;
 
+#[derive(Clone, Copy)]
 pub struct SqliteValue<'c> {
-    index: i32,
-    statement: &'c Statement,
+    source: Source<'c>,
+    strict: bool,
+}
+
+// A value is either a column of the current row of a statement or an argument passed
+// to a user-defined function
+#[derive(Clone, Copy)]
+enum Source<'c> {
+    Column(&'c Statement, i32),
+    Value(NonNull<sqlite3_value>, PhantomData<&'c sqlite3_value>),
 }
 
 impl<'c> SqliteValue<'c> {
     #[inline]
-    pub(super) fn new(statement: &'c Statement, index: usize) -> Self {
+    pub(super) fn new(statement: &'c Statement, index: usize, strict: bool) -> Self {
         Self {
-            statement,
-            index: index as i32,
+            source: Source::Column(statement, index as i32),
+            strict,
+        }
+    }
+
+    // The value must remain valid for `'c`; the arguments of a function are valid until
+    // the function returns
+    #[inline]
+    pub(super) fn from_raw(value: NonNull<sqlite3_value>) -> Self {
+        Self {
+            source: Source::Value(value, PhantomData),
+            strict: false,
+        }
+    }
+
+    /// Returns true if the value must not be converted from the type it is stored as.
+    pub(super) fn is_strict(&self) -> bool {
+        self.strict
+    }
+}
+
+impl<'c> SqliteValue<'c> {
+    /// Returns a reader over the bytes of a TEXT (UTF-8) or BLOB value, or `None` if the
+    /// value is of another type or NULL.
+    ///
+    /// The bytes are read directly from the row held by SQLite so large values can be
+    /// processed in chunks instead of being copied into a `String` or `Vec<u8>`.
+    pub fn reader(&self) -> Option<impl Read + 'c> {
+        match self.type_code() {
+            SQLITE_TEXT | SQLITE_BLOB => Some(self.blob()),
+            _ => None,
         }
     }
 }
 
 // https://www.sqlite.org/c3ref/column_blob.html
 // https://www.sqlite.org/capi3ref.html#sqlite3_column_blob
+// https://www.sqlite.org/c3ref/value_blob.html
 
-// These routines return information about a single column of the current result row of a query.
+// These routines return information about a single column of the current result row of a query,
+// or about a single argument of a function.
+
+// Calls the `sqlite3_column_*` or `sqlite3_value_*` routine for the source of the value
+macro_rules! value_fn {
+    ($self:ident, $column_fn:ident, $value_fn:ident) => {{
+        #[allow(unsafe_code)]
+        unsafe {
+            match $self.source {
+                Source::Column(statement, index) => $column_fn(statement.handle(), index),
+                Source::Value(value, _) => $value_fn(value.as_ptr()),
+            }
+        }
+    }};
+}
 
 impl<'c> SqliteValue<'c> {
+    fn type_code(&self) -> i32 {
+        value_fn!(self, sqlite3_column_type, sqlite3_value_type)
+    }
+
     /// Returns true if the value should be intrepreted as NULL.
     pub(super) fn is_null(&self) -> bool {
-        #[allow(unsafe_code)]
-        let type_code = unsafe { sqlite3_column_type(self.statement.handle(), self.index) };
-        type_code == SQLITE_NULL
+        self.type_code() == SQLITE_NULL
     }
 
-    /// Returns the 32-bit INTEGER result.
-    pub(super) fn int(&self) -> i32 {
-        #[allow(unsafe_code)]
-        unsafe {
-            sqlite3_column_int(self.statement.handle(), self.index)
+    /// Returns the type the value is stored as, or `None` if the value is NULL.
+    pub(super) fn r#type(&self) -> Option<SqliteType> {
+        match self.type_code() {
+            SQLITE_INTEGER => Some(SqliteType::Integer),
+            SQLITE_FLOAT => Some(SqliteType::Float),
+            SQLITE_TEXT => Some(SqliteType::Text),
+            SQLITE_BLOB => Some(SqliteType::Blob),
+            _ => None,
         }
     }
 
     /// Returns the 64-bit INTEGER result.
     pub(super) fn int64(&self) -> i64 {
-        #[allow(unsafe_code)]
-        unsafe {
-            sqlite3_column_int64(self.statement.handle(), self.index)
-        }
+        value_fn!(self, sqlite3_column_int64, sqlite3_value_int64)
     }
 
     /// Returns the 64-bit, REAL result.
     pub(super) fn double(&self) -> f64 {
-        #[allow(unsafe_code)]
-        unsafe {
-            sqlite3_column_double(self.statement.handle(), self.index)
-        }
+        value_fn!(self, sqlite3_column_double, sqlite3_value_double)
     }
 
     /// Returns the UTF-8 TEXT result.
     pub(super) fn text(&self) -> Option<&'c str> {
-        #[allow(unsafe_code)]
-        unsafe {
-            let ptr = sqlite3_column_text(self.statement.handle(), self.index) as *const i8;
+        let ptr = value_fn!(self, sqlite3_column_text, sqlite3_value_text) as *const i8;
 
-            if ptr.is_null() {
-                None
-            } else {
+        if ptr.is_null() {
+            None
+        } else {
+            #[allow(unsafe_code)]
+            unsafe {
                 Some(from_utf8_unchecked(CStr::from_ptr(ptr).to_bytes()))
             }
         }
@@ -78,15 +136,13 @@ impl<'c> SqliteValue<'c> {
 
     fn bytes(&self) -> usize {
         // Returns the size of the result in bytes.
-        #[allow(unsafe_code)]
-        let len = unsafe { sqlite3_column_bytes(self.statement.handle(), self.index) };
+        let len = value_fn!(self, sqlite3_column_bytes, sqlite3_value_bytes);
         len as usize
     }
 
     /// Returns the BLOB result.
     pub(super) fn blob(&self) -> &'c [u8] {
-        #[allow(unsafe_code)]
-        let ptr = unsafe { sqlite3_column_blob(self.statement.handle(), self.index) };
+        let ptr = value_fn!(self, sqlite3_column_blob, sqlite3_value_blob);
 
         if ptr.is_null() {
             // Empty BLOBs are received as null pointers
@@ -6,7 +6,7 @@ use crate::connection::Connection;
 use crate::database::Database;
 use crate::database::HasCursor;
 use crate::describe::Describe;
-use crate::executor::{Execute, Executor, RefExecutor};
+use crate::executor::{Execute, Executor, QueryResult, RefExecutor};
 use crate::runtime::spawn;
 
 /// Represents a database transaction.
@@ -78,6 +78,44 @@ where
 
         Ok(inner)
     }
+
+    /// Runs `f` within a new save point in the current transaction, releasing it if `f`
+    /// returns `Ok` and rolling back to it if `f` returns `Err`.
+    ///
+    /// This is the borrowing counterpart of [`Connection::transaction`] for use from within
+    /// its closure; the outer transaction is left open either way.
+    pub async fn transaction<F, R, E>(&mut self, f: F) -> Result<R, E>
+    where
+        F: for<'t> FnOnce(&'t mut Transaction<T>) -> BoxFuture<'t, Result<R, E>>,
+        E: From<crate::Error>,
+    {
+        let depth = self.depth;
+
+        self.execute(&*format!("SAVEPOINT _sqlx_savepoint_{}", depth))
+            .await?;
+
+        // save points opened by `f` must be named after this one
+        self.depth += 1;
+        let res = f(self).await;
+        self.depth -= 1;
+
+        match res {
+            Ok(value) => {
+                self.execute(&*format!("RELEASE SAVEPOINT _sqlx_savepoint_{}", depth))
+                    .await?;
+
+                Ok(value)
+            }
+
+            Err(err) => {
+                let _ = self
+                    .execute(&*format!("ROLLBACK TO SAVEPOINT _sqlx_savepoint_{}", depth))
+                    .await;
+
+                Err(err)
+            }
+        }
+    }
 }
 
 const ERR_FINALIZED: &str = "(bug) transaction already finalized";
@@ -146,7 +184,7 @@ where
     fn execute<'e, 'q: 'e, 'c: 'e, E: 'e>(
         &'c mut self,
         query: E,
-    ) -> BoxFuture<'e, crate::Result<u64>>
+    ) -> BoxFuture<'e, crate::Result<QueryResult>>
     where
         E: Execute<'q, Self::Database>,
     {
@@ -1,6 +1,10 @@
 //! Traits linking Rust types to SQL types.
 
 use std::fmt::{Debug, Display};
+use std::num::{
+    NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8,
+};
+use std::sync::Arc;
 
 use crate::database::Database;
 
@@ -11,23 +15,38 @@ pub use uuid::Uuid;
 #[cfg(feature = "chrono")]
 #[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
 pub mod chrono {
-    pub use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+    pub use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 }
 
 #[cfg(feature = "bigdecimal")]
 #[cfg_attr(docsrs, doc(cfg(feature = "bigdecimal")))]
 pub use bigdecimal::BigDecimal;
 
+#[cfg(feature = "bytes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+pub use bytes::Bytes;
+
 #[cfg(feature = "ipnetwork")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ipnetwork")))]
 pub mod ipnetwork {
     pub use ipnetwork::{IpNetwork, Ipv4Network, Ipv6Network};
 }
 
+#[cfg(feature = "json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+pub mod json;
+
+#[cfg(feature = "json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+pub use json::{Json, JsonValue};
+
 pub trait TypeInfo: Debug + Display + Clone {
     /// Compares type information to determine if `other` is compatible at the Rust level
     /// with `self`.
     fn compatible(&self, other: &Self) -> bool;
+
+    /// Returns the name of the SQL type, for use in diagnostics.
+    fn name(&self) -> &str;
 }
 
 /// Indicates that a SQL type is supported for a database.
@@ -51,6 +70,26 @@ where
     }
 }
 
+impl<T: ?Sized, DB> Type<DB> for Box<T>
+where
+    DB: Database,
+    T: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <T as Type<DB>>::type_info()
+    }
+}
+
+impl<T: ?Sized, DB> Type<DB> for Arc<T>
+where
+    DB: Database,
+    T: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <T as Type<DB>>::type_info()
+    }
+}
+
 // For optional types in Rust, the underlying SQL type information
 // is equivalent
 impl<T, DB> Type<DB> for Option<T>
@@ -62,3 +101,32 @@ where
         <T as Type<DB>>::type_info()
     }
 }
+
+// Non-zero integers have the SQL type of the integer they wrap
+
+macro_rules! impl_type_for_non_zero {
+    ($($ty:ident($int:ty)),*) => {
+        $(
+            impl<DB> Type<DB> for $ty
+            where
+                DB: Database,
+                $int: Type<DB>,
+            {
+                fn type_info() -> DB::TypeInfo {
+                    <$int as Type<DB>>::type_info()
+                }
+            }
+        )*
+    };
+}
+
+impl_type_for_non_zero!(
+    NonZeroI8(i8),
+    NonZeroI16(i16),
+    NonZeroI32(i32),
+    NonZeroI64(i64),
+    NonZeroU8(u8),
+    NonZeroU16(u16),
+    NonZeroU32(u32),
+    NonZeroU64(u64)
+);
@@ -0,0 +1,61 @@
+//! Support for JSON values through [`serde_json`](https://crates.io/crates/serde_json).
+
+use std::ops::{Deref, DerefMut};
+
+use serde::{Deserialize, Serialize};
+
+pub use serde_json::Value as JsonValue;
+
+/// Wraps a value that is encoded to and decoded from the database as JSON.
+///
+/// Any type that implements [`Serialize`] can be bound as a `Json<T>` and any type that
+/// implements [`Deserialize`] can be read back out of a JSON column.
+///
+/// ```rust,ignore
+/// #[derive(serde::Deserialize)]
+/// struct Settings {
+///     theme: String,
+/// }
+///
+/// let settings: Json<Settings> = row.try_get("settings")?;
+/// ```
+///
+/// A SQL `NULL` and a JSON `null` are different values: `Option<Json<T>>` decodes a SQL `NULL`
+/// as `None` and a JSON `null` as `Some(Json(..))`, which requires a `T` that can be
+/// deserialized from `null` such as an `Option<U>` or a [`JsonValue`]. Likewise, binding `None`
+/// writes a SQL `NULL` while binding `Json(None::<U>)` writes a JSON `null`.
+///
+/// ```rust,ignore
+/// // SQL NULL → None, 'null'::jsonb → Some(Json(None)), '1'::jsonb → Some(Json(Some(1)))
+/// let value: Option<Json<Option<i32>>> = row.try_get("value")?;
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(transparent)]
+pub struct Json<T>(pub T);
+
+impl<T> Json<T> {
+    /// Consumes the `Json` wrapper and returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Json<T> {
+    fn from(value: T) -> Self {
+        Json(value)
+    }
+}
+
+impl<T> Deref for Json<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Json<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
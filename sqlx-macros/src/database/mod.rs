@@ -8,6 +8,9 @@ pub enum ParamChecking {
 }
 
 pub trait DatabaseExt: Database {
+    /// The name of the database as recorded in `sqlx-data.json`.
+    const NAME: &'static str;
+
     const DATABASE_PATH: &'static str;
     const ROW_PATH: &'static str;
 
@@ -35,9 +38,11 @@ macro_rules! impl_database_ext {
         },
         ParamChecking::$param_checking:ident,
         feature-types: $name:ident => $get_gate:expr,
-        row = $row:path
+        row = $row:path,
+        name = $db_name:literal
     ) => {
         impl $crate::database::DatabaseExt for $database {
+            const NAME: &'static str = $db_name;
             const DATABASE_PATH: &'static str = stringify!($database);
             const ROW_PATH: &'static str = stringify!($row);
             const PARAM_CHECKING: $crate::database::ParamChecking = $crate::database::ParamChecking::$param_checking;
@@ -8,9 +8,23 @@ impl_database_ext! {
         f32,
         f64,
 
+        // "CHAR"
+        u8,
+
+        // OID
+        u32,
+
         // BYTEA
         Vec<u8> | &[u8],
 
+        sqlx::postgres::types::PgRange<i32>,
+        sqlx::postgres::types::PgRange<i64>,
+
+        sqlx::postgres::types::PgXml,
+
+        // VOID
+        (),
+
         #[cfg(feature = "uuid")]
         sqlx::types::Uuid,
 
@@ -26,13 +40,50 @@ impl_database_ext! {
         #[cfg(feature = "chrono")]
         sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc> | sqlx::types::chrono::DateTime<_>,
 
+        #[cfg(feature = "chrono")]
+        sqlx::types::chrono::Duration,
+
+        #[cfg(feature = "chrono")]
+        sqlx::postgres::types::PgRange<sqlx::types::chrono::NaiveDate>,
+
+        #[cfg(feature = "chrono")]
+        sqlx::postgres::types::PgRange<sqlx::types::chrono::NaiveDateTime>,
+
+        #[cfg(feature = "chrono")]
+        sqlx::postgres::types::PgRange<sqlx::types::chrono::DateTime<sqlx::types::chrono::Utc>> | sqlx::postgres::types::PgRange<sqlx::types::chrono::DateTime<_>>,
+
         #[cfg(feature = "bigdecimal")]
         sqlx::types::BigDecimal,
 
+        #[cfg(feature = "bigdecimal")]
+        sqlx::postgres::types::PgRange<sqlx::types::BigDecimal>,
+
         #[cfg(feature = "ipnetwork")]
-        sqlx::types::ipnetwork::IpNetwork
+        sqlx::types::ipnetwork::IpNetwork,
+
+        #[cfg(feature = "json")]
+        sqlx::types::JsonValue,
+
+        #[cfg(feature = "geometry")]
+        sqlx::postgres::types::PgPoint,
+
+        #[cfg(feature = "geometry")]
+        sqlx::postgres::types::PgLine,
+
+        #[cfg(feature = "geometry")]
+        sqlx::postgres::types::PgLSeg,
+
+        #[cfg(feature = "geometry")]
+        sqlx::postgres::types::PgBox,
+
+        #[cfg(feature = "geometry")]
+        sqlx::postgres::types::PgPath,
+
+        #[cfg(feature = "geometry")]
+        sqlx::postgres::types::PgPolygon
     },
     ParamChecking::Strong,
     feature-types: info => info.type_feature_gate(),
-    row = sqlx::postgres::PgRow
+    row = sqlx::postgres::PgRow,
+    name = "PostgreSQL"
 }
@@ -31,10 +31,18 @@ pub enum RenameAll {
     LowerCase,
 }
 
+#[derive(Copy, Clone, PartialEq)]
+pub enum MySqlEnum {
+    Label,
+    Index,
+}
+
 pub struct SqlxContainerAttributes {
     pub transparent: bool,
     pub postgres_oid: Option<u32>,
+    pub postgres_array_oid: Option<u32>,
     pub rename_all: Option<RenameAll>,
+    pub mysql_enum: Option<MySqlEnum>,
     pub repr: Option<Ident>,
 }
 
@@ -45,8 +53,10 @@ pub struct SqlxChildAttributes {
 pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContainerAttributes> {
     let mut transparent = None;
     let mut postgres_oid = None;
+    let mut postgres_array_oid = None;
     let mut repr = None;
     let mut rename_all = None;
+    let mut mysql_enum = None;
 
     for attr in input {
         let meta = attr
@@ -75,6 +85,21 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
                                 try_set!(rename_all, val, value)
                             }
 
+                            Meta::NameValue(MetaNameValue {
+                                path,
+                                lit: Lit::Str(val),
+                                ..
+                            }) if path.is_ident("mysql_enum") => {
+                                let val = match &*val.value() {
+                                    "label" => MySqlEnum::Label,
+                                    "index" => MySqlEnum::Index,
+
+                                    _ => fail!(meta, "unexpected value for mysql_enum"),
+                                };
+
+                                try_set!(mysql_enum, val, value)
+                            }
+
                             Meta::List(list) if list.path.is_ident("postgres") => {
                                 for value in list.nested.iter() {
                                     match value {
@@ -85,6 +110,17 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
                                         })) if path.is_ident("oid") => {
                                             try_set!(postgres_oid, val.base10_parse()?, value);
                                         }
+                                        NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                                            path,
+                                            lit: Lit::Int(val),
+                                            ..
+                                        })) if path.is_ident("array_oid") => {
+                                            try_set!(
+                                                postgres_array_oid,
+                                                val.base10_parse()?,
+                                                value
+                                            );
+                                        }
                                         u => fail!(u, "unexpected value"),
                                     }
                                 }
@@ -114,8 +150,10 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
     Ok(SqlxContainerAttributes {
         transparent: transparent.unwrap_or(false),
         postgres_oid,
+        postgres_array_oid,
         repr,
         rename_all,
+        mysql_enum,
     })
 }
 
@@ -167,12 +205,26 @@ pub fn check_transparent_attributes(input: &DeriveInput, field: &Field) -> syn::
         input
     );
 
+    #[cfg(feature = "postgres")]
+    assert_attribute!(
+        attributes.postgres_array_oid.is_none(),
+        "unexpected #[sqlx(postgres(array_oid = ..))]",
+        input
+    );
+
     assert_attribute!(
         attributes.rename_all.is_none(),
         "unexpected #[sqlx(rename_all = ..)]",
         field
     );
 
+    #[cfg(feature = "mysql")]
+    assert_attribute!(
+        attributes.mysql_enum.is_none(),
+        "unexpected #[sqlx(mysql_enum = ..)]",
+        input
+    );
+
     assert_attribute!(attributes.repr.is_none(), "unexpected #[repr(..)]", input);
 
     let attributes = parse_child_attributes(&field.attrs)?;
@@ -211,8 +263,22 @@ pub fn check_weak_enum_attributes(
         input
     );
 
+    #[cfg(feature = "postgres")]
+    assert_attribute!(
+        attributes.postgres_array_oid.is_none(),
+        "unexpected #[sqlx(postgres(array_oid = ..))]",
+        input
+    );
+
     assert_attribute!(attributes.repr.is_some(), "expected #[repr(..)]", input);
 
+    #[cfg(feature = "mysql")]
+    assert_attribute!(
+        attributes.mysql_enum.is_none(),
+        "unexpected #[sqlx(mysql_enum = ..)]",
+        input
+    );
+
     assert_attribute!(
         attributes.rename_all.is_none(),
         "unexpected #[sqlx(c = ..)]",
@@ -275,6 +341,13 @@ pub fn check_struct_attributes<'a>(
         input
     );
 
+    #[cfg(feature = "mysql")]
+    assert_attribute!(
+        attributes.mysql_enum.is_none(),
+        "unexpected #[sqlx(mysql_enum = ..)]",
+        input
+    );
+
     assert_attribute!(attributes.repr.is_none(), "unexpected #[repr(..)]", input);
 
     for field in fields {
@@ -289,3 +362,38 @@ pub fn check_struct_attributes<'a>(
 
     Ok(attributes)
 }
+
+pub fn check_row_attributes(input: &DeriveInput) -> syn::Result<SqlxContainerAttributes> {
+    let attributes = parse_container_attributes(&input.attrs)?;
+
+    assert_attribute!(
+        !attributes.transparent,
+        "unexpected #[sqlx(transparent)]",
+        input
+    );
+
+    #[cfg(feature = "postgres")]
+    assert_attribute!(
+        attributes.postgres_oid.is_none(),
+        "unexpected #[sqlx(postgres(oid = ..))]",
+        input
+    );
+
+    #[cfg(feature = "postgres")]
+    assert_attribute!(
+        attributes.postgres_array_oid.is_none(),
+        "unexpected #[sqlx(postgres(array_oid = ..))]",
+        input
+    );
+
+    #[cfg(feature = "mysql")]
+    assert_attribute!(
+        attributes.mysql_enum.is_none(),
+        "unexpected #[sqlx(mysql_enum = ..)]",
+        input
+    );
+
+    assert_attribute!(attributes.repr.is_none(), "unexpected #[repr(..)]", input);
+
+    Ok(attributes)
+}
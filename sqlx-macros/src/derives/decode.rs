@@ -1,6 +1,6 @@
 use super::attributes::{
     check_strong_enum_attributes, check_struct_attributes, check_transparent_attributes,
-    check_weak_enum_attributes, parse_child_attributes, parse_container_attributes,
+    check_weak_enum_attributes, parse_child_attributes, parse_container_attributes, MySqlEnum,
 };
 use super::rename_all;
 use quote::quote;
@@ -8,7 +8,7 @@ use syn::punctuated::Punctuated;
 use syn::token::Comma;
 use syn::{
     parse_quote, Arm, Data, DataEnum, DataStruct, DeriveInput, Field, Fields, FieldsNamed,
-    FieldsUnnamed, Stmt, Variant,
+    FieldsUnnamed, Ident, Stmt, Variant,
 };
 
 pub fn expand_derive_decode(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
@@ -122,34 +122,109 @@ fn expand_derive_decode_strong_enum(
     let ident = &input.ident;
     let ident_s = ident.to_string();
 
-    let value_arms = variants.iter().map(|v| -> Arm {
-        let id = &v.ident;
-        let attributes = parse_child_attributes(&v.attrs).unwrap();
+    let labels: Vec<(&Ident, String)> = variants
+        .iter()
+        .map(|v| {
+            let attributes = parse_child_attributes(&v.attrs).unwrap();
 
-        if let Some(rename) = attributes.rename {
-            parse_quote!(#rename => Ok(#ident :: #id),)
-        } else if let Some(pattern) = cattr.rename_all {
-            let name = rename_all(&*id.to_string(), pattern);
+            let label = if let Some(rename) = attributes.rename {
+                rename
+            } else if let Some(pattern) = cattr.rename_all {
+                rename_all(&*v.ident.to_string(), pattern)
+            } else {
+                v.ident.to_string()
+            };
 
-            parse_quote!(#name => Ok(#ident :: #id),)
-        } else {
-            let name = id.to_string();
-            parse_quote!(#name => Ok(#ident :: #id),)
-        }
+            (&v.ident, label)
+        })
+        .collect();
+
+    let value_arms: Vec<Arm> = labels
+        .iter()
+        .map(|(id, label)| parse_quote!(#label => Ok(#ident :: #id),))
+        .collect();
+
+    if !cfg!(feature = "mysql") || cattr.mysql_enum != Some(MySqlEnum::Index) {
+        return Ok(quote!(
+            impl<'de, DB: sqlx::Database> sqlx::decode::Decode<'de, DB> for #ident where &'de str: sqlx::decode::Decode<'de, DB> {
+                fn decode(value: <DB as sqlx::database::HasRawValue<'de>>::RawValue) -> sqlx::Result<Self> {
+                    let value = <&'de str as sqlx::decode::Decode<'de, DB>>::decode(value)?;
+                    match value {
+                        #(#value_arms)*
+
+                        _ => Err(sqlx::Error::Decode(format!("invalid value {:?} for enum {}", value, #ident_s).into()))
+                    }
+                }
+            }
+        ));
+    }
+
+    // MySQL returns ENUM columns by their label, but the 1-based index of the variant is
+    // accepted as well, so the label is decoded for each of the other databases separately
+    let mut tts = proc_macro2::TokenStream::new();
+
+    let mut databases = Vec::new();
+
+    if cfg!(feature = "postgres") {
+        databases.push(quote!(sqlx::Postgres));
+    }
+
+    if cfg!(feature = "sqlite") {
+        databases.push(quote!(sqlx::Sqlite));
+    }
+
+    for db in databases {
+        tts.extend(quote!(
+            impl<'de> sqlx::decode::Decode<'de, #db> for #ident {
+                fn decode(value: <#db as sqlx::database::HasRawValue<'de>>::RawValue) -> sqlx::Result<Self> {
+                    let value = <&'de str as sqlx::decode::Decode<'de, #db>>::decode(value)?;
+                    match value {
+                        #(#value_arms)*
+
+                        _ => Err(sqlx::Error::Decode(format!("invalid value {:?} for enum {}", value, #ident_s).into()))
+                    }
+                }
+            }
+        ));
+    }
+
+    let label_arms = labels
+        .iter()
+        .map(|(id, label)| -> Arm { parse_quote!(#label => return Ok(#ident :: #id),) });
+
+    let index_arms = variants.iter().enumerate().map(|(i, v)| -> Arm {
+        let id = &v.ident;
+        let index = (i + 1) as u16;
+
+        parse_quote!(#index => Ok(#ident :: #id),)
     });
 
-    Ok(quote!(
-        impl<'de, DB: sqlx::Database> sqlx::decode::Decode<'de, DB> for #ident where &'de str: sqlx::decode::Decode<'de, DB> {
-            fn decode(value: <DB as sqlx::database::HasRawValue<'de>>::RawValue) -> sqlx::Result<Self> {
-                let value = <&'de str as sqlx::decode::Decode<'de, DB>>::decode(value)?;
-                match value {
-                    #(#value_arms)*
+    tts.extend(quote!(
+        impl<'de> sqlx::decode::Decode<'de, sqlx::MySql> for #ident {
+            fn decode(value: Option<sqlx::mysql::MySqlValue<'de>>) -> sqlx::Result<Self> {
+                let value = value.ok_or_else(|| sqlx::Error::Decode(sqlx::error::UnexpectedNullError.into()))?;
 
-                    _ => Err(sqlx::Error::Decode(format!("invalid value {:?} for enum {}", value, #ident_s).into()))
+                // the label, as ENUM columns are returned
+                if let Ok(label) = <&'de str as sqlx::decode::Decode<'de, sqlx::MySql>>::decode(Some(value)) {
+                    match label {
+                        #(#label_arms)*
+
+                        _ => {}
+                    }
+                }
+
+                let index = value.enum_index()?;
+
+                match index {
+                    #(#index_arms)*
+
+                    _ => Err(sqlx::Error::Decode(format!("invalid index {} for enum {}", index, #ident_s).into()))
                 }
             }
         }
-    ))
+    ));
+
+    Ok(tts)
 }
 
 fn expand_derive_decode_struct(
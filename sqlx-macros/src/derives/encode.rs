@@ -1,6 +1,6 @@
 use super::attributes::{
     check_strong_enum_attributes, check_struct_attributes, check_transparent_attributes,
-    check_weak_enum_attributes, parse_child_attributes, parse_container_attributes,
+    check_weak_enum_attributes, parse_child_attributes, parse_container_attributes, MySqlEnum,
 };
 use super::rename_all;
 use quote::quote;
@@ -80,6 +80,9 @@ fn expand_derive_encode_transparent(
             fn size_hint(&self) -> usize {
                 sqlx::encode::Encode::size_hint(&self.0)
             }
+            fn check_encode(&self) -> sqlx::Result<()> {
+                sqlx::encode::Encode::check_encode(&self.0)
+            }
         }
     ))
 }
@@ -135,23 +138,83 @@ fn expand_derive_encode_strong_enum(
         }
     }
 
-    Ok(quote!(
-        impl<DB: sqlx::Database> sqlx::encode::Encode<DB> for #ident where str: sqlx::encode::Encode<DB> {
-            fn encode(&self, buf: &mut DB::RawBuffer) {
-                let val = match self {
-                    #(#value_arms)*
+    if !cfg!(feature = "mysql") || cattr.mysql_enum != Some(MySqlEnum::Index) {
+        return Ok(quote!(
+            impl<DB: sqlx::Database> sqlx::encode::Encode<DB> for #ident where str: sqlx::encode::Encode<DB> {
+                fn encode(&self, buf: &mut DB::RawBuffer) {
+                    let val = match self {
+                        #(#value_arms)*
+                    };
+                    <str as sqlx::encode::Encode<DB>>::encode(val, buf)
+                }
+
+                fn size_hint(&self) -> usize {
+                    let val = match self {
+                        #(#value_arms)*
+                    };
+                    <str as sqlx::encode::Encode<DB>>::size_hint(val)
+                }
+            }
+        ));
+    }
+
+    // MySQL binds the 1-based index of the variant, so the label is encoded for each of the
+    // other databases separately
+    let mut tts = proc_macro2::TokenStream::new();
+
+    let mut databases = Vec::new();
+
+    if cfg!(feature = "postgres") {
+        databases.push(quote!(sqlx::Postgres));
+    }
+
+    if cfg!(feature = "sqlite") {
+        databases.push(quote!(sqlx::Sqlite));
+    }
+
+    for db in databases {
+        tts.extend(quote!(
+            impl sqlx::encode::Encode<#db> for #ident {
+                fn encode(&self, buf: &mut <#db as sqlx::Database>::RawBuffer) {
+                    let val = match self {
+                        #(#value_arms)*
+                    };
+                    <str as sqlx::encode::Encode<#db>>::encode(val, buf)
+                }
+
+                fn size_hint(&self) -> usize {
+                    let val = match self {
+                        #(#value_arms)*
+                    };
+                    <str as sqlx::encode::Encode<#db>>::size_hint(val)
+                }
+            }
+        ));
+    }
+
+    let index_arms = variants.iter().enumerate().map(|(i, v)| {
+        let id = &v.ident;
+        let index = (i + 1) as u16;
+
+        quote!(#ident :: #id => #index,)
+    });
+
+    tts.extend(quote!(
+        impl sqlx::encode::Encode<sqlx::MySql> for #ident {
+            fn encode(&self, buf: &mut std::vec::Vec<u8>) {
+                let index: u16 = match self {
+                    #(#index_arms)*
                 };
-                <str as sqlx::encode::Encode<DB>>::encode(val, buf)
+                <u16 as sqlx::encode::Encode<sqlx::MySql>>::encode(&index, buf)
             }
 
             fn size_hint(&self) -> usize {
-                let val = match self {
-                    #(#value_arms)*
-                };
-                <str as sqlx::encode::Encode<DB>>::size_hint(val)
+                std::mem::size_of::<u16>()
             }
         }
-    ))
+    ));
+
+    Ok(tts)
 }
 
 fn expand_derive_encode_struct(
@@ -201,6 +264,15 @@ fn expand_derive_encode_struct(
             )
         });
 
+        let checks = fields.iter().map(|field| -> Stmt {
+            let id = &field.ident;
+            let ty = &field.ty;
+
+            parse_quote!(
+                <#ty as sqlx::encode::Encode<sqlx::Postgres>>::check_encode(&self. #id)?;
+            )
+        });
+
         tts.extend(quote!(
             impl #impl_generics sqlx::encode::Encode<sqlx::Postgres> for #ident #ty_generics #where_clause {
                 fn encode(&self, buf: &mut std::vec::Vec<u8>) {
@@ -215,6 +287,12 @@ fn expand_derive_encode_struct(
                     #column_count * (4 + 4) // oid (int) and length (int) for each column
                         + #(#sizes)+* // sum of the size hints for each column
                 }
+
+                fn check_encode(&self) -> sqlx::Result<()> {
+                    #(#checks)*
+
+                    Ok(())
+                }
             }
         ));
     }
@@ -0,0 +1,115 @@
+use super::attributes::{check_row_attributes, parse_child_attributes};
+use super::rename_all;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{
+    parse_quote, Data, DataStruct, DeriveInput, Field, FieldValue, Fields, FieldsNamed, Lifetime,
+};
+
+pub fn expand_derive_from_row(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    match &input.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(FieldsNamed { named, .. }),
+            ..
+        }) => expand_derive_from_row_struct(input, named),
+        Data::Struct(DataStruct {
+            fields: Fields::Unnamed(..),
+            ..
+        }) => Err(syn::Error::new_spanned(
+            input,
+            "tuple structs are not supported",
+        )),
+        Data::Struct(DataStruct {
+            fields: Fields::Unit,
+            ..
+        }) => Err(syn::Error::new_spanned(
+            input,
+            "unit structs are not supported",
+        )),
+        Data::Enum(_) => Err(syn::Error::new_spanned(input, "enums are not supported")),
+        Data::Union(_) => Err(syn::Error::new_spanned(input, "unions are not supported")),
+    }
+}
+
+fn expand_derive_from_row_struct(
+    input: &DeriveInput,
+    fields: &Punctuated<Field, Comma>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let attributes = check_row_attributes(input)?;
+
+    let ident = &input.ident;
+
+    let generics = &input.generics;
+    let (_, ty_generics, _) = generics.split_for_impl();
+
+    let lifetime = Lifetime::new("'c", proc_macro2::Span::call_site());
+
+    let mut generics = generics.clone();
+    generics
+        .params
+        .insert(0, parse_quote!(R: sqlx::Row<#lifetime>));
+    generics.params.insert(0, parse_quote!(#lifetime));
+
+    let predicates = &mut generics.make_where_clause().predicates;
+    predicates.push(parse_quote!(&#lifetime str: sqlx::row::ColumnIndex<R::Database>));
+
+    for field in fields {
+        let ty = &field.ty;
+
+        predicates.push(parse_quote!(#ty: sqlx::types::Type<R::Database>));
+        predicates.push(parse_quote!(#ty: for<'r> sqlx::decode::Decode<'r, R::Database>));
+    }
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let mut reads: Vec<FieldValue> = Vec::with_capacity(fields.len());
+
+    for field in fields {
+        let id = &field.ident;
+        let ty = &field.ty;
+
+        // the column is named by #[sqlx(rename = ..)] on the field if present,
+        // otherwise by the field name under the container's #[sqlx(rename_all = ..)]
+        let column = match parse_child_attributes(&field.attrs)?.rename {
+            Some(rename) => rename,
+            None => {
+                let name = id.as_ref().unwrap().to_string();
+
+                match attributes.rename_all {
+                    Some(pattern) => rename_all(&name, pattern),
+                    None => name,
+                }
+            }
+        };
+
+        reads.push(parse_quote!(
+            #id: sqlx::Row::try_get::<#ty, _>(&row, #column)?
+        ));
+    }
+
+    let expected = fields.len();
+
+    // columns are read by name so any extra columns are ignored, but a row
+    // with fewer columns than there are fields cannot have all of them
+    let tts = quote!(
+        impl #impl_generics sqlx::row::FromRow<#lifetime, R> for #ident #ty_generics #where_clause {
+            fn from_row(row: R) -> sqlx::Result<Self> {
+                let found = sqlx::Row::len(&row);
+
+                if found < #expected {
+                    return Err(sqlx::Error::ColumnCountMismatch {
+                        expected: #expected,
+                        found,
+                    });
+                }
+
+                Ok(#ident {
+                    #(#reads),*
+                })
+            }
+        }
+    );
+
+    Ok(tts)
+}
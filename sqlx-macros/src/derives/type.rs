@@ -1,6 +1,6 @@
 use super::attributes::{
     check_strong_enum_attributes, check_struct_attributes, check_transparent_attributes,
-    check_weak_enum_attributes, parse_container_attributes,
+    check_weak_enum_attributes, parse_container_attributes, MySqlEnum,
 };
 use quote::quote;
 use syn::punctuated::Punctuated;
@@ -106,7 +106,16 @@ fn expand_derive_has_sql_type_strong_enum(
     let ident = &input.ident;
     let mut tts = proc_macro2::TokenStream::new();
 
-    if cfg!(feature = "mysql") {
+    if cfg!(feature = "mysql") && attributes.mysql_enum == Some(MySqlEnum::Index) {
+        tts.extend(quote!(
+            impl sqlx::Type< sqlx::MySql > for #ident {
+                fn type_info() -> sqlx::mysql::MySqlTypeInfo {
+                    // The value is bound as the 1-based index of the variant
+                    <u16 as sqlx::Type<sqlx::MySql>>::type_info()
+                }
+            }
+        ));
+    } else if cfg!(feature = "mysql") {
         tts.extend(quote!(
             impl sqlx::Type< sqlx::MySql > for #ident {
                 fn type_info() -> sqlx::mysql::MySqlTypeInfo {
@@ -127,6 +136,16 @@ fn expand_derive_has_sql_type_strong_enum(
                 }
             }
         ));
+
+        if let Some(array_oid) = attributes.postgres_array_oid {
+            tts.extend(quote!(
+                impl sqlx::postgres::types::HasArrayType for #ident {
+                    fn array_type_info() -> sqlx::postgres::PgTypeInfo {
+                        sqlx::postgres::PgTypeInfo::with_oid(#array_oid)
+                    }
+                }
+            ));
+        }
     }
 
     Ok(tts)
@@ -150,6 +169,16 @@ fn expand_derive_has_sql_type_struct(
                 }
             }
         ));
+
+        if let Some(array_oid) = attributes.postgres_array_oid {
+            tts.extend(quote!(
+                impl sqlx::postgres::types::HasArrayType for #ident {
+                    fn array_type_info() -> sqlx::postgres::PgTypeInfo {
+                        sqlx::postgres::PgTypeInfo::with_oid(#array_oid)
+                    }
+                }
+            ));
+        }
     }
 
     Ok(tts)
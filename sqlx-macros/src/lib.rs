@@ -4,6 +4,8 @@
 )]
 extern crate proc_macro;
 
+use std::sync::{Mutex, PoisonError};
+
 use proc_macro::TokenStream;
 
 use quote::quote;
@@ -42,6 +44,37 @@ fn block_on<F: std::future::Future>(future: F) -> F::Output {
     BASIC_RUNTIME.enter(|| futures::executor::block_on(future))
 }
 
+// The compiler loads this crate once and expands every query macro of a crate in the same
+// process so we keep the connection around instead of reconnecting for each invocation
+lazy_static::lazy_static! {
+    static ref CONNECTION_CACHE: ConnectionCache = ConnectionCache::default();
+}
+
+#[derive(Default)]
+struct ConnectionCache {
+    #[cfg(feature = "sqlite")]
+    sqlite: Mutex<Option<sqlx::sqlite::SqliteConnection>>,
+    #[cfg(feature = "postgres")]
+    postgres: Mutex<Option<sqlx::postgres::PgConnection>>,
+    #[cfg(feature = "mysql")]
+    mysql: Mutex<Option<sqlx::mysql::MySqlConnection>>,
+}
+
+async fn connect_cached<'c, C>(cached: &'c mut Option<C>, url: &Url) -> Result<&'c mut C>
+where
+    C: sqlx::connection::Connect,
+{
+    if cached.is_none() {
+        let conn = C::connect(url.as_str())
+            .await
+            .map_err(|e| format!("failed to connect to database: {}", e))?;
+
+        *cached = Some(conn);
+    }
+
+    Ok(cached.as_mut().unwrap())
+}
+
 fn macro_result(tokens: proc_macro2::TokenStream) -> TokenStream {
     quote!(
         macro_rules! macro_result {
@@ -51,6 +84,20 @@ fn macro_result(tokens: proc_macro2::TokenStream) -> TokenStream {
     .into()
 }
 
+// Drops the cached connection if the expansion failed as it may have been left in an
+// unknown state
+macro_rules! expand_cached (
+    ($cached:ident, $expr:expr) => {{
+        let res = $expr.await;
+
+        if res.is_err() {
+            *$cached = None;
+        }
+
+        res
+    }}
+);
+
 macro_rules! async_macro (
     ($db:ident, $input:ident: $ty:ty => $expr:expr) => {{
         let $input = match syn::parse::<$ty>($input) {
@@ -59,18 +106,51 @@ macro_rules! async_macro (
         };
 
         let res: Result<proc_macro2::TokenStream> = block_on(async {
-            use sqlx::connection::Connect;
+            #[cfg(feature = "offline")]
+            {
+                if query_macros::offline::is_offline() {
+                    let data = query_macros::offline::DataFile::load()?;
+
+                    return match &*data.db {
+                        #[cfg(feature = "sqlite")]
+                        "SQLite" => {
+                            let $db = &mut data.into_offline::<sqlx::sqlite::Sqlite>();
+
+                            $expr.await
+                        }
+                        #[cfg(feature = "postgres")]
+                        "PostgreSQL" => {
+                            let $db = &mut data.into_offline::<sqlx::postgres::Postgres>();
+
+                            $expr.await
+                        }
+                        #[cfg(feature = "mysql")]
+                        "MySQL" => {
+                            let $db = &mut data.into_offline::<sqlx::mysql::MySql>();
+
+                            $expr.await
+                        }
+                        db => Err(format!(
+                            "sqlx-data.json has queries for {} but the corresponding feature \
+                             of sqlx was not enabled",
+                             db
+                        ).into()),
+                    };
+                }
+            }
 
             let db_url = Url::parse(&dotenv::var("DATABASE_URL").map_err(|_| "DATABASE_URL not set")?)?;
 
             match db_url.scheme() {
                 #[cfg(feature = "sqlite")]
                 "sqlite" => {
-                    let $db = sqlx::sqlite::SqliteConnection::connect(db_url.as_str())
-                        .await
-                        .map_err(|e| format!("failed to connect to database: {}", e))?;
+                    let mut cached = CONNECTION_CACHE.sqlite.lock().unwrap_or_else(PoisonError::into_inner);
+                    let $db = connect_cached(&mut cached, &db_url).await?;
+
+                    #[cfg(feature = "offline")]
+                    let $db = &mut query_macros::offline::Prepare::new($db);
 
-                    $expr.await
+                    expand_cached!(cached, $expr)
                 }
                 #[cfg(not(feature = "sqlite"))]
                 "sqlite" => Err(format!(
@@ -80,11 +160,13 @@ macro_rules! async_macro (
                 ).into()),
                 #[cfg(feature = "postgres")]
                 "postgresql" | "postgres" => {
-                    let $db = sqlx::postgres::PgConnection::connect(db_url.as_str())
-                        .await
-                        .map_err(|e| format!("failed to connect to database: {}", e))?;
+                    let mut cached = CONNECTION_CACHE.postgres.lock().unwrap_or_else(PoisonError::into_inner);
+                    let $db = connect_cached(&mut cached, &db_url).await?;
 
-                    $expr.await
+                    #[cfg(feature = "offline")]
+                    let $db = &mut query_macros::offline::Prepare::new($db);
+
+                    expand_cached!(cached, $expr)
                 }
                 #[cfg(not(feature = "postgres"))]
                 "postgresql" | "postgres" => Err(format!(
@@ -94,11 +176,13 @@ macro_rules! async_macro (
                 ).into()),
                 #[cfg(feature = "mysql")]
                 "mysql" | "mariadb" => {
-                    let $db = sqlx::mysql::MySqlConnection::connect(db_url.as_str())
-                            .await
-                            .map_err(|e| format!("failed to connect to database: {}", e))?;
+                    let mut cached = CONNECTION_CACHE.mysql.lock().unwrap_or_else(PoisonError::into_inner);
+                    let $db = connect_cached(&mut cached, &db_url).await?;
+
+                    #[cfg(feature = "offline")]
+                    let $db = &mut query_macros::offline::Prepare::new($db);
 
-                    $expr.await
+                    expand_cached!(cached, $expr)
                 }
                 #[cfg(not(feature = "mysql"))]
                 "mysql" | "mariadb" => Err(format!(
@@ -169,6 +253,15 @@ pub fn derive_decode(tokenstream: TokenStream) -> TokenStream {
     }
 }
 
+#[proc_macro_derive(FromRow, attributes(sqlx))]
+pub fn derive_from_row(tokenstream: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(tokenstream as syn::DeriveInput);
+    match derives::expand_derive_from_row(&input) {
+        Ok(ts) => ts.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
 #[proc_macro_derive(Type, attributes(sqlx))]
 pub fn derive_type(tokenstream: TokenStream) -> TokenStream {
     let input = syn::parse_macro_input!(tokenstream as syn::DeriveInput);
@@ -0,0 +1,31 @@
+use futures::future::LocalBoxFuture;
+
+use sqlx::connection::Connection;
+use sqlx::database::Database;
+use sqlx::describe::Describe;
+use sqlx::executor::Executor;
+
+/// A source of query descriptions for the macros; either a live connection or, in offline
+/// mode, the data recorded in `sqlx-data.json`.
+pub trait DescribeQuery {
+    type Database: Database;
+
+    fn describe_query<'e>(
+        &'e mut self,
+        query: &'e str,
+    ) -> LocalBoxFuture<'e, crate::Result<Describe<Self::Database>>>;
+}
+
+impl<C> DescribeQuery for C
+where
+    C: Connection,
+{
+    type Database = C::Database;
+
+    fn describe_query<'e>(
+        &'e mut self,
+        query: &'e str,
+    ) -> LocalBoxFuture<'e, crate::Result<Describe<Self::Database>>> {
+        Box::pin(async move { Ok(Executor::describe(self, query).await?) })
+    }
+}
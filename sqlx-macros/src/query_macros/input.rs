@@ -9,10 +9,11 @@ use syn::token::Group;
 use syn::{Expr, ExprLit, ExprPath, Lit};
 use syn::{ExprGroup, Token};
 
-use sqlx::connection::Connection;
 use sqlx::describe::Describe;
 use sqlx::runtime::fs;
 
+use super::DescribeQuery;
+
 /// Macro input shared by `query!()` and `query_file!()`
 pub struct QueryMacroInput {
     pub(super) source: String,
@@ -77,12 +78,12 @@ impl QueryMacroInput {
 
     /// Run a parse/describe on the query described by this input and validate that it matches the
     /// passed number of args
-    pub async fn describe_validate<C: Connection>(
+    pub async fn describe_validate<D: DescribeQuery>(
         &self,
-        conn: &mut C,
-    ) -> crate::Result<Describe<C::Database>> {
-        let describe = conn
-            .describe(&*self.source)
+        db: &mut D,
+    ) -> crate::Result<Describe<D::Database>> {
+        let describe = db
+            .describe_query(&self.source)
             .await
             .map_err(|e| syn::Error::new(self.source_span, e))?;
 
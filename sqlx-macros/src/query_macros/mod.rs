@@ -3,39 +3,43 @@ use std::fmt::Display;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
+pub use describe::DescribeQuery;
 pub use input::{QueryAsMacroInput, QueryMacroInput};
 pub use query::expand_query;
 
 use crate::database::DatabaseExt;
 
-use sqlx::connection::Connection;
 use sqlx::database::Database;
 
 mod args;
+mod describe;
 mod input;
 mod output;
 mod query;
 
-pub async fn expand_query_file<C: Connection>(
+#[cfg(feature = "offline")]
+pub mod offline;
+
+pub async fn expand_query_file<D: DescribeQuery>(
     input: QueryMacroInput,
-    conn: C,
+    db: &mut D,
 ) -> crate::Result<TokenStream>
 where
-    C::Database: DatabaseExt + Sized,
-    <C::Database as Database>::TypeInfo: Display,
+    D::Database: DatabaseExt + Sized,
+    <D::Database as Database>::TypeInfo: Display,
 {
-    expand_query(input.expand_file_src().await?, conn).await
+    expand_query(input.expand_file_src().await?, db).await
 }
 
-pub async fn expand_query_as<C: Connection>(
+pub async fn expand_query_as<D: DescribeQuery>(
     input: QueryAsMacroInput,
-    mut conn: C,
+    db: &mut D,
 ) -> crate::Result<TokenStream>
 where
-    C::Database: DatabaseExt + Sized,
-    <C::Database as Database>::TypeInfo: Display,
+    D::Database: DatabaseExt + Sized,
+    <D::Database as Database>::TypeInfo: Display,
 {
-    let describe = input.query_input.describe_validate(&mut conn).await?;
+    let describe = input.query_input.describe_validate(db).await?;
 
     if describe.result_columns.is_empty() {
         return Err(syn::Error::new(
@@ -50,7 +54,7 @@ where
     let query_args = format_ident!("query_args");
 
     let columns = output::columns_to_rust(&describe)?;
-    let output = output::quote_query_as::<C::Database>(
+    let output = output::quote_query_as::<D::Database>(
         &input.query_input.source,
         &input.as_ty.path,
         &query_args,
@@ -72,13 +76,13 @@ where
     })
 }
 
-pub async fn expand_query_file_as<C: Connection>(
+pub async fn expand_query_file_as<D: DescribeQuery>(
     input: QueryAsMacroInput,
-    conn: C,
+    db: &mut D,
 ) -> crate::Result<TokenStream>
 where
-    C::Database: DatabaseExt + Sized,
-    <C::Database as Database>::TypeInfo: Display,
+    D::Database: DatabaseExt + Sized,
+    <D::Database as Database>::TypeInfo: Display,
 {
-    expand_query_as(input.expand_file_src().await?, conn).await
+    expand_query_as(input.expand_file_src().await?, db).await
 }
@@ -0,0 +1,266 @@
+//! Offline mode for the query macros.
+//!
+//! With `SQLX_OFFLINE=true` the macros describe queries from `sqlx-data.json` in the root
+//! of the crate being compiled instead of connecting to `DATABASE_URL`. Building with
+//! `SQLX_PREPARE=true` and a live `DATABASE_URL` records every query the macros describe
+//! in that file.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use futures::future::LocalBoxFuture;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use sqlx::connection::Connection;
+use sqlx::database::Database;
+use sqlx::describe::Describe;
+
+use super::DescribeQuery;
+use crate::database::DatabaseExt;
+
+const DATA_FILE: &str = "sqlx-data.json";
+
+/// Returns `true` if queries should be described from `sqlx-data.json`.
+pub fn is_offline() -> bool {
+    env_flag("SQLX_OFFLINE")
+}
+
+fn env_flag(name: &str) -> bool {
+    dotenv::var(name).map_or(false, |value| ["true", "1"].contains(&&*value))
+}
+
+fn hash_query(query: &str) -> String {
+    hex::encode(Sha256::digest(query.as_bytes()))
+}
+
+/// The contents of `sqlx-data.json`: the database the queries were described against
+/// and the description of each query, keyed by the SHA-256 hash of its source.
+#[derive(Serialize, Deserialize)]
+pub struct DataFile {
+    pub db: String,
+    #[serde(flatten)]
+    queries: BTreeMap<String, QueryData>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct QueryData {
+    query: String,
+    describe: serde_json::Value,
+}
+
+impl DataFile {
+    fn path() -> crate::Result<PathBuf> {
+        let dir = env::var("CARGO_MANIFEST_DIR")
+            .map_err(|_| "CARGO_MANIFEST_DIR is not set; please use Cargo to build")?;
+
+        Ok(Path::new(&dir).join(DATA_FILE))
+    }
+
+    pub fn load() -> crate::Result<Self> {
+        let path = Self::path()?;
+
+        let data = fs::read_to_string(&path).map_err(|e| {
+            format!(
+                "failed to read {}: {}; build once with SQLX_PREPARE=true and \
+                 DATABASE_URL set to create it",
+                path.display(),
+                e
+            )
+        })?;
+
+        Self::parse(&data)
+    }
+
+    fn parse(data: &str) -> crate::Result<Self> {
+        serde_json::from_str(data)
+            .map_err(|e| format!("failed to parse {}: {}", DATA_FILE, e).into())
+    }
+
+    pub fn into_offline<DB>(self) -> OfflineData<DB> {
+        OfflineData {
+            queries: self.queries,
+            database: PhantomData,
+        }
+    }
+}
+
+/// Describes queries from the data recorded in `sqlx-data.json`.
+pub struct OfflineData<DB> {
+    queries: BTreeMap<String, QueryData>,
+    database: PhantomData<DB>,
+}
+
+impl<DB> DescribeQuery for OfflineData<DB>
+where
+    DB: Database,
+    Describe<DB>: DeserializeOwned,
+{
+    type Database = DB;
+
+    fn describe_query<'e>(
+        &'e mut self,
+        query: &'e str,
+    ) -> LocalBoxFuture<'e, crate::Result<Describe<DB>>> {
+        Box::pin(async move {
+            let data = self.queries.remove(&hash_query(query)).ok_or_else(|| {
+                format!(
+                    "query not found in {}; build with SQLX_PREPARE=true and \
+                     DATABASE_URL set to update it",
+                    DATA_FILE
+                )
+            })?;
+
+            Ok(serde_json::from_value(data.describe)?)
+        })
+    }
+}
+
+/// Describes queries with a live connection and, with `SQLX_PREPARE=true`, records the
+/// descriptions in `sqlx-data.json`.
+pub struct Prepare<'c, C> {
+    conn: &'c mut C,
+    enabled: bool,
+}
+
+impl<'c, C> Prepare<'c, C> {
+    pub fn new(conn: &'c mut C) -> Self {
+        Self {
+            conn,
+            enabled: env_flag("SQLX_PREPARE"),
+        }
+    }
+}
+
+impl<C> DescribeQuery for Prepare<'_, C>
+where
+    C: Connection,
+    C::Database: DatabaseExt,
+    Describe<C::Database>: Serialize,
+{
+    type Database = C::Database;
+
+    fn describe_query<'e>(
+        &'e mut self,
+        query: &'e str,
+    ) -> LocalBoxFuture<'e, crate::Result<Describe<C::Database>>> {
+        Box::pin(async move {
+            let describe = self.conn.describe_query(query).await?;
+
+            if self.enabled {
+                save::<C::Database>(query, &describe)?;
+            }
+
+            Ok(describe)
+        })
+    }
+}
+
+fn save<DB>(query: &str, describe: &Describe<DB>) -> crate::Result<()>
+where
+    DB: DatabaseExt,
+    Describe<DB>: Serialize,
+{
+    let path = DataFile::path()?;
+    let _lock = FileLock::acquire(path.with_extension("json.lock"))?;
+
+    let mut data = match fs::read_to_string(&path) {
+        Ok(data) => DataFile::parse(&data)?,
+
+        Err(e) if e.kind() == io::ErrorKind::NotFound => DataFile {
+            db: DB::NAME.into(),
+            queries: BTreeMap::new(),
+        },
+
+        Err(e) => return Err(e.into()),
+    };
+
+    if data.db != DB::NAME {
+        return Err(format!(
+            "{} has queries for {} but DATABASE_URL is a {} database",
+            DATA_FILE,
+            data.db,
+            DB::NAME
+        )
+        .into());
+    }
+
+    data.queries.insert(
+        hash_query(query),
+        QueryData {
+            query: query.into(),
+            describe: serde_json::to_value(describe)?,
+        },
+    );
+
+    fs::write(&path, serde_json::to_string_pretty(&data)?)?;
+
+    Ok(())
+}
+
+// The crates of a workspace (and the tests of a crate) are expanded by separate compiler
+// processes, possibly at the same time, so the data file is only read and written while
+// holding a lock file
+struct FileLock(PathBuf);
+
+impl FileLock {
+    fn acquire(path: PathBuf) -> crate::Result<Self> {
+        let deadline = Instant::now() + Duration::from_secs(10);
+
+        loop {
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(_) => return Ok(FileLock(path)),
+
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists && Instant::now() < deadline => {
+                    thread::sleep(Duration::from_millis(10));
+                }
+
+                Err(e) => {
+                    return Err(format!("failed to lock {}: {}", path.display(), e).into());
+                }
+            }
+        }
+    }
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn test_expand_query_from_data_file() -> crate::Result<()> {
+    use futures::executor::block_on;
+
+    use super::{expand_query, QueryMacroInput};
+
+    let data = DataFile::parse(include_str!("../../tests/fixtures/sqlx-data.json"))?;
+    assert_eq!(data.db, "PostgreSQL");
+
+    let mut db = data.into_offline::<sqlx::postgres::Postgres>();
+
+    let input: QueryMacroInput = syn::parse_str(
+        r#""SELECT * from (VALUES (1, 'Herp Derpinson')) accounts(id, name) where id = $1", 1i32"#,
+    )?;
+
+    let expanded = block_on(expand_query(input, &mut db))?.to_string();
+
+    assert!(expanded.contains("sqlx :: postgres :: Postgres"));
+    assert!(expanded.contains("id : i32"));
+    assert!(expanded.contains("name : String"));
+
+    // queries that are not in the file are an error instead of being described live
+    let input: QueryMacroInput = syn::parse_str(r#""SELECT 1""#)?;
+    assert!(block_on(expand_query(input, &mut db)).is_err());
+
+    Ok(())
+}
@@ -5,28 +5,28 @@ use proc_macro2::TokenStream;
 use syn::{Ident, Path};
 
 use quote::{format_ident, quote};
-use sqlx::{connection::Connection, database::Database};
+use sqlx::database::Database;
 
-use super::{args, output, QueryMacroInput};
+use super::{args, output, DescribeQuery, QueryMacroInput};
 use crate::database::DatabaseExt;
 
 /// Given an input like `query!("SELECT * FROM accounts WHERE account_id > ?", account_id)`,
 /// expand to an anonymous record
-pub async fn expand_query<C: Connection>(
+pub async fn expand_query<D: DescribeQuery>(
     input: QueryMacroInput,
-    mut conn: C,
+    db: &mut D,
 ) -> crate::Result<TokenStream>
 where
-    C::Database: DatabaseExt + Sized,
-    <C::Database as Database>::TypeInfo: Display,
+    D::Database: DatabaseExt + Sized,
+    <D::Database as Database>::TypeInfo: Display,
 {
-    let describe = input.describe_validate(&mut conn).await?;
+    let describe = input.describe_validate(db).await?;
     let sql = &input.source;
 
     let args = args::quote_args(&input, &describe)?;
 
     let arg_names = &input.arg_names;
-    let db_path = <C::Database as DatabaseExt>::db_path();
+    let db_path = <D::Database as DatabaseExt>::db_path();
 
     if describe.result_columns.is_empty() {
         return Ok(quote! {
@@ -57,7 +57,7 @@ where
         .collect::<TokenStream>();
 
     let query_args = format_ident!("query_args");
-    let output = output::quote_query_as::<C::Database>(sql, &record_type, &query_args, &columns);
+    let output = output::quote_query_as::<D::Database>(sql, &record_type, &query_args, &columns);
 
     Ok(quote! {
         macro_rules! macro_result {
@@ -8,13 +8,14 @@ compile_error!("only one of 'runtime-async-std' or 'runtime-tokio' features must
 
 pub use sqlx_core::arguments;
 pub use sqlx_core::connection::{Connect, Connection};
-pub use sqlx_core::cursor::Cursor;
+pub use sqlx_core::cursor::{Cursor, RowStream};
 pub use sqlx_core::database::{self, Database};
 pub use sqlx_core::describe;
-pub use sqlx_core::executor::{self, Execute, Executor};
+pub use sqlx_core::executor::{self, Execute, Executor, QueryResult};
 pub use sqlx_core::pool::{self, Pool};
 pub use sqlx_core::query::{self, query, Query};
 pub use sqlx_core::query_as::{query_as, QueryAs};
+pub use sqlx_core::retry::{self, retry, Retry};
 pub use sqlx_core::row::{self, FromRow, Row};
 pub use sqlx_core::transaction::Transaction;
 
@@ -41,7 +42,7 @@ pub use sqlx_core::sqlite::{self, Sqlite, SqliteConnection, SqlitePool};
 pub extern crate sqlx_macros;
 
 #[cfg(feature = "macros")]
-pub use sqlx_macros::Type;
+pub use sqlx_macros::{FromRow, Type};
 
 #[cfg(feature = "macros")]
 mod macros;
@@ -57,7 +58,7 @@ pub mod result_ext;
 
 /// Types and traits for encoding values for the database.
 pub mod encode {
-    pub use sqlx_core::encode::{Encode, IsNull};
+    pub use sqlx_core::encode::{DynEncode, Encode, IsNull};
 
     #[cfg(feature = "macros")]
     pub use sqlx_macros::Encode;
@@ -79,6 +79,11 @@
 ///     * The schema of the database URL (e.g. `postgres://` or `mysql://`) will be used to
 ///       determine the database type.
 ///
+/// * With the `offline` feature, setting `SQLX_OFFLINE=true` checks queries against the
+///   descriptions recorded in `sqlx-data.json` in the crate root instead, so that the crate
+///   can be built without access to the database. Build once with `SQLX_PREPARE=true` (and
+///   `DATABASE_URL` set) to record every query in that file, and commit it alongside your code.
+///
 /// [dotenv]: https://crates.io/crates/dotenv
 /// ## See Also
 /// * [query_as!] if you want to use a struct you can name,
@@ -1,5 +1,6 @@
-use sqlx::MySql;
-use sqlx_test::test_type;
+use sqlx::mysql::MySqlQueryAs;
+use sqlx::{Executor, MySql};
+use sqlx_test::{new, test_type};
 use std::fmt::Debug;
 
 // Transparent types are rust-side wrappers over DB types
@@ -25,6 +26,16 @@ enum Color {
     Blue,
 }
 
+// Strong enums can also bind the 1-based index of the variant, as for an ENUM column
+// declared with the variants in the same order
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(rename_all = "lowercase", mysql_enum = "index")]
+enum Size {
+    Small,
+    Medium,
+    Large,
+}
+
 test_type!(transparent(
     MySql,
     Transparent,
@@ -41,3 +52,45 @@ test_type!(weak_enum(
 ));
 
 test_type!(strong_color_enum(MySql, Color, "'green'" == Color::Green));
+
+test_type!(strong_index_enum(MySql, Size, "2" == Size::Medium));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_enum_label_and_index() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE shirts (
+    color ENUM('red', 'green', 'blue') NOT NULL,
+    size ENUM('small', 'medium', 'large') NOT NULL
+)
+    "#,
+    )
+    .await?;
+
+    sqlx::query("INSERT INTO shirts (color, size) VALUES (?, ?)")
+        .bind(Color::Blue)
+        .bind(Size::Large)
+        .execute(&mut conn)
+        .await?;
+
+    // the label is bound for the default mode, the index for `mysql_enum = "index"`
+    let (color, size): (String, u64) = sqlx::query_as("SELECT color, size + 0 FROM shirts")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(color, "blue");
+    assert_eq!(size, 3);
+
+    // decoding accepts either the label or the index
+    let (by_label, by_index): (Size, Size) = sqlx::query_as("SELECT size, size + 0 FROM shirts")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(by_label, Size::Large);
+    assert_eq!(by_index, Size::Large);
+
+    Ok(())
+}
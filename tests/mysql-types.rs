@@ -1,5 +1,8 @@
-use sqlx::MySql;
-use sqlx_test::test_type;
+use std::num::{NonZeroU32, NonZeroU64};
+
+use sqlx::mysql::{MySqlInet, MySqlQueryAs, MySqlValue};
+use sqlx::{Cursor, Executor, MySql, Row};
+use sqlx_test::{new, test_type};
 
 test_type!(null(
     MySql,
@@ -21,6 +24,59 @@ test_type!(i32(MySql, i32, "2141512" == 2141512_i32));
 test_type!(u64(MySql, u64, "2141512" == 2141512_u64));
 test_type!(i64(MySql, i64, "2141512" == 2141512_i64));
 
+test_type!(non_zero_u32(
+    MySql,
+    NonZeroU32,
+    "2141512" == NonZeroU32::new(2141512).unwrap()
+));
+test_type!(non_zero_u64(
+    MySql,
+    NonZeroU64,
+    "2141512" == NonZeroU64::new(2141512).unwrap()
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_integer_out_of_range() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    // binary (prepared) values
+    let res: Result<(i64,), _> = sqlx::query_as("SELECT ?")
+        .bind(u64::MAX)
+        .fetch_one(&mut conn)
+        .await;
+
+    let err = res.unwrap_err().to_string();
+    assert!(
+        err.contains("UNSIGNED value 18446744073709551615 is out of range for `i64`"),
+        "{}",
+        err
+    );
+
+    let res: Result<(i32,), _> = sqlx::query_as("SELECT ?")
+        .bind(i64::from(i32::MAX) + 1)
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    let (value,): (i64,) = sqlx::query_as("SELECT ?")
+        .bind(i64::MAX as u64)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, i64::MAX);
+
+    // text (unprepared) values
+    let mut cursor = conn.fetch("SELECT CAST(18446744073709551615 AS UNSIGNED)");
+    let row = cursor.next().await?.unwrap();
+
+    assert!(row.try_get::<i64, _>(0).is_err());
+    assert_eq!(row.try_get::<u64, _>(0)?, u64::MAX);
+
+    Ok(())
+}
+
 test_type!(double(MySql, f64, "3.14159265E0" == 3.14159265f64));
 
 // NOTE: This behavior can be very surprising. MySQL implicitly widens FLOAT bind parameters
@@ -39,6 +95,31 @@ test_type!(string(
     "''" == ""
 ));
 
+test_type!(char(
+    MySql,
+    char,
+    "'a'" == 'a',
+    "'é'" == 'é',
+    "'漢'" == '漢'
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_char_from_longer_string() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let res: Result<(char,), _> = sqlx::query_as("SELECT 'ab'").fetch_one(&mut conn).await;
+
+    assert!(res.is_err());
+
+    let mut cursor = conn.fetch("SELECT 'ab'");
+    let row = cursor.next().await?.unwrap();
+
+    assert!(row.try_get::<char, _>(0).is_err());
+
+    Ok(())
+}
+
 test_type!(bytes(
     MySql,
     Vec<u8>,
@@ -50,6 +131,96 @@ test_type!(bytes(
         == vec![0_u8, 0, 0, 0, 0x52]
 ));
 
+test_type!(fixed_size_bytes(
+    MySql,
+    [u8; 16],
+    "CAST(X'0123456789ABCDEF0123456789ABCDEF' AS BINARY(16))"
+        == [
+            0x01_u8, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0x01, 0x23, 0x45, 0x67, 0x89, 0xAB,
+            0xCD, 0xEF
+        ],
+    "X'00000000000000000000000000000000'" == [0_u8; 16]
+));
+
+test_type!(inet(
+    MySql,
+    MySqlInet,
+    "INET6_ATON('192.168.0.1')" == MySqlInet("192.168.0.1".parse().unwrap()),
+    "INET6_ATON('2001:db8::ff00:42:8329')" == MySqlInet("2001:db8::ff00:42:8329".parse().unwrap())
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_binary_padding() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE hashes (fixed BINARY(16), variable VARBINARY(4))")
+        .await?;
+
+    sqlx::query("INSERT INTO hashes (fixed, variable) VALUES (?, ?)")
+        .bind(&[0x01_u8, 0x02][..])
+        .bind(&[0x01_u8, 0x02][..])
+        .execute(&mut conn)
+        .await?;
+
+    // BINARY values are padded to the width of the column and returned with the padding;
+    // VARBINARY values are returned as they were stored
+    let (fixed, variable): (Vec<u8>, Vec<u8>) =
+        sqlx::query_as("SELECT fixed, variable FROM hashes")
+            .fetch_one(&mut conn)
+            .await?;
+
+    let mut padded = [0_u8; 16];
+    padded[..2].copy_from_slice(&[0x01, 0x02]);
+
+    assert_eq!(fixed, padded);
+    assert_eq!(variable, [0x01, 0x02]);
+
+    let (fixed,): ([u8; 16],) = sqlx::query_as("SELECT fixed FROM hashes")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(fixed, padded);
+
+    // a VARBINARY value shorter than the array is not padded to fit
+    let res: Result<([u8; 4],), _> = sqlx::query_as("SELECT variable FROM hashes")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+test_type!(duration(
+    MySql,
+    std::time::Duration,
+    "TIME '00:00:00'" == std::time::Duration::from_secs(0),
+    "TIME '00:00:00.250000'" == std::time::Duration::from_millis(250),
+    "TIME '838:59:59'" == std::time::Duration::from_secs(838 * 3600 + 59 * 60 + 59)
+));
+
+#[cfg(feature = "bytes")]
+test_type!(bytes_bytes(
+    MySql,
+    sqlx::types::Bytes,
+    "X'DEADBEEF'" == sqlx::types::Bytes::from_static(&[0xDE, 0xAD, 0xBE, 0xEF]),
+    "X''" == sqlx::types::Bytes::new()
+));
+
+test_type!(boxed_str(
+    MySql,
+    Box<str>,
+    "'helloworld'" == Box::<str>::from("helloworld"),
+    "''" == Box::<str>::from("")
+));
+
+test_type!(boxed_bytes(
+    MySql,
+    Box<[u8]>,
+    "X'DEADBEEF'" == Box::<[u8]>::from(&[0xDE_u8, 0xAD, 0xBE, 0xEF][..])
+));
+
 #[cfg(feature = "chrono")]
 mod chrono {
     use super::*;
@@ -84,3 +255,109 @@ mod chrono {
             )
     ));
 }
+
+#[cfg(feature = "json")]
+mod json {
+    use serde::{Deserialize, Serialize};
+    use sqlx::types::{Json, JsonValue};
+    use sqlx_test::test_unprepared_type;
+
+    use super::*;
+
+    test_unprepared_type!(json_value(
+        MySql,
+        JsonValue,
+        "CAST('{\"name\":\"Joe\",\"age\":33}' AS JSON)"
+            == serde_json::json!({ "name": "Joe", "age": 33 }),
+        "CAST('[1, 2, 3]' AS JSON)" == serde_json::json!([1, 2, 3])
+    ));
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Address {
+        city: String,
+        lines: Vec<String>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Customer {
+        name: String,
+        address: Address,
+    }
+
+    #[cfg_attr(feature = "runtime-async-std", async_std::test)]
+    #[cfg_attr(feature = "runtime-tokio", tokio::test)]
+    async fn test_json_column_round_trip() -> anyhow::Result<()> {
+        let mut conn = new::<MySql>().await?;
+
+        conn.execute(
+            "CREATE TEMPORARY TABLE customers (id INTEGER PRIMARY KEY, data JSON NOT NULL)",
+        )
+        .await?;
+
+        let customer = Customer {
+            name: "Joe".to_owned(),
+            address: Address {
+                city: "Paris".to_owned(),
+                lines: vec!["1 Rue de Rivoli".to_owned(), "Apt. 2".to_owned()],
+            },
+        };
+
+        sqlx::query("INSERT INTO customers (id, data) VALUES (?, ?), (?, ?)")
+            .bind(1)
+            .bind(Json(&customer))
+            .bind(2)
+            .bind(serde_json::json!({ "nested": { "list": [1, { "deep": true }] } }))
+            .execute(&mut conn)
+            .await?;
+
+        // binary protocol
+        let (data,): (Json<Customer>,) = sqlx::query_as("SELECT data FROM customers WHERE id = ?")
+            .bind(1)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(data.0, customer);
+
+        let (value,): (JsonValue,) = sqlx::query_as("SELECT data FROM customers WHERE id = ?")
+            .bind(2)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(value["nested"]["list"][1]["deep"], true);
+
+        // text protocol
+        let mut cursor = conn.fetch("SELECT data FROM customers WHERE id = 1");
+        let row = cursor.next().await?.unwrap();
+
+        assert_eq!(row.try_get_json::<Customer, _>("data")?, customer);
+
+        Ok(())
+    }
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_raw_value() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    // prepared; the binary protocol
+    let mut cursor = sqlx::query("SELECT 'hello', NULL").fetch(&mut conn);
+    let row = cursor.next().await?.unwrap();
+
+    let raw = row.try_get_raw_value(0)?;
+
+    assert!(matches!(raw.value(), Some(MySqlValue::Binary(b"hello"))));
+    assert!(row.try_get_raw_value(1)?.is_null());
+
+    drop(cursor);
+
+    // unprepared; the text protocol
+    let mut cursor = conn.fetch("SELECT 'hello'");
+    let row = cursor.next().await?.unwrap();
+
+    let raw = row.try_get_raw_value(0)?;
+
+    assert!(matches!(raw.value(), Some(MySqlValue::Text(b"hello"))));
+
+    Ok(())
+}
@@ -1,5 +1,9 @@
 use futures::TryStreamExt;
-use sqlx::{mysql::MySqlQueryAs, Connection, Executor, MySql, MySqlPool};
+use sqlx::types::TypeInfo;
+use sqlx::{
+    mysql::{MySqlQueryAs, MySqlRow},
+    Connection, Cursor, Executor, MySql, MySqlConnection, MySqlPool, Row, Type,
+};
 use sqlx_test::new;
 use std::time::Duration;
 
@@ -18,7 +22,7 @@ async fn it_drops_results_in_affected_rows() -> anyhow::Result<()> {
     let affected = conn.execute("select * from mysql.time_zone").await?;
 
     // In MySQL, rows being returned isn't enough to flag it as an _affected_ row
-    assert_eq!(0, affected);
+    assert_eq!(0, affected.rows_affected());
 
     Ok(())
 }
@@ -42,7 +46,7 @@ CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY)
             .execute(&mut conn)
             .await?;
 
-        assert_eq!(cnt, 1);
+        assert_eq!(cnt.rows_affected(), 1);
     }
 
     let sum: i32 = sqlx::query_as("SELECT id FROM users")
@@ -55,6 +59,120 @@ CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY)
     Ok(())
 }
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_returns_affected_rows() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let affected = conn
+        .execute("CREATE TEMPORARY TABLE products (id INTEGER PRIMARY KEY, price INTEGER)")
+        .await?;
+
+    assert_eq!(affected.rows_affected(), 0);
+
+    let affected = conn
+        .execute("INSERT INTO products (id, price) VALUES (1, 10), (2, 20), (3, 30)")
+        .await?;
+
+    assert_eq!(affected.rows_affected(), 3);
+
+    let affected = sqlx::query("UPDATE products SET price = price + 1 WHERE id < ?")
+        .bind(3_i32)
+        .execute(&mut conn)
+        .await?;
+
+    assert_eq!(affected.rows_affected(), 2);
+
+    // DDL following DML must not report the count of the previous statement
+    let affected = conn
+        .execute("CREATE INDEX products_price ON products (price)")
+        .await?;
+
+    assert_eq!(affected.rows_affected(), 0);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_returns_the_last_insert_id() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE notes (id INTEGER AUTO_INCREMENT PRIMARY KEY, body TEXT)")
+        .await?;
+
+    let result = conn
+        .execute("INSERT INTO notes (body) VALUES ('a'), ('b')")
+        .await?;
+
+    // the id generated for the first row of a multi-row insert
+    assert_eq!(result.rows_affected(), 2);
+    assert_eq!(result.last_insert_id(), Some(1));
+    assert_eq!(result.last_insert_rowid(), None);
+    assert_eq!(result.command_tag(), None);
+    assert_eq!(u64::from(result), 2);
+
+    let result = sqlx::query("INSERT INTO notes (body) VALUES (?)")
+        .bind("c")
+        .execute(&mut conn)
+        .await?;
+
+    assert_eq!(result.last_insert_id(), Some(3));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_binds_optional_values() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let name = String::from("Ferris");
+
+    let (a, b, c): (Option<String>, Option<String>, Option<i32>) = sqlx::query_as("SELECT ?, ?, ?")
+        .bind(Some(&*name))
+        .bind(None::<&str>)
+        .bind(Some(None::<i32>))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(a.as_deref(), Some("Ferris"));
+    assert_eq!(b, None);
+    assert_eq!(c, None);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_binds_and_decodes_optional_ints() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    // an `Option<T>` has the SQL type of `T`
+    assert!(
+        <Option<i32> as Type<MySql>>::type_info().compatible(&<i32 as Type<MySql>>::type_info())
+    );
+
+    conn.execute("CREATE TEMPORARY TABLE optional_ints (id INT PRIMARY KEY, value INT)")
+        .await?;
+
+    for (id, value) in vec![(1_i32, Some(10_i32)), (2, None)] {
+        sqlx::query("INSERT INTO optional_ints (id, value) VALUES (?, ?)")
+            .bind(id)
+            .bind(value)
+            .execute(&mut conn)
+            .await?;
+    }
+
+    let values: Vec<(Option<i32>,)> = sqlx::query_as("SELECT value FROM optional_ints ORDER BY id")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(values, vec![(Some(10),), (None,)]);
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
 async fn it_selects_null() -> anyhow::Result<()> {
@@ -133,6 +251,61 @@ async fn test_describe() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_describe_parameterized() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        "CREATE TEMPORARY TABLE describe_params (id int primary key, name varchar(32) null)",
+    )
+    .await?;
+
+    let closed_before = stmt_close_count(&mut conn).await?;
+
+    let describe = conn
+        .describe("SELECT id, name FROM describe_params WHERE id > ? AND name = ?")
+        .await?;
+
+    assert_eq!(describe.param_types.len(), 2);
+    assert_eq!(describe.result_columns.len(), 2);
+
+    assert_eq!(describe.result_columns[0].name.as_deref(), Some("id"));
+    assert_eq!(describe.result_columns[0].non_null, Some(true));
+    assert_eq!(
+        describe.result_columns[0]
+            .type_info
+            .as_ref()
+            .unwrap()
+            .type_name(),
+        "INT"
+    );
+
+    assert_eq!(describe.result_columns[1].name.as_deref(), Some("name"));
+    assert_eq!(describe.result_columns[1].non_null, Some(false));
+    assert_eq!(
+        describe.result_columns[1]
+            .type_info
+            .as_ref()
+            .unwrap()
+            .type_name(),
+        "VAR_CHAR"
+    );
+
+    // the statement prepared to describe the query is closed again
+    assert_eq!(stmt_close_count(&mut conn).await?, closed_before + 1);
+
+    Ok(())
+}
+
+async fn stmt_close_count(conn: &mut MySqlConnection) -> anyhow::Result<u64> {
+    let (_, count): (String, String) = sqlx::query_as("SHOW SESSION STATUS LIKE 'Com_stmt_close'")
+        .fetch_one(conn)
+        .await?;
+
+    Ok(count.parse()?)
+}
+
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
 async fn pool_immediately_fails_with_db_error() -> anyhow::Result<()> {
@@ -210,3 +383,193 @@ async fn pool_smoke_test() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_gets_columns_by_index_and_name() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let mut cursor = conn.fetch("SELECT 1 AS id, 'alice' AS name");
+    let row = cursor.next().await?.unwrap();
+
+    let name = String::from("name");
+
+    assert_eq!(row.try_get::<String, _>(1)?, "alice");
+    assert_eq!(row.try_get::<String, _>("name")?, "alice");
+    assert_eq!(row.try_get::<String, _>(&name)?, "alice");
+    assert_eq!(row.try_get::<String, _>(name)?, "alice");
+
+    assert!(matches!(
+        row.try_get::<String, _>(2),
+        Err(sqlx::Error::ColumnIndexOutOfBounds { index: 2, len: 2 })
+    ));
+
+    assert!(matches!(
+        row.try_get::<String, _>("email"),
+        Err(sqlx::Error::ColumnNotFound(ref name)) if &**name == "email"
+    ));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_describes_the_columns_of_a_row() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let mut cursor = sqlx::query("SELECT 1 AS id, ? AS name, 1.5e0 AS score")
+        .bind("alice")
+        .fetch(&mut conn);
+
+    let row = cursor.next().await?.unwrap();
+
+    let columns: Vec<_> = (0..row.len())
+        .map(|index| {
+            let column = row.column(index).unwrap();
+
+            (
+                column.ordinal(),
+                column.name().map(ToOwned::to_owned),
+                column.type_info().map(|info| info.name().to_owned()),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        columns,
+        [
+            (0, Some("id".to_owned()), Some("BIG_INT".to_owned())),
+            (1, Some("name".to_owned()), Some("VAR_CHAR".to_owned())),
+            (2, Some("score".to_owned()), Some("DOUBLE".to_owned())),
+        ]
+    );
+
+    assert!(row.column(3).is_none());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_iterates_result_sets_of_a_procedure() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute("DROP PROCEDURE IF EXISTS sqlx_two_result_sets")
+        .await?;
+
+    conn.execute(
+        r#"
+CREATE PROCEDURE sqlx_two_result_sets()
+BEGIN
+    SELECT 1 AS id UNION ALL SELECT 2;
+    SELECT 'alice' AS name;
+END
+        "#,
+    )
+    .await?;
+
+    let mut cursor = conn.fetch("CALL sqlx_two_result_sets()");
+
+    assert!(cursor.next_result_set().await?);
+
+    let mut ids = Vec::new();
+
+    while let Some(row) = cursor.next().await? {
+        ids.push(row.get::<i64, _>("id"));
+    }
+
+    assert_eq!(ids, [1, 2]);
+
+    assert!(cursor.next_result_set().await?);
+
+    let row = cursor.next().await?.unwrap();
+    assert_eq!(row.get::<String, _>("name"), "alice");
+    assert!(cursor.next().await?.is_none());
+
+    // the final result of a CALL is the status of the call itself, which has no rows
+    assert!(!cursor.next_result_set().await?);
+
+    drop(cursor);
+
+    // moving to the next result set skips the rows left in the current one
+    let mut cursor = conn.fetch("CALL sqlx_two_result_sets()");
+
+    assert!(cursor.next_result_set().await?);
+    assert!(cursor.next().await?.is_some());
+    assert!(cursor.next_result_set().await?);
+
+    let row = cursor.next().await?.unwrap();
+    assert_eq!(row.get::<String, _>("name"), "alice");
+
+    drop(cursor);
+
+    // without asking for result sets, the rows of all of them are returned
+    let names: Vec<Option<String>> = sqlx::query("CALL sqlx_two_result_sets()")
+        .try_map(|row: MySqlRow| Ok(row.column_name(0).map(str::to_owned)))
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(
+        names,
+        [
+            Some("id".to_owned()),
+            Some("id".to_owned()),
+            Some("name".to_owned())
+        ]
+    );
+
+    // stopping partway through the result sets leaves the connection usable
+    let mut cursor = conn.fetch("CALL sqlx_two_result_sets()");
+    assert!(cursor.next().await?.is_some());
+    drop(cursor);
+
+    let (value,): (i32,) = sqlx::query_as("SELECT 5").fetch_one(&mut conn).await?;
+    assert_eq!(value, 5);
+
+    conn.execute("DROP PROCEDURE sqlx_two_result_sets").await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_fetches_all_rows_of_several_statements() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let values: Vec<(i32,)> = sqlx::query_as("SELECT 1; SELECT 2")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(values, [(1,), (2,)]);
+
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_serializes_a_row() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let mut cursor = conn.fetch(
+        sqlx::query(
+            "SELECT ? AS id, 'alice' AS name, 2.5e0 AS score, X'DEADBEEF' AS data, NULL AS note",
+        )
+        .bind(7_i64),
+    );
+
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&row)?,
+        serde_json::json!({
+            "id": 7,
+            "name": "alice",
+            "score": 2.5,
+            "data": "3q2+7w==",
+            "note": null,
+        })
+    );
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
+use sqlx::postgres::PgQueryAs;
 use sqlx::Postgres;
-use sqlx_test::test_type;
+use sqlx_test::{new, test_type};
 use std::fmt::Debug;
 
 // Transparent types are rust-side wrappers over DB types
@@ -18,7 +19,7 @@ enum Weak {
 
 // "Strong" enums can map to TEXT (25) or a custom enum type
 #[derive(PartialEq, Debug, sqlx::Type)]
-#[sqlx(postgres(oid = 25))]
+#[sqlx(postgres(oid = 25, array_oid = 1009))]
 #[sqlx(rename_all = "lowercase")]
 enum Strong {
     One,
@@ -28,6 +29,18 @@ enum Strong {
     Three,
 }
 
+// Rows map columns to fields by name, unless renamed
+#[derive(PartialEq, Debug, sqlx::FromRow)]
+#[sqlx(rename_all = "lowercase")]
+#[allow(non_snake_case)]
+struct Account {
+    #[sqlx(rename = "account_id")]
+    id: i32,
+    userName: String,
+    #[sqlx(rename = "total")]
+    count: i64,
+}
+
 // TODO: Figure out a good solution for custom type testing
 // Records must map to a custom type
 // Note that all types are types in Postgres
@@ -62,6 +75,12 @@ test_type!(strong_enum(
     "'four'::text" == Strong::Three
 ));
 
+test_type!(strong_enum_array(
+    Postgres,
+    Vec<Strong>,
+    "ARRAY['one', 'four']::text[]" == vec![Strong::One, Strong::Three]
+));
+
 // TODO: Figure out a good solution for custom type testing
 // test_type!(record_pg_config(
 //     Postgres,
@@ -85,3 +104,66 @@ test_type!(strong_enum(
 //             price: Some(2350)
 //         }
 // ));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_from_row_with_renamed_columns() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let account: Account = sqlx::query_as(
+        "SELECT 1 AS account_id, name AS username, count(*) AS total \
+         FROM (VALUES ('Herp'), ('Herp')) accounts(name) GROUP BY name",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(
+        account,
+        Account {
+            id: 1,
+            userName: "Herp".into(),
+            count: 2,
+        }
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_from_row_column_count() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // columns without a field are ignored
+    let account: Account =
+        sqlx::query_as("SELECT 1 AS account_id, 'Herp' AS username, 2::int8 AS total, true")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(account.count, 2);
+
+    let res: Result<Account, _> = sqlx::query_as("SELECT 1 AS account_id, 'Herp' AS username")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(matches!(
+        res,
+        Err(sqlx::Error::ColumnCountMismatch {
+            expected: 3,
+            found: 2
+        })
+    ));
+
+    // with enough columns, a missing field is reported by name
+    let res: Result<Account, _> =
+        sqlx::query_as("SELECT 1 AS account_id, 'Herp' AS username, 2::int8 AS count")
+            .fetch_one(&mut conn)
+            .await;
+
+    match res {
+        Err(sqlx::Error::ColumnNotFound(name)) => assert_eq!(&*name, "total"),
+        res => panic!("expected a missing column, got {:?}", res),
+    }
+
+    Ok(())
+}
@@ -29,6 +29,20 @@ async fn test_no_result() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_void_result() -> anyhow::Result<()> {
+    let mut conn = connect().await?;
+
+    let rec = sqlx::query!("SELECT pg_sleep(0) AS slept")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(rec.slept, ());
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
 async fn _file() -> anyhow::Result<()> {
@@ -13,7 +13,29 @@ async fn test_empty_query() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
     let affected = conn.execute("").await?;
 
-    assert_eq!(affected, 0);
+    assert_eq!(affected.rows_affected(), 0);
+
+    Ok(())
+}
+
+/// A query of only whitespace and comments is empty as well, whether it is
+/// executed or fetched.
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_whitespace_query() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+    let affected = conn.execute(" \n\t -- nothing to see here\n").await?;
+
+    assert_eq!(affected.rows_affected(), 0);
+
+    let mut cursor = conn.fetch("   ");
+    assert!(cursor.next().await?.is_none());
+
+    // the connection is still usable afterwards
+    let mut cursor = conn.fetch("SELECT 1");
+    let row = cursor.next().await?.unwrap();
+
+    assert!(1i32 == row.try_get::<i32, _>(0)?);
 
     Ok(())
 }
@@ -1,11 +1,15 @@
+use std::convert::TryInto;
+use std::num::{NonZeroI32, NonZeroI64, NonZeroU32};
+use std::ops::Bound;
 use std::sync::atomic::{AtomicU32, Ordering};
 
 use sqlx::decode::Decode;
 use sqlx::encode::Encode;
 use sqlx::postgres::types::raw::{PgNumeric, PgNumericSign, PgRecordDecoder, PgRecordEncoder};
+use sqlx::postgres::types::{PgInterval, PgLtree, PgRange, PgRegValue, PgXml};
 use sqlx::postgres::{PgQueryAs, PgTypeInfo, PgValue};
 use sqlx::{Cursor, Executor, Postgres, Row, Type};
-use sqlx_test::{new, test_prepared_type, test_type};
+use sqlx_test::{new, test_prepared_type, test_type, test_unprepared_type};
 
 test_type!(null(
     Postgres,
@@ -20,10 +24,182 @@ test_type!(bool(
     "true::boolean" == true
 ));
 
+test_type!(char(
+    Postgres,
+    u8,
+    "'r'::\"char\"" == b'r',
+    "'\\377'::\"char\"" == 0xFF_u8
+));
+
+test_type!(char_as_char(Postgres, char, "'v'::\"char\"" == 'v'));
+
+test_type!(char_multibyte(
+    Postgres,
+    char,
+    "'é'::bpchar" == 'é',
+    "'漢'::text" == '漢'
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_char_from_longer_string() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let res: Result<(char,), _> = sqlx::query_as("SELECT 'ab'::text")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    let mut cursor = conn.fetch("SELECT 'ab'::text");
+    let row = cursor.next().await?.unwrap();
+
+    assert!(row.try_get::<char, _>(0).is_err());
+
+    Ok(())
+}
+
 test_type!(i16(Postgres, i16, "821::smallint" == 821_i16));
 test_type!(i32(Postgres, i32, "94101::int" == 94101_i32));
 test_type!(i64(Postgres, i64, "9358295312::bigint" == 9358295312_i64));
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_integer_out_of_range() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // binary (prepared) values
+    let res: Result<(i32,), _> = sqlx::query_as("SELECT $1::int8")
+        .bind(i64::from(i32::MAX) + 1)
+        .fetch_one(&mut conn)
+        .await;
+
+    let err = res.unwrap_err().to_string();
+    assert!(
+        err.contains("INT8 value 2147483648 is out of range for `i32`"),
+        "{}",
+        err
+    );
+
+    // a value that fits decodes from any integer type
+    let (narrow, wide): (i32, i64) = sqlx::query_as("SELECT $1::int8, $2::int2")
+        .bind(i64::from(i32::MAX))
+        .bind(42_i16)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(narrow, i32::MAX);
+    assert_eq!(wide, 42);
+
+    // text (unprepared) values
+    let mut cursor = conn.fetch("SELECT 2147483648::int8, (-32769)::int4");
+    let row = cursor.next().await?.unwrap();
+
+    assert!(row.try_get::<i32, _>(0).is_err());
+    assert!(row.try_get::<i16, _>(1).is_err());
+    assert_eq!(row.try_get::<i64, _>(0)?, 2147483648);
+
+    Ok(())
+}
+
+test_type!(u64(
+    Postgres,
+    u64,
+    "9223372036854775807::int8" == i64::MAX as u64,
+    "0::int8" == 0_u64
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_u64_is_limited_to_the_range_of_int8() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // binary (prepared) values
+    let (value, text): (u64, String) = sqlx::query_as("SELECT $1, $1::text")
+        .bind(i64::MAX as u64)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, i64::MAX as u64);
+    assert_eq!(text, "9223372036854775807");
+
+    let res: Result<(u64,), _> = sqlx::query_as("SELECT $1::int8")
+        .bind(-1_i64)
+        .fetch_one(&mut conn)
+        .await;
+
+    let err = res.unwrap_err().to_string();
+    assert!(
+        err.contains("INT8 value -1 is out of range for `u64`"),
+        "{}",
+        err
+    );
+
+    // binding a value that does not fit fails the query instead of panicking
+    let res = sqlx::query("SELECT $1::int8")
+        .bind(u64::MAX)
+        .execute(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::Encode(_))));
+
+    // the connection is still usable
+    let (value,): (u64,) = sqlx::query_as("SELECT $1")
+        .bind(1_u64)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 1);
+
+    // text (unprepared) values
+    let mut cursor = conn.fetch("SELECT 9223372036854775807::int8, (-9223372036854775808)::int8");
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(row.try_get::<u64, _>(0)?, i64::MAX as u64);
+    assert!(row.try_get::<u64, _>(1).is_err());
+
+    Ok(())
+}
+
+test_type!(non_zero_i32(
+    Postgres,
+    NonZeroI32,
+    "2147483647::int4" == NonZeroI32::new(i32::MAX).unwrap()
+));
+
+test_type!(non_zero_i64(
+    Postgres,
+    NonZeroI64,
+    "-9358295312::int8" == NonZeroI64::new(-9358295312).unwrap()
+));
+
+test_type!(non_zero_u32(
+    Postgres,
+    NonZeroU32,
+    "1::oid" == NonZeroU32::new(1).unwrap()
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_zero_does_not_decode_as_non_zero() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // binary (prepared) values
+    let res: Result<(NonZeroI64,), _> = sqlx::query_as("SELECT 0::int8").fetch_one(&mut conn).await;
+
+    let err = res.unwrap_err();
+    assert!(matches!(err, sqlx::Error::Decode(_)), "{:?}", err);
+    assert_eq!(err.to_string(), "zero cannot be decoded as NonZeroI64");
+
+    // text (unprepared) values
+    let mut cursor = conn.fetch("SELECT 0::int8");
+    let row = cursor.next().await?.unwrap();
+
+    assert!(row.try_get::<NonZeroI64, _>(0).is_err());
+
+    Ok(())
+}
+
 test_type!(f32(Postgres, f32, "9419.122::real" == 9419.122_f32));
 test_type!(f64(
     Postgres,
@@ -38,6 +214,34 @@ test_type!(string(
     "''" == ""
 ));
 
+test_type!(oid(
+    Postgres,
+    u32,
+    "'pg_class'::regclass::oid" == 1259_u32,
+    "0::oid" == 0_u32,
+    "4294967295::oid" == u32::MAX
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_oid_is_not_decoded_as_a_signed_integer() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // read as an `INT4`, this would be -1
+    let res: Result<(i32,), _> = sqlx::query_as("SELECT 4294967295::oid")
+        .fetch_one(&mut conn)
+        .await;
+
+    let err = res.unwrap_err().to_string();
+
+    assert!(err.contains("`i32` (as SQL type INT4)"), "{}", err);
+    assert!(err.contains("SQL type OID"), "{}", err);
+
+    Ok(())
+}
+
+test_type!(name(Postgres, String, "'pg_class'::name" == "pg_class"));
+
 test_type!(bytea(
     Postgres,
     Vec<u8>,
@@ -49,6 +253,86 @@ test_type!(bytea(
         == vec![0_u8, 0, 0, 0, 0x52]
 ));
 
+#[cfg(feature = "bytes")]
+test_type!(bytea_bytes(
+    Postgres,
+    sqlx::types::Bytes,
+    "E'\\\\xDEADBEEF'::bytea" == sqlx::types::Bytes::from_static(&[0xDE, 0xAD, 0xBE, 0xEF]),
+    "E'\\\\x'::bytea" == sqlx::types::Bytes::new()
+));
+
+test_type!(boxed_str(
+    Postgres,
+    Box<str>,
+    "'this is foo'" == Box::<str>::from("this is foo"),
+    "''" == Box::<str>::from("")
+));
+
+test_type!(boxed_bytes(
+    Postgres,
+    Box<[u8]>,
+    "E'\\\\xDEADBEEF'::bytea" == Box::<[u8]>::from(&[0xDE_u8, 0xAD, 0xBE, 0xEF][..])
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_boxed_str_has_no_spare_capacity() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let (value,): (Box<str>,) = sqlx::query_as("SELECT $1")
+        .bind(Box::<str>::from("hello, world"))
+        .fetch_one(&mut conn)
+        .await?;
+
+    // converting a box into a `String` keeps its allocation
+    let value = String::from(value);
+
+    assert_eq!(value, "hello, world");
+    assert_eq!(value.capacity(), value.len());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_bytea_round_trip() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let large: Vec<u8> = (0..1_000_000_u32).map(|i| (i % 251) as u8).collect();
+
+    for bytes in vec![Vec::new(), large] {
+        let (value, oid, len): (Vec<u8>, u32, i32) =
+            sqlx::query_as("SELECT $1, pg_typeof($1)::oid, octet_length($1)")
+                .bind(&bytes)
+                .fetch_one(&mut conn)
+                .await?;
+
+        assert_eq!(oid, 17);
+        assert_eq!(len as usize, bytes.len());
+        assert!(value == bytes);
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_unprepared_bytea_output() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    for output in &["hex", "escape"] {
+        conn.execute(&*format!("SET bytea_output = '{}'", output))
+            .await?;
+
+        let mut cursor = conn.fetch("SELECT E'\\\\x48656c6c6f5c00ff'::bytea");
+        let row = cursor.next().await?.unwrap();
+
+        assert_eq!(row.get::<Vec<u8>, _>(0), b"Hello\\\0\xFF", "{}", output);
+    }
+
+    Ok(())
+}
+
 // PgNumeric only works on the wire protocol
 test_prepared_type!(numeric(
     Postgres,
@@ -120,10 +404,51 @@ test_type!(decimal(
     "10000::numeric" == "10000".parse::<sqlx::types::BigDecimal>().unwrap(),
     "0.1::numeric" == "0.1".parse::<sqlx::types::BigDecimal>().unwrap(),
     "0.01234::numeric" == "0.01234".parse::<sqlx::types::BigDecimal>().unwrap(),
+    "0.01::numeric" == "0.01".parse::<sqlx::types::BigDecimal>().unwrap(),
+    "0.0001::numeric" == "0.0001".parse::<sqlx::types::BigDecimal>().unwrap(),
+    "0.00001::numeric" == "0.00001".parse::<sqlx::types::BigDecimal>().unwrap(),
+    "0.000012::numeric" == "0.000012".parse::<sqlx::types::BigDecimal>().unwrap(),
     "12.34::numeric" == "12.34".parse::<sqlx::types::BigDecimal>().unwrap(),
     "12345.6789::numeric" == "12345.6789".parse::<sqlx::types::BigDecimal>().unwrap(),
+    // wider than the 28 significant digits of a 96-bit decimal
+    "1234567890123456789012345678901234567890::numeric"
+        == "1234567890123456789012345678901234567890"
+            .parse::<sqlx::types::BigDecimal>()
+            .unwrap(),
+    "-12345678901234567890.12345678901234567890::numeric"
+        == "-12345678901234567890.12345678901234567890"
+            .parse::<sqlx::types::BigDecimal>()
+            .unwrap(),
+    "0.0000000000000000000000000000000000000001::numeric"
+        == "0.0000000000000000000000000000000000000001"
+            .parse::<sqlx::types::BigDecimal>()
+            .unwrap(),
 ));
 
+#[cfg(feature = "bigdecimal")]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_decimal_nan() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // binary format
+    let res: Result<(sqlx::types::BigDecimal,), _> = sqlx::query_as("SELECT $1::numeric")
+        .bind("NaN")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.unwrap_err().to_string().contains("NaN"));
+
+    // text format
+    let mut cursor = conn.fetch("SELECT 'NaN'::numeric");
+    let row = cursor.next().await?.unwrap();
+
+    let err = row.try_get::<sqlx::types::BigDecimal, _>(0).unwrap_err();
+    assert!(err.to_string().contains("NaN"));
+
+    Ok(())
+}
+
 #[cfg(feature = "uuid")]
 test_type!(uuid(
     Postgres,
@@ -164,9 +489,253 @@ test_type!(ipnetwork(
             .unwrap(),
 ));
 
+#[cfg(feature = "json")]
+mod json {
+    use serde::Deserialize;
+    use sqlx::types::{Json, JsonValue};
+
+    use super::*;
+
+    test_type!(json_value(
+        Postgres,
+        JsonValue,
+        "'{\"name\":\"Joe\",\"age\":33}'::jsonb" == serde_json::json!({ "name": "Joe", "age": 33 }),
+        "'[1, 2, 3]'::jsonb" == serde_json::json!([1, 2, 3]),
+        "'\"hello\"'::jsonb" == serde_json::json!("hello")
+    ));
+
+    test_type!(json_struct(
+        Postgres,
+        Json<Vec<i32>>,
+        "'[5, 10, 15]'::jsonb" == Json(vec![5, 10, 15])
+    ));
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Friend {
+        name: String,
+        age: u32,
+    }
+
+    #[cfg_attr(feature = "runtime-async-std", async_std::test)]
+    #[cfg_attr(feature = "runtime-tokio", tokio::test)]
+    async fn test_row_get_json() -> anyhow::Result<()> {
+        let mut conn = new::<Postgres>().await?;
+
+        let mut cursor = conn.fetch(
+            "SELECT '{\"name\":\"Joe\",\"age\":33}'::json, '{\"name\":\"Joe\",\"age\":33}'::jsonb, '{\"name\":1}'::jsonb",
+        );
+
+        let row = cursor.next().await?.unwrap();
+        let expected = Friend {
+            name: "Joe".to_owned(),
+            age: 33,
+        };
+
+        assert_eq!(row.get_json::<Friend, _>(0), expected);
+        assert_eq!(row.try_get_json::<Friend, _>(1)?, expected);
+        assert!(row.try_get_json::<Friend, _>(2).is_err());
+
+        drop(cursor);
+
+        let mut cursor = sqlx::query("SELECT $1::jsonb")
+            .bind(Json(serde_json::json!({ "name": "Joe", "age": 33 })))
+            .fetch(&mut conn);
+
+        let row = cursor.next().await?.unwrap();
+
+        assert_eq!(row.get_json::<Friend, _>(0), expected);
+
+        Ok(())
+    }
+
+    #[cfg_attr(feature = "runtime-async-std", async_std::test)]
+    #[cfg_attr(feature = "runtime-tokio", tokio::test)]
+    async fn test_sql_null_is_not_json_null() -> anyhow::Result<()> {
+        let mut conn = new::<Postgres>().await?;
+
+        let sql = "SELECT NULL::jsonb, 'null'::jsonb, '1'::jsonb";
+
+        // binary (prepared) values
+        let rec: (
+            Option<Json<Option<i32>>>,
+            Option<Json<Option<i32>>>,
+            Option<Json<Option<i32>>>,
+        ) = sqlx::query_as(sql).fetch_one(&mut conn).await?;
+
+        assert_eq!(rec, (None, Some(Json(None)), Some(Json(Some(1)))));
+
+        let rec: (Option<JsonValue>, Option<JsonValue>) =
+            sqlx::query_as("SELECT NULL::jsonb, 'null'::jsonb")
+                .fetch_one(&mut conn)
+                .await?;
+
+        assert_eq!(rec, (None, Some(JsonValue::Null)));
+
+        // text (unprepared) values
+        let mut cursor = conn.fetch(sql);
+        let row = cursor.next().await?.unwrap();
+
+        assert_eq!(row.try_get::<Option<Json<Option<i32>>>, _>(0)?, None);
+        assert_eq!(
+            row.try_get::<Option<Json<Option<i32>>>, _>(1)?,
+            Some(Json(None))
+        );
+        assert_eq!(
+            row.try_get::<Option<JsonValue>, _>(1)?,
+            Some(JsonValue::Null)
+        );
+
+        // without an `Option`, a SQL NULL is an error even if JSON `null` is not
+        assert!(row.try_get::<Json<Option<i32>>, _>(0).is_err());
+        assert_eq!(row.try_get::<Json<Option<i32>>, _>(1)?, Json(None));
+
+        drop(cursor);
+
+        // encoding keeps them apart as well
+        let rec: (bool, bool, Option<String>) =
+            sqlx::query_as("SELECT $1::jsonb IS NULL, $2::jsonb IS NULL, jsonb_typeof($2)")
+                .bind(None::<Json<Option<i32>>>)
+                .bind(Some(Json(None::<i32>)))
+                .fetch_one(&mut conn)
+                .await?;
+
+        assert_eq!(rec, (true, false, Some("null".to_owned())));
+
+        Ok(())
+    }
+}
+
+test_type!(int4_array(
+    Postgres,
+    Vec<i32>,
+    "ARRAY[1, 2, 3]::int4[]" == vec![1, 2, 3],
+    "'{}'::int4[]" == Vec::<i32>::new()
+));
+
+test_type!(nullable_int4_array(
+    Postgres,
+    Vec<Option<i32>>,
+    "ARRAY[1, NULL, 3]::int4[]" == vec![Some(1), None, Some(3)]
+));
+
+test_type!(text_array(
+    Postgres,
+    Vec<String>,
+    "ARRAY['foo', 'bar, baz', 'NULL', 'a \"quote\"']::text[]"
+        == vec![
+            "foo".to_owned(),
+            "bar, baz".to_owned(),
+            "NULL".to_owned(),
+            "a \"quote\"".to_owned()
+        ]
+));
+
+test_type!(text_array_special_chars(
+    Postgres,
+    Vec<Option<String>>,
+    "ARRAY['a,b', 'c\"d', 'NULL', NULL, '{}', E'back\\\\slash', '', ' padded ']::text[]"
+        == vec![
+            Some("a,b".to_owned()),
+            Some("c\"d".to_owned()),
+            Some("NULL".to_owned()),
+            None,
+            Some("{}".to_owned()),
+            Some("back\\slash".to_owned()),
+            Some("".to_owned()),
+            Some(" padded ".to_owned()),
+        ]
+));
+
+test_type!(float4_fixed_size_array(
+    Postgres,
+    [f32; 3],
+    "ARRAY[0.5, 1, -2]::float4[]" == [0.5_f32, 1.0, -2.0]
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_fixed_size_array_wrong_length() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let mut cursor = sqlx::query("SELECT ARRAY[0.5, 1, -2]::float4[]").fetch(&mut conn);
+    let row = cursor.next().await?.unwrap();
+
+    assert!(row.try_get::<[f32; 2], _>(0).is_err());
+    assert!(row.try_get::<[f32; 4], _>(0).is_err());
+
+    Ok(())
+}
+
+test_type!(int4range(
+    Postgres,
+    PgRange<i32>,
+    "'[1,5)'::int4range" == PgRange::from(1..5),
+    "'[1,)'::int4range" == PgRange::from(1..),
+    "'(,5]'::int4range" == PgRange::new(Bound::Unbounded, Bound::Excluded(6)),
+    "'empty'::int4range" == PgRange::<i32>::empty()
+));
+
+test_type!(int8range(
+    Postgres,
+    PgRange<i64>,
+    "'[-9000000000,9000000000)'::int8range" == PgRange::from(-9_000_000_000_i64..9_000_000_000)
+));
+
+#[cfg(feature = "geometry")]
+mod geometry {
+    use sqlx::postgres::types::{PgBox, PgPoint};
+
+    use super::*;
+
+    // `POINT` has no equality operator so the prepared round-trip is tested by hand below
+    test_unprepared_type!(point(
+        Postgres,
+        PgPoint,
+        "'(1.5, -2)'::point" == PgPoint { x: 1.5, y: -2.0 },
+        "'(0,1e300)'::point" == PgPoint { x: 0.0, y: 1e300 }
+    ));
+
+    test_type!(box(
+        Postgres,
+        PgBox,
+        "'((3,4),(1,2))'::box"
+            == PgBox {
+                high: PgPoint { x: 3.0, y: 4.0 },
+                low: PgPoint { x: 1.0, y: 2.0 },
+            },
+        "'(-1.5,0),(0,-0.5)'::box"
+            == PgBox {
+                high: PgPoint { x: 0.0, y: 0.0 },
+                low: PgPoint { x: -1.5, y: -0.5 },
+            }
+    ));
+
+    #[cfg_attr(feature = "runtime-async-std", async_std::test)]
+    #[cfg_attr(feature = "runtime-tokio", tokio::test)]
+    async fn test_prepared_type_point() -> anyhow::Result<()> {
+        let mut conn = new::<Postgres>().await?;
+
+        let point = PgPoint { x: 1.5, y: -2.0 };
+
+        let (same, text, returned): (bool, String, PgPoint) =
+            sqlx::query_as("SELECT $1 ~= '(1.5, -2)'::point, $2::text, $3")
+                .bind(point)
+                .bind(point)
+                .bind(point)
+                .fetch_one(&mut conn)
+                .await?;
+
+        assert!(same);
+        assert_eq!(text, "(1.5,-2)");
+        assert_eq!(returned, point);
+
+        Ok(())
+    }
+}
+
 #[cfg(feature = "chrono")]
 mod chrono {
-    use sqlx::types::chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+    use sqlx::types::chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 
     use super::*;
 
@@ -198,6 +767,300 @@ mod chrono {
                 Utc,
             )
     ));
+
+    // the offset is not preserved but the instant is
+    test_type!(chrono_date_time_fixed_offset(
+        Postgres,
+        DateTime::<FixedOffset>,
+        "TIMESTAMPTZ '2019-01-02 05:10:20.115100+05:30'"
+            == DateTime::<FixedOffset>::from_utc(
+                NaiveDate::from_ymd(2019, 1, 1).and_hms_micro(23, 40, 20, 115100),
+                FixedOffset::east(5 * 3600 + 30 * 60),
+            )
+    ));
+
+    test_type!(chrono_duration(
+        Postgres,
+        sqlx::types::chrono::Duration,
+        "INTERVAL '1.5 seconds'" == sqlx::types::chrono::Duration::milliseconds(1500),
+        "INTERVAL '-2 hours 30 minutes'" == sqlx::types::chrono::Duration::minutes(-90),
+        "INTERVAL '0'" == sqlx::types::chrono::Duration::zero()
+    ));
+
+    #[cfg_attr(feature = "runtime-async-std", async_std::test)]
+    #[cfg_attr(feature = "runtime-tokio", tokio::test)]
+    async fn test_interval_with_months_does_not_decode_as_duration() -> anyhow::Result<()> {
+        let mut conn = new::<Postgres>().await?;
+
+        let res: Result<(sqlx::types::chrono::Duration,), _> =
+            sqlx::query_as("SELECT INTERVAL '1 month 2 seconds'")
+                .fetch_one(&mut conn)
+                .await;
+
+        let err = res.unwrap_err().to_string();
+        assert!(err.contains("month"), "{}", err);
+
+        let mut cursor = conn.fetch("SELECT INTERVAL '1 month 2 seconds'");
+        let row = cursor.next().await?.unwrap();
+
+        assert!(row.try_get::<sqlx::types::chrono::Duration, _>(0).is_err());
+
+        Ok(())
+    }
+
+    #[cfg_attr(feature = "runtime-async-std", async_std::test)]
+    #[cfg_attr(feature = "runtime-tokio", tokio::test)]
+    async fn test_timestamptz_does_not_decode_as_naive() -> anyhow::Result<()> {
+        let mut conn = new::<Postgres>().await?;
+
+        let res: Result<(NaiveDateTime,), _> =
+            sqlx::query_as("SELECT TIMESTAMPTZ '2019-01-02 05:10:20'")
+                .fetch_one(&mut conn)
+                .await;
+
+        let err = res.unwrap_err().to_string();
+        assert!(
+            err.contains("not compatible with SQL type TIMESTAMPTZ"),
+            "{}",
+            err
+        );
+
+        let res: Result<(DateTime<Utc>,), _> =
+            sqlx::query_as("SELECT TIMESTAMP '2019-01-02 05:10:20'")
+                .fetch_one(&mut conn)
+                .await;
+
+        let err = res.unwrap_err().to_string();
+        assert!(
+            err.contains("not compatible with SQL type TIMESTAMP:"),
+            "{}",
+            err
+        );
+
+        Ok(())
+    }
+
+    test_type!(chrono_tsrange(
+        Postgres,
+        PgRange<NaiveDateTime>,
+        "'[2019-01-02 05:10:20,2019-01-03 00:00:00)'::tsrange"
+            == PgRange::from(
+                NaiveDate::from_ymd(2019, 1, 2).and_hms(5, 10, 20)
+                    ..NaiveDate::from_ymd(2019, 1, 3).and_hms(0, 0, 0)
+            ),
+        "'(2019-01-02 05:10:20,)'::tsrange"
+            == PgRange::new(
+                Bound::Excluded(NaiveDate::from_ymd(2019, 1, 2).and_hms(5, 10, 20)),
+                Bound::Unbounded
+            )
+    ));
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_void() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // `pg_advisory_lock` returns `void`
+    let ((),): ((),) = sqlx::query_as("SELECT pg_advisory_lock(1)")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let () = conn.fetch_scalar("SELECT pg_sleep(0)").await?;
+
+    // unprepared; the text protocol
+    let mut cursor = conn.fetch("SELECT pg_advisory_unlock_all()");
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(row.try_get_raw_value(0)?.type_info().oid(), 2278);
+    row.try_get::<(), _>(0)?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_catalog_char_and_name() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let rec: (char, String) =
+        sqlx::query_as("SELECT relkind::\"char\", relname::name FROM pg_class WHERE relname = $1")
+            .bind("pg_class")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(rec.0, 'r');
+    assert_eq!(rec.1, "pg_class");
+
+    let mut cursor = conn
+        .fetch("SELECT relkind::\"char\", relname::name FROM pg_class WHERE relname = 'pg_class'");
+
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(row.get::<u8, _>(0), b'r');
+    assert_eq!(row.get::<&str, _>(1), "pg_class");
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_reg_types() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // prepared queries receive the binary format; the OID
+    let rec: (PgRegValue, PgRegValue, u32) =
+        sqlx::query_as("SELECT 'pg_class'::regclass, 'int4'::regtype, 'english'::regconfig::oid")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(rec.0, PgRegValue::Oid(1259));
+    assert_eq!(rec.1.oid(), Some(23));
+    assert_eq!(rec.1.name(), None);
+
+    let mut cursor =
+        conn.fetch("SELECT 'pg_class'::regclass, 'int4'::regtype, 'english'::regconfig");
+    let row = cursor.next().await?.unwrap();
+
+    // unprepared queries receive the text format; the name
+    assert_eq!(
+        row.get::<PgRegValue, _>(0),
+        PgRegValue::Name("pg_class".into())
+    );
+    assert_eq!(row.get::<PgRegValue, _>(1).name(), Some("integer"));
+    assert_eq!(row.get::<String, _>(2), "english");
+
+    drop(cursor);
+
+    // the binary format decodes into a `u32` and matches the OID of the regconfig
+    let mut cursor = sqlx::query("SELECT 'english'::regconfig").fetch(&mut conn);
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(row.get::<u32, _>(0), rec.2);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_ltree() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE EXTENSION IF NOT EXISTS ltree").await?;
+
+    let path: PgLtree = "Top.Science.Astronomy".parse()?;
+
+    // prepared queries use the binary format; the type of the parameter is inferred
+    let rec: (bool, PgLtree, PgLtree) = sqlx::query_as(
+        "SELECT $1 = 'Top.Science.Astronomy'::ltree, $1::ltree, subpath($1::ltree, 1)",
+    )
+    .bind(&path)
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert!(rec.0);
+    assert_eq!(rec.1, path);
+    assert_eq!(rec.2.to_string(), "Science.Astronomy");
+
+    // unprepared queries use the text format
+    let mut cursor = conn.fetch("SELECT 'Top.Science.Astronomy'::ltree, ''::ltree");
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(row.get::<PgLtree, _>(0), path);
+    assert!(row.get::<PgLtree, _>(1).is_empty());
+
+    drop(cursor);
+
+    assert!("Top.Science-Fiction".parse::<PgLtree>().is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_xml() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE documents (id INT PRIMARY KEY, body XML)")
+        .await?;
+
+    let body = PgXml(r#"<book lang="en"><title>Dune</title><year>1965</year></book>"#.into());
+
+    // prepared queries use the binary format
+    sqlx::query("INSERT INTO documents (id, body) VALUES (1, $1)")
+        .bind(&body)
+        .execute(&mut conn)
+        .await?;
+
+    let (title, rec): (String, PgXml) = sqlx::query_as(
+        "SELECT (xpath('/book/title/text()', body))[1]::text, body FROM documents WHERE id = 1",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(title, "Dune");
+    assert_eq!(rec, body);
+
+    // unprepared queries use the text format
+    let mut cursor = conn.fetch("SELECT body FROM documents WHERE id = 1");
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(row.get::<PgXml, _>(0), body);
+
+    drop(cursor);
+
+    // malformed XML is rejected by Postgres, not by us
+    let res = sqlx::query("INSERT INTO documents (id, body) VALUES (2, $1)")
+        .bind(PgXml("<book>".into()))
+        .execute(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_raw_value_of_unsupported_type() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // prepared; `POINT` is sent in the binary format as two big-endian f64s
+    let mut cursor = sqlx::query("SELECT '(1.5, -2)'::point, NULL::point").fetch(&mut conn);
+    let row = cursor.next().await?.unwrap();
+
+    let raw = row.try_get_raw_value(0)?;
+
+    assert_eq!(raw.type_info().oid(), 600);
+
+    let (x, y) = match raw.value() {
+        Some(PgValue::Binary(buf)) => {
+            assert_eq!(buf.len(), 16);
+
+            let x = f64::from_bits(u64::from_be_bytes(buf[..8].try_into()?));
+            let y = f64::from_bits(u64::from_be_bytes(buf[8..].try_into()?));
+
+            (x, y)
+        }
+
+        value => panic!("expected a binary value, got {:?}", value),
+    };
+
+    assert_eq!((x, y), (1.5, -2.0));
+    assert!(row.try_get_raw_value(1)?.is_null());
+
+    drop(cursor);
+
+    // unprepared; `POINT` is sent in the text format
+    let mut cursor = conn.fetch("SELECT '(1.5, -2)'::point");
+    let row = cursor.next().await?.unwrap();
+
+    let raw = row.try_get_raw_value(0)?;
+
+    assert_eq!(raw.type_info().oid(), 600);
+    assert!(matches!(raw.value(), Some(PgValue::Text("(1.5,-2)"))));
+
+    Ok(())
 }
 
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
@@ -282,6 +1145,88 @@ async fn test_unprepared_anonymous_record() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_anonymous_record_as_text() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let query = "SELECT (1, 'hi', true, NULL::int4, 'say \"hi\"', 2.5::float8, '\\x0aff'::bytea)";
+
+    let expected = [
+        Some("1"),
+        Some("hi"),
+        Some("t"),
+        None,
+        Some("say \"hi\""),
+        Some("2.5"),
+        Some("\\x0aff"),
+    ];
+
+    // binary format
+    let mut cursor = sqlx::query(query).fetch(&mut conn);
+    let row = cursor.next().await?.unwrap();
+    let fields = PgRecordDecoder::new(row.try_get_raw(0)?)?.decode_remaining_as_text()?;
+
+    assert_eq!(
+        fields.iter().map(Option::as_deref).collect::<Vec<_>>(),
+        expected
+    );
+
+    // text format
+    let mut cursor = conn.fetch(query);
+    let row = cursor.next().await?.unwrap();
+    let fields = PgRecordDecoder::new(row.try_get_raw(0)?)?.decode_remaining_as_text()?;
+
+    assert_eq!(
+        fields.iter().map(Option::as_deref).collect::<Vec<_>>(),
+        expected
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_anonymous_row_constructor() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // the type of each field is only known from the OID embedded in the record
+    let (rec,): ((i32, f64, String),) = sqlx::query_as("SELECT ROW(1, 2.0::float8, 'x')")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(rec, (1, 2.0, "x".to_owned()));
+
+    #[cfg(feature = "bigdecimal")]
+    {
+        let (rec,): ((i32, sqlx::types::BigDecimal, String),) =
+            sqlx::query_as("SELECT ROW(1, 2.0, 'x')")
+                .fetch_one(&mut conn)
+                .await?;
+
+        assert_eq!(rec.0, 1);
+        assert_eq!(rec.1, "2.0".parse::<sqlx::types::BigDecimal>()?);
+        assert_eq!(rec.2, "x");
+
+        let mut cursor = conn.fetch("SELECT ROW(1, 2.0, 'x')");
+        let row = cursor.next().await?.unwrap();
+        let rec: (i32, sqlx::types::BigDecimal, String) = row.get(0);
+
+        assert_eq!(rec.1, "2.0".parse::<sqlx::types::BigDecimal>()?);
+    }
+
+    // a field that cannot be decoded is reported with its type
+    let res: Result<((f64, f64, String),), _> = sqlx::query_as("SELECT ROW(1, 2.0::float8, 'x')")
+        .fetch_one(&mut conn)
+        .await;
+
+    let err = res.unwrap_err().to_string();
+
+    assert!(err.contains("INT4"), "{}", err);
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
 async fn test_prepared_structs() -> anyhow::Result<()> {
@@ -384,3 +1329,150 @@ END $$;
 
     Ok(())
 }
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_type_resolved_by_name() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // recreated each time as `postgres-macros` empties `pg_enum`
+    conn.execute(
+        r#"
+DROP TYPE IF EXISTS _sqlx_mood;
+CREATE TYPE _sqlx_mood AS ENUM ('sad', 'ok', 'happy');
+    "#,
+    )
+    .await?;
+
+    // the binary format of an enum is its label
+    #[derive(Debug, PartialEq)]
+    struct Mood(String);
+
+    impl Type<Postgres> for Mood {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_name("public._sqlx_mood")
+        }
+    }
+
+    impl Encode<Postgres> for Mood {
+        fn encode(&self, buf: &mut Vec<u8>) {
+            buf.extend_from_slice(self.0.as_bytes());
+        }
+    }
+
+    impl<'de> Decode<'de, Postgres> for Mood {
+        fn decode(value: Option<PgValue<'de>>) -> sqlx::Result<Self> {
+            <String as Decode<Postgres>>::decode(value).map(Mood)
+        }
+    }
+
+    // the parameter is sent as `_sqlx_mood` so it can be compared without a cast
+    let (matches, oid, mood): (bool, i32, Mood) =
+        sqlx::query_as("SELECT $1 = 'happy'::_sqlx_mood, pg_typeof($1)::oid::int4, $1")
+            .bind(Mood("happy".to_owned()))
+            .fetch_one(&mut conn)
+            .await?;
+
+    let (expected_oid,): (i32,) =
+        sqlx::query_as("SELECT oid::int4 FROM pg_type WHERE typname = '_sqlx_mood'")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert!(matches);
+    assert_eq!(oid, expected_oid);
+    assert_eq!(mood, Mood("happy".to_owned()));
+
+    // the OID is looked up once per connection; running the query again only
+    // reuses the cached statement of the query itself
+    let stats = conn.statement_cache_stats();
+
+    let (matches,): (bool,) = sqlx::query_as("SELECT $1 = 'happy'::_sqlx_mood")
+        .bind(Mood("sad".to_owned()))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(!matches);
+
+    let (matches,): (bool,) = sqlx::query_as("SELECT $1 = 'happy'::_sqlx_mood")
+        .bind(Mood("happy".to_owned()))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(matches);
+    assert_eq!(conn.statement_cache_stats().hits, stats.hits + 1);
+
+    // a type that does not exist can not be bound
+    struct Missing;
+
+    impl Type<Postgres> for Missing {
+        fn type_info() -> PgTypeInfo {
+            PgTypeInfo::with_name("\"_sqlx_Missing\"")
+        }
+    }
+
+    impl Encode<Postgres> for Missing {
+        fn encode(&self, _buf: &mut Vec<u8>) {}
+    }
+
+    let res = sqlx::query("SELECT $1")
+        .bind(Missing)
+        .execute(&mut conn)
+        .await;
+
+    assert!(matches!(
+        res,
+        Err(sqlx::Error::TypeNotFound { ref type_name }) if &**type_name == "\"_sqlx_Missing\""
+    ));
+
+    Ok(())
+}
+
+test_type!(interval(
+    Postgres,
+    PgInterval,
+    "INTERVAL '1 year 2 months 3 days 04:05:06.5'"
+        == PgInterval {
+            months: 14,
+            days: 3,
+            microseconds: 14_706_500_000
+        },
+    "INTERVAL '-1 day +00:00:01'"
+        == PgInterval {
+            months: 0,
+            days: -1,
+            microseconds: 1_000_000
+        },
+    "INTERVAL '0'" == PgInterval::default()
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_interval_styles() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let interval = PgInterval {
+        months: -14,
+        days: 3,
+        microseconds: -14_706_500_000,
+    };
+
+    for style in &["postgres", "postgres_verbose", "iso_8601"] {
+        conn.execute(&*format!("SET IntervalStyle = {}", style))
+            .await?;
+
+        // unprepared queries receive the interval as text in the current style
+        let mut cursor =
+            conn.fetch("SELECT INTERVAL '-1 year -2 months +3 days -04:05:06.5', INTERVAL '0'");
+        let row = cursor.next().await?.unwrap();
+
+        assert_eq!(row.try_get::<PgInterval, _>(0)?, interval, "{}", style);
+        assert_eq!(
+            row.try_get::<PgInterval, _>(1)?,
+            PgInterval::default(),
+            "{}",
+            style
+        );
+    }
+
+    Ok(())
+}
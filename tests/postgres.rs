@@ -1,7 +1,12 @@
-use futures::TryStreamExt;
-use sqlx::postgres::{PgPool, PgQueryAs, PgRow};
-use sqlx::{Connection, Executor, Postgres, Row};
+use futures::{StreamExt, TryStreamExt};
+use sqlx::encode::DynEncode;
+use sqlx::postgres::{PgConnection, PgPool, PgQueryAs, PgQueryBuilder, PgRow, PgSql};
+use sqlx::row::DuplicateColumns;
+use sqlx::types::TypeInfo;
+use sqlx::{Connect, Connection, Cursor, Executor, Postgres, Row, Type};
 use sqlx_test::new;
+use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Duration;
 
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
@@ -19,35 +24,792 @@ async fn it_connects() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_connects_with_tcp_options() -> anyhow::Result<()> {
+    let url = dotenv::var("DATABASE_URL")?;
+    let separator = if url.contains('?') { '&' } else { '?' };
+
+    let mut conn = PgConnection::connect(format!(
+        "{}{}tcp_nodelay=false&tcp_keepalive=60",
+        url, separator
+    ))
+    .await?;
+
+    conn.ping().await?;
+
+    let res = PgConnection::connect(format!("{}{}tcp_keepalive=soon", url, separator)).await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_reports_the_server_version() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let (major, minor) = conn.server_version();
+
+    assert!(major >= 9, "implausible server version {}.{}", major, minor);
+
+    // e.g. 120002 for 12.2 and 90601 for 9.6.1
+    let (num,): (String,) = sqlx::query_as("SHOW server_version_num")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let num: u32 = num.parse()?;
+    let expected = if num >= 100_000 {
+        (num / 10_000, num % 10_000)
+    } else {
+        (num / 10_000, num / 100 % 100)
+    };
+
+    assert_eq!((u32::from(major), u32::from(minor)), expected);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_connects_with_a_search_path() -> anyhow::Result<()> {
+    let url = dotenv::var("DATABASE_URL")?;
+    let separator = if url.contains('?') { '&' } else { '?' };
+
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        r#"
+CREATE SCHEMA IF NOT EXISTS sqlx_tenant;
+CREATE TABLE IF NOT EXISTS sqlx_tenant.widgets (name TEXT NOT NULL);
+DELETE FROM sqlx_tenant.widgets;
+INSERT INTO sqlx_tenant.widgets VALUES ('sprocket');
+        "#,
+    )
+    .await?;
+
+    let mut conn = PgConnection::connect(format!(
+        "{}{}search_path=sqlx_tenant,public",
+        url, separator
+    ))
+    .await?;
+
+    let (name,): (String,) = sqlx::query_as("SELECT name FROM widgets")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(name, "sprocket");
+
+    // the search path is the session default, so it survives a reset
+    conn.execute("RESET search_path").await?;
+
+    let (search_path,): (String,) = sqlx::query_as("SHOW search_path")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(search_path, "sqlx_tenant,public");
+
+    // options are passed on to the server as with libpq
+    let mut conn = PgConnection::connect(format!(
+        "{}{}options=-c%20search_path%3Dsqlx_tenant",
+        url, separator
+    ))
+    .await?;
+
+    let (search_path,): (String,) = sqlx::query_as("SHOW search_path")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(search_path, "sqlx_tenant");
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
 async fn it_executes() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
 
-    let _ = conn
-        .execute(
-            r#"
-CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY);
-            "#,
-        )
-        .await?;
+    let _ = conn
+        .execute(
+            r#"
+CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY);
+            "#,
+        )
+        .await?;
+
+    for index in 1..=10_i32 {
+        let cnt = sqlx::query("INSERT INTO users (id) VALUES ($1)")
+            .bind(index)
+            .execute(&mut conn)
+            .await?;
+
+        assert_eq!(cnt.rows_affected(), 1);
+    }
+
+    let sum: i32 = sqlx::query("SELECT id FROM users")
+        .try_map(|row: PgRow| row.try_get::<i32, _>(0))
+        .fetch(&mut conn)
+        .try_fold(0_i32, |acc, x| async move { Ok(acc + x) })
+        .await?;
+
+    assert_eq!(sum, 55);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_binds_values_as_text() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let (a, b, c, d) = sqlx::query("SELECT $1::int4 - 1, $2::int8, $3::float8, $4::int8")
+        .bind_text(-42_i32)
+        .bind(i64::MIN)
+        .bind_text(-0.5_f64)
+        .bind_text(None::<i64>)
+        .try_map(|row: PgRow| {
+            Ok((
+                row.try_get::<i32, _>(0)?,
+                row.try_get::<i64, _>(1)?,
+                row.try_get::<f64, _>(2)?,
+                row.try_get::<Option<i64>, _>(3)?,
+            ))
+        })
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(a, -43);
+    assert_eq!(b, i64::MIN);
+    assert_eq!(c, -0.5);
+    assert_eq!(d, None);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_returns_the_command_tag() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let result = conn
+        .execute("CREATE TEMPORARY TABLE notes (id SERIAL PRIMARY KEY, body TEXT)")
+        .await?;
+
+    assert_eq!(result.rows_affected(), 0);
+    assert_eq!(result.command_tag(), Some("CREATE TABLE"));
+
+    let result = sqlx::query("INSERT INTO notes (body) VALUES ($1), ($2)")
+        .bind("a")
+        .bind("b")
+        .execute(&mut conn)
+        .await?;
+
+    assert_eq!(result.rows_affected(), 2);
+    assert_eq!(result.command_tag(), Some("INSERT 0 2"));
+    assert_eq!(result.last_insert_id(), None);
+    assert_eq!(result.last_insert_rowid(), None);
+    assert_eq!(u64::from(result), 2);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_reads_the_command_tag_from_a_cursor() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let mut cursor = conn.fetch("CREATE TEMPORARY TABLE tagged (id INT)");
+    assert!(cursor.next().await?.is_none());
+    assert_eq!(cursor.command_tag(), Some("CREATE TABLE"));
+
+    let mut cursor = conn.fetch("SELECT * FROM generate_series(1, 3)");
+    assert_eq!(cursor.command_tag(), None);
+
+    while cursor.next().await?.is_some() {}
+    assert_eq!(cursor.command_tag(), Some("SELECT 3"));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_binds_values_of_runtime_types() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE profiles (id INT, name TEXT, score FLOAT8, active BOOL)")
+        .await?;
+
+    // an INSERT whose set of columns is only known at runtime
+    let values: Vec<(&str, Box<dyn DynEncode<Postgres> + Send + Sync>)> = vec![
+        ("id", Box::new(1_i32)),
+        ("name", Box::new("alice")),
+        ("active", Box::new(Some(true))),
+    ];
+
+    let columns: Vec<_> = values.iter().map(|(column, _)| *column).collect();
+    let params: Vec<_> = (1..=values.len()).map(|i| format!("${}", i)).collect();
+
+    let sql = format!(
+        "INSERT INTO profiles ({}) VALUES ({})",
+        columns.join(", "),
+        params.join(", ")
+    );
+
+    let mut query = sqlx::query(&sql);
+
+    for (_, value) in &values {
+        query = query.bind_dyn(&**value);
+    }
+
+    assert_eq!(query.execute(&mut conn).await?.rows_affected(), 1);
+
+    let row: (i32, String, Option<f64>, bool) =
+        sqlx::query_as("SELECT id, name, score, active FROM profiles")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(row, (1, "alice".to_owned(), None, true));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_streams_rows_from_a_cursor() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let mut stream = conn
+        .fetch("SELECT generate_series(1, 100)")
+        .into_stream(|row: PgRow| row.try_get::<i32, _>(0));
+
+    let mut sum = 0;
+
+    while let Some(value) = stream.try_next().await? {
+        sum += value;
+    }
+
+    assert_eq!(sum, 5050);
+
+    drop(stream);
+
+    let values: Vec<i32> = sqlx::query("SELECT generate_series(1, 100)")
+        .fetch(&mut conn)
+        .into_stream(|row: PgRow| row.try_get::<i32, _>(0))
+        .take(2)
+        .try_collect()
+        .await?;
+
+    assert_eq!(values, vec![1, 2]);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_reads_many_rows_of_varying_width() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // every row is read into the same buffer of value ranges; rows differ in
+    // the number of NULLs and the length of their values
+    let query = r#"
+SELECT i, NULLIF(i % 3, 0), repeat('x', i % 50)
+FROM generate_series(1, 20000) i
+    "#;
+
+    for prepared in &[false, true] {
+        let mut cursor = if *prepared {
+            sqlx::query(query).fetch(&mut conn)
+        } else {
+            conn.fetch(query)
+        };
+
+        let mut count = 0;
+
+        while let Some(row) = cursor.next().await? {
+            count += 1;
+
+            let i: i32 = row.get(0);
+            let rem: Option<i32> = row.get(1);
+            let text: String = row.get(2);
+
+            assert_eq!(i, count);
+            assert_eq!(rem, Some(i % 3).filter(|&rem| rem != 0));
+            assert_eq!(text.len(), (i % 50) as usize);
+        }
+
+        assert_eq!(count, 20000);
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_fetches_a_scalar() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let count: i64 = conn
+        .fetch_scalar("SELECT COUNT(*) FROM generate_series(1, 10)")
+        .await?;
+
+    assert_eq!(count, 10);
+
+    // the remaining rows are discarded
+    let first: i32 = conn
+        .fetch_scalar(sqlx::query("SELECT generate_series($1, 100)").bind(5_i32))
+        .await?;
+
+    assert_eq!(first, 5);
+
+    let res = conn.fetch_scalar::<i32, _>("SELECT 1 WHERE false").await;
+
+    assert!(matches!(res, Err(sqlx::Error::RowNotFound)));
+
+    // the connection is left ready for the next query
+    let value: String = conn.fetch_scalar("SELECT 'hello'::text").await?;
+
+    assert_eq!(value, "hello");
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_checks_for_a_row_with_unit() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let none: Option<()> = sqlx::query_as("SELECT 1 WHERE false")
+        .fetch_optional(&mut conn)
+        .await?;
+
+    let some: Option<()> = sqlx::query_as("SELECT 1").fetch_optional(&mut conn).await?;
+
+    assert_eq!(none, None);
+    assert_eq!(some, Some(()));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_binds_optional_values() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let name = String::from("Ferris");
+
+    let (a, b, c): (Option<String>, Option<String>, Option<i32>) =
+        sqlx::query_as("SELECT $1, $2, $3")
+            .bind(Some(&*name))
+            .bind(None::<&str>)
+            .bind(Some(None::<i32>))
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(a.as_deref(), Some("Ferris"));
+    assert_eq!(b, None);
+    assert_eq!(c, None);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_binds_and_decodes_optional_ints() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // an `Option<T>` has the SQL type of `T`
+    assert!(<Option<i32> as Type<Postgres>>::type_info()
+        .compatible(&<i32 as Type<Postgres>>::type_info()));
+
+    conn.execute("CREATE TEMPORARY TABLE optional_ints (id INT4 PRIMARY KEY, value INT4)")
+        .await?;
+
+    for (id, value) in vec![(1_i32, Some(10_i32)), (2, None)] {
+        sqlx::query("INSERT INTO optional_ints (id, value) VALUES ($1, $2)")
+            .bind(id)
+            .bind(value)
+            .execute(&mut conn)
+            .await?;
+    }
+
+    let values: Vec<(Option<i32>,)> = sqlx::query_as("SELECT value FROM optional_ints ORDER BY id")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(values, vec![(Some(10),), (None,)]);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_binds_shared_and_boxed_values() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let name = Arc::new(String::from("Ferris"));
+    let tag: Box<str> = "crab".into();
+
+    let (a, b, c): (String, String, i32) = sqlx::query_as("SELECT $1, $2, $3")
+        .bind(Arc::clone(&name))
+        .bind(tag)
+        .bind(Box::new(42_i32))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(a, *name);
+    assert_eq!(b, "crab");
+    assert_eq!(c, 42);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_reports_mismatched_parameter_types() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let res = sqlx::query("SELECT * FROM (VALUES (1)) AS t (id) WHERE id = $1")
+        .bind("1")
+        .execute(&mut conn)
+        .await;
+
+    match res {
+        Err(sqlx::Error::ParameterTypeMismatch {
+            index,
+            expected,
+            found,
+        }) => {
+            assert_eq!(index, 1);
+            assert_eq!(&*expected, "INT4");
+            assert_eq!(&*found, "TEXT");
+        }
+
+        res => panic!("expected a parameter type mismatch, got {:?}", res),
+    }
+
+    // the connection is still usable and the statement was not cached
+    let cnt = sqlx::query("SELECT * FROM (VALUES (1)) AS t (id) WHERE id = $1")
+        .bind(1_i32)
+        .execute(&mut conn)
+        .await?;
+
+    assert_eq!(cnt.rows_affected(), 1);
+
+    // with the check disabled the error from the server is returned as-is
+    conn.check_parameter_types(false);
+
+    let res = sqlx::query("SELECT * FROM (VALUES (2)) AS t (id) WHERE id = $1")
+        .bind("2")
+        .execute(&mut conn)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::Database(_))));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_reports_parameters_of_an_unknown_type() -> anyhow::Result<()> {
+    // a type declared with an OID that does not exist in `pg_type`
+    struct Bogus;
+
+    impl sqlx::Type<Postgres> for Bogus {
+        fn type_info() -> sqlx::postgres::PgTypeInfo {
+            sqlx::postgres::PgTypeInfo::with_oid(0x7FFF_FFF0)
+        }
+    }
+
+    impl sqlx::encode::Encode<Postgres> for Bogus {
+        fn encode(&self, buf: &mut Vec<u8>) {
+            buf.extend_from_slice(&1_i32.to_be_bytes());
+        }
+    }
+
+    let mut conn = new::<Postgres>().await?;
+
+    let res = sqlx::query("SELECT * FROM (VALUES (1)) AS t (id) WHERE id = $1")
+        .bind(Bogus)
+        .execute(&mut conn)
+        .await;
+
+    match res {
+        Err(sqlx::Error::ParameterTypeMismatch {
+            index,
+            expected,
+            found,
+        }) => {
+            assert_eq!(index, 1);
+            assert_eq!(&*expected, "INT4");
+            assert_eq!(&*found, "OID 2147483632");
+        }
+
+        res => panic!("expected a parameter type mismatch, got {:?}", res),
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_reports_mismatched_column_types() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let res: Result<(i64,), _> = sqlx::query_as("SELECT 'hello'::text")
+        .fetch_one(&mut conn)
+        .await;
+
+    let err = res.unwrap_err().to_string();
+
+    assert!(err.contains("`i64` (as SQL type INT8)"), "{}", err);
+    assert!(err.contains("SQL type TEXT"), "{}", err);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_inserts_multiple_rows_with_query_builder() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE tweets (id INT4 PRIMARY KEY, text TEXT, likes INT8)")
+        .await?;
+
+    let tweets = vec![
+        (1, "hello", Some(10_i64)),
+        (2, "world", None),
+        (3, "from", Some(0)),
+        (4, "a", Some(1_000)),
+        (5, "builder", None),
+    ];
+
+    let mut builder = PgQueryBuilder::new("INSERT INTO tweets (id, text, likes) ");
+
+    builder.push_values(&tweets, |mut row, (id, text, likes)| {
+        row.push_bind(*id).push_bind(*text).push_bind(*likes);
+    })?;
+
+    let cnt = builder.build().execute(&mut conn).await?;
+
+    assert_eq!(cnt.rows_affected(), 5);
+
+    let rows: Vec<(i32, String, Option<i64>)> =
+        sqlx::query_as("SELECT id, text, likes FROM tweets ORDER BY id")
+            .fetch_all(&mut conn)
+            .await?;
+
+    assert_eq!(rows.len(), 5);
+
+    for (row, tweet) in rows.iter().zip(&tweets) {
+        assert_eq!(row.0, tweet.0);
+        assert_eq!(row.1, tweet.1);
+        assert_eq!(row.2, tweet.2);
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_filters_with_sql_fragments() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let filter = |text: Option<&'static str>, min_likes: Option<i64>| -> sqlx::Result<PgSql> {
+        let mut filter = PgSql::new("TRUE")?;
+
+        if let Some(text) = text {
+            filter.push(" AND text LIKE ")?.push_bind(text);
+        }
+
+        if let Some(min_likes) = min_likes {
+            filter.push(" AND likes >= ")?.push_bind(min_likes);
+        }
+
+        Ok(filter)
+    };
+
+    for (text, min_likes, expected) in vec![
+        (None, None, vec![1, 2, 3]),
+        (Some("%o%"), None, vec![1, 2]),
+        (None, Some(5), vec![1, 3]),
+        (Some("%o%"), Some(5), vec![1]),
+    ] {
+        let mut builder = PgQueryBuilder::new(
+            "SELECT id FROM (VALUES (1, 'hello', 10::int8), (2, 'world', 0), (3, 'x', 5)) \
+             tweets (id, text, likes) WHERE id <= ",
+        );
+
+        builder
+            .push_bind(3)?
+            .push(" AND ")
+            .push_sql(filter(text, min_likes)?)?
+            .push(" ORDER BY id");
+
+        let ids: Vec<i32> = builder
+            .build()
+            .try_map(|row: PgRow| row.try_get(0))
+            .fetch_all(&mut conn)
+            .await?;
+
+        assert_eq!(ids, expected);
+    }
+
+    // user input can only be bound, never spliced into the fragment
+    let res = PgSql::new("text = 'it's'");
+
+    assert!(matches!(res, Err(sqlx::Error::InvalidSqlFragment { .. })));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_exports_results_as_csv() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let query = r#"
+SELECT * FROM (VALUES
+    (1, 'Smith, J', NULL),
+    (2, '', 'says "hi"'),
+    (3, 'Doe', 'line 1
+line 2')
+) AS t (id, name, bio)
+"#;
+
+    let expected = "id,name,bio\n\
+                    1,\"Smith, J\",\n\
+                    2,\"\",\"says \"\"hi\"\"\"\n\
+                    3,Doe,\"line 1\nline 2\"\n";
+
+    // unprepared queries receive values as text
+    let mut buf = Vec::new();
+    let rows = conn.fetch(query).write_csv(&mut buf).await?;
+
+    assert_eq!(rows, 3);
+    assert_eq!(String::from_utf8(buf)?, expected);
+
+    // prepared queries receive values in the binary format
+    let mut buf = Vec::new();
+    let rows = sqlx::query(query)
+        .fetch(&mut conn)
+        .write_csv(&mut buf)
+        .await?;
+
+    assert_eq!(rows, 3);
+    assert_eq!(String::from_utf8(buf)?, expected);
+
+    // the header is written even without rows
+    let mut buf = Vec::new();
+    let rows = conn
+        .fetch("SELECT 1 AS a, 2 AS b WHERE false")
+        .write_csv(&mut buf)
+        .await?;
+
+    assert_eq!(rows, 0);
+    assert_eq!(String::from_utf8(buf)?, "a,b\n");
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_inserts_rows_with_unnest() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE tweets (id INT4 PRIMARY KEY, text TEXT, likes INT8)")
+        .await?;
+
+    let ids: Vec<i32> = (0..1000).collect();
+    let texts: Vec<String> = ids.iter().map(|id| format!("tweet #{}", id)).collect();
+    let likes: Vec<Option<i64>> = ids
+        .iter()
+        .map(|&id| if id % 2 == 0 { Some(id.into()) } else { None })
+        .collect();
+
+    let mut builder = PgQueryBuilder::new("INSERT INTO tweets (id, text, likes) ");
+
+    builder.push_unnest(|columns| {
+        columns.push_bind(&ids).push_bind(&texts).push_bind(&likes);
+    })?;
+
+    let cnt = builder.build().execute(&mut conn).await?;
+
+    assert_eq!(cnt.rows_affected(), 1000);
+
+    let rows: Vec<(i32, String, Option<i64>)> =
+        sqlx::query_as("SELECT id, text, likes FROM tweets ORDER BY id")
+            .fetch_all(&mut conn)
+            .await?;
+
+    assert_eq!(rows.len(), 1000);
+
+    for (i, row) in rows.iter().enumerate() {
+        assert_eq!(row.0, ids[i]);
+        assert_eq!(row.1, texts[i]);
+        assert_eq!(row.2, likes[i]);
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_returns_affected_rows() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let affected = conn
+        .execute("CREATE TEMPORARY TABLE products (id INTEGER PRIMARY KEY, price INTEGER)")
+        .await?;
+
+    assert_eq!(affected.rows_affected(), 0);
+
+    let affected = conn
+        .execute("INSERT INTO products (id, price) VALUES (1, 10), (2, 20), (3, 30)")
+        .await?;
+
+    assert_eq!(affected.rows_affected(), 3);
+
+    let affected = sqlx::query("UPDATE products SET price = price + 1 WHERE id < $1")
+        .bind(3_i32)
+        .execute(&mut conn)
+        .await?;
+
+    assert_eq!(affected.rows_affected(), 2);
+
+    // DDL following DML must not report the count of the previous statement
+    let affected = conn
+        .execute("CREATE INDEX products_price ON products (price)")
+        .await?;
+
+    assert_eq!(affected.rows_affected(), 0);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_counts_statement_cache_hits() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let before = conn.statement_cache_stats();
 
-    for index in 1..=10_i32 {
-        let cnt = sqlx::query("INSERT INTO users (id) VALUES ($1)")
-            .bind(index)
-            .execute(&mut conn)
+    for _ in 0..2 {
+        let (value,): (i32,) = sqlx::query_as("SELECT $1::int4 + 1")
+            .bind(1_i32)
+            .fetch_one(&mut conn)
             .await?;
 
-        assert_eq!(cnt, 1);
+        assert_eq!(value, 2);
     }
 
-    let sum: i32 = sqlx::query("SELECT id FROM users")
-        .try_map(|row: PgRow| row.try_get::<i32, _>(0))
-        .fetch(&mut conn)
-        .try_fold(0_i32, |acc, x| async move { Ok(acc + x) })
-        .await?;
+    let after = conn.statement_cache_stats();
 
-    assert_eq!(sum, 55);
+    assert_eq!(after.prepared - before.prepared, 1);
+    assert_eq!(after.misses - before.misses, 1);
+    assert_eq!(after.hits - before.hits, 1);
+    assert_eq!(after.size - before.size, 1);
 
     Ok(())
 }
@@ -129,119 +891,539 @@ async fn it_can_work_with_transactions() -> anyhow::Result<()> {
         .fetch_one(&mut conn)
         .await?;
 
-    assert_eq!(count, 1);
+    assert_eq!(count, 1);
+
+    // begin .. (drop)
+
+    {
+        let mut tx = conn.begin().await?;
+
+        sqlx::query("INSERT INTO _sqlx_users_1922 (id) VALUES ($1)")
+            .bind(20_i32)
+            .execute(&mut tx)
+            .await?;
+    }
+
+    conn = new::<Postgres>().await?;
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM _sqlx_users_1922")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 1);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_rolls_back_a_transaction_closure_on_error() -> anyhow::Result<()> {
+    let pool = PgPool::builder()
+        .max_size(1)
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    pool.acquire()
+        .await?
+        .execute("CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    // the closure fails so the insert is rolled back
+
+    let res = pool
+        .acquire()
+        .await?
+        .transaction(|tx| {
+            Box::pin(async move {
+                sqlx::query("INSERT INTO users (id) VALUES ($1)")
+                    .bind(10_i32)
+                    .execute(&mut *tx)
+                    .await?;
+
+                Err::<(), _>(sqlx::Error::RowNotFound)
+            })
+        })
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::RowNotFound)));
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM users")
+        .fetch_one(&pool)
+        .await?;
+
+    assert_eq!(count, 0);
+
+    // a failed nested transaction only rolls back its save point
+
+    let (_, ()) = pool
+        .acquire()
+        .await?
+        .transaction(|tx| {
+            Box::pin(async move {
+                sqlx::query("INSERT INTO users (id) VALUES ($1)")
+                    .bind(20_i32)
+                    .execute(&mut *tx)
+                    .await?;
+
+                let res = tx
+                    .transaction(|tx| {
+                        Box::pin(async move {
+                            sqlx::query("INSERT INTO users (id) VALUES ($1)")
+                                .bind(30_i32)
+                                .execute(&mut *tx)
+                                .await?;
+
+                            Err::<(), _>(sqlx::Error::RowNotFound)
+                        })
+                    })
+                    .await;
+
+                assert!(res.is_err());
+
+                Ok::<_, sqlx::Error>(())
+            })
+        })
+        .await?;
+
+    let ids: Vec<i32> = sqlx::query_as("SELECT id FROM users")
+        .fetch_all(&pool)
+        .await?
+        .into_iter()
+        .map(|(id,): (i32,)| id)
+        .collect();
+
+    assert_eq!(ids, [20]);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_retries_a_transaction_on_a_serialization_failure() -> anyhow::Result<()> {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use sqlx::Retry;
+
+    let pool = PgPool::builder()
+        .max_size(1)
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    // a dropped transaction closes its connection, so this can not be a temporary table
+    pool.acquire()
+        .await?
+        .execute(
+            r#"
+CREATE TABLE IF NOT EXISTS retried_users (id INTEGER PRIMARY KEY);
+DELETE FROM retried_users;
+            "#,
+        )
+        .await?;
+
+    let pool = &pool;
+    let attempts = &AtomicU32::new(0);
+
+    // the first attempt fails as if it lost to a concurrent transaction
+
+    let id = sqlx::retry(|| async move {
+        let mut tx = pool.begin().await?;
+
+        sqlx::query("INSERT INTO retried_users (id) VALUES ($1)")
+            .bind(10_i32)
+            .execute(&mut tx)
+            .await?;
+
+        if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+            tx.execute(
+                "DO $$ BEGIN RAISE EXCEPTION 'could not serialize access' USING ERRCODE = 'serialization_failure'; END $$",
+            )
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(10_i32)
+    })
+    .await?;
+
+    assert_eq!(id, 10);
+    assert_eq!(attempts.load(Ordering::SeqCst), 2);
+
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM retried_users")
+        .fetch_one(pool)
+        .await?;
+
+    assert_eq!(count, 1);
+
+    // other errors are not retried
+
+    attempts.store(0, Ordering::SeqCst);
+
+    let res = sqlx::retry(|| async move {
+        attempts.fetch_add(1, Ordering::SeqCst);
+
+        sqlx::query("INSERT INTO retried_users (id) VALUES ($1)")
+            .bind(10_i32)
+            .execute(pool)
+            .await
+    })
+    .await;
+
+    let error = match res {
+        Err(sqlx::Error::Database(error)) => error,
+        res => panic!("expected a database error; got {:?}", res),
+    };
+
+    assert_eq!(error.code(), Some("23505"));
+    assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+    // the last error is returned once the attempts run out
+
+    attempts.store(0, Ordering::SeqCst);
+
+    let res = Retry::new()
+        .max_attempts(3)
+        .initial_backoff(Duration::from_millis(1))
+        .run(|| async move {
+            attempts.fetch_add(1, Ordering::SeqCst);
+
+            sqlx::query(
+                "DO $$ BEGIN RAISE EXCEPTION 'deadlock' USING ERRCODE = 'deadlock_detected'; END $$",
+            )
+            .execute(pool)
+            .await
+        })
+        .await;
+
+    let error = match res {
+        Err(sqlx::Error::Database(error)) => error,
+        res => panic!("expected a database error; got {:?}", res),
+    };
+
+    assert_eq!(error.code(), Some("40P01"));
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_can_work_with_nested_transactions() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TABLE IF NOT EXISTS _sqlx_users_2523 (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    conn.execute("TRUNCATE _sqlx_users_2523").await?;
+
+    // begin
+    let mut tx = conn.begin().await?;
+
+    // insert a user
+    sqlx::query("INSERT INTO _sqlx_users_2523 (id) VALUES ($1)")
+        .bind(50_i32)
+        .execute(&mut tx)
+        .await?;
+
+    // begin once more
+    let mut tx = tx.begin().await?;
+
+    // insert another user
+    sqlx::query("INSERT INTO _sqlx_users_2523 (id) VALUES ($1)")
+        .bind(10_i32)
+        .execute(&mut tx)
+        .await?;
+
+    // never mind, rollback
+    let mut tx = tx.rollback().await?;
+
+    // did we really?
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM _sqlx_users_2523")
+        .fetch_one(&mut tx)
+        .await?;
+
+    assert_eq!(count, 1);
+
+    // actually, commit
+    let mut conn = tx.commit().await?;
+
+    // did we really?
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM _sqlx_users_2523")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 1);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_can_rollback_nested_transactions() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TABLE IF NOT EXISTS _sqlx_users_512412 (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    conn.execute("TRUNCATE _sqlx_users_512412").await?;
+
+    // begin
+    let mut tx = conn.begin().await?;
+
+    // insert a user
+    sqlx::query("INSERT INTO _sqlx_users_512412 (id) VALUES ($1)")
+        .bind(50_i32)
+        .execute(&mut tx)
+        .await?;
+
+    // begin once more
+    let mut tx = tx.begin().await?;
+
+    // insert another user
+    sqlx::query("INSERT INTO _sqlx_users_512412 (id) VALUES ($1)")
+        .bind(10_i32)
+        .execute(&mut tx)
+        .await?;
+
+    // stop the phone, drop the entire transaction
+    tx.close().await?;
+
+    // did we really?
+    let mut conn = new::<Postgres>().await?;
+    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM _sqlx_users_512412")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 0);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn pool_replaces_broken_connection() -> anyhow::Result<()> {
+    let pool = PgPool::builder()
+        .max_size(1)
+        .test_on_acquire(true)
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let (pid,): (i32,) = sqlx::query_as("SELECT pg_backend_pid()")
+        .fetch_one(&pool)
+        .await?;
+
+    // terminate the pooled connection from the server side, closing its socket
+    let mut conn = new::<Postgres>().await?;
+
+    sqlx::query("SELECT pg_terminate_backend($1)")
+        .bind(pid)
+        .execute(&mut conn)
+        .await?;
+
+    // the dead connection should be replaced instead of handed out
+    let (new_pid,): (i32,) = sqlx::query_as("SELECT pg_backend_pid()")
+        .fetch_one(&pool)
+        .await?;
+
+    assert_ne!(pid, new_pid);
+    assert_eq!(pool.size(), 1);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn pool_executes_and_releases_connection_on_error() -> anyhow::Result<()> {
+    let pool = PgPool::builder()
+        .max_size(1)
+        .connect_timeout(Duration::from_secs(5))
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let res = sqlx::query("SELECT 1 / 0").execute(&pool).await;
+
+    assert!(matches!(res, Err(sqlx::Error::Database(_))));
+
+    let res: Result<(i32,), _> = sqlx::query_as("SELECT 1 / $1")
+        .bind(0_i32)
+        .fetch_one(&pool)
+        .await;
+
+    assert!(matches!(res, Err(sqlx::Error::Database(_))));
+
+    // the only connection was released back to the pool after each error
+    let (value,): (i32,) = sqlx::query_as("SELECT 1 + $1")
+        .bind(1_i32)
+        .fetch_one(&pool)
+        .await?;
+
+    assert_eq!(value, 2);
+    assert_eq!(pool.size(), 1);
+    assert_eq!(pool.idle(), 1);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn pool_resets_connection_on_release() -> anyhow::Result<()> {
+    let pool = PgPool::builder()
+        .max_size(1)
+        .reset_on_release(true)
+        .build(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    let mut conn = pool.acquire().await?;
+
+    let (pid,): (i32,) = sqlx::query_as("SELECT pg_backend_pid()")
+        .fetch_one(&mut conn)
+        .await?;
+
+    conn.execute("SET application_name = 'sqlx_reset_test'")
+        .await?;
+
+    // leave a transaction open
+    conn.execute("BEGIN").await?;
 
-    // begin .. (drop)
+    drop(conn);
 
-    {
-        let mut tx = conn.begin().await?;
+    let mut conn = pool.acquire().await?;
 
-        sqlx::query("INSERT INTO _sqlx_users_1922 (id) VALUES ($1)")
-            .bind(20_i32)
-            .execute(&mut tx)
+    // the same connection was reset and handed out again
+    let (new_pid, name): (i32, String) =
+        sqlx::query_as("SELECT pg_backend_pid(), current_setting('application_name')")
+            .fetch_one(&mut conn)
             .await?;
-    }
-
-    conn = new::<Postgres>().await?;
 
-    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM _sqlx_users_1922")
-        .fetch_one(&mut conn)
-        .await?;
+    assert_eq!(pid, new_pid);
+    assert_ne!(name, "sqlx_reset_test");
 
-    assert_eq!(count, 1);
+    // the statement cache was cleared along with the deallocated statements, so only
+    // the query above is cached
+    assert_eq!(conn.statement_cache_stats().size, 1);
 
     Ok(())
 }
 
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
-async fn it_can_work_with_nested_transactions() -> anyhow::Result<()> {
-    let mut conn = new::<Postgres>().await?;
-
-    conn.execute("CREATE TABLE IF NOT EXISTS _sqlx_users_2523 (id INTEGER PRIMARY KEY)")
+async fn pool_opens_min_size_connections_on_build() -> anyhow::Result<()> {
+    let pool = PgPool::builder()
+        .min_size(3)
+        .max_size(10)
+        .build(&dotenv::var("DATABASE_URL")?)
         .await?;
 
-    conn.execute("TRUNCATE _sqlx_users_2523").await?;
+    assert_eq!(pool.size(), 3);
+    assert_eq!(pool.idle(), 3);
 
-    // begin
-    let mut tx = conn.begin().await?;
+    // holding three connections at once does not open any more
+    let mut conns = Vec::new();
+    let mut pids = Vec::new();
 
-    // insert a user
-    sqlx::query("INSERT INTO _sqlx_users_2523 (id) VALUES ($1)")
-        .bind(50_i32)
-        .execute(&mut tx)
-        .await?;
+    for _ in 0..3 {
+        let mut conn = pool.acquire().await?;
 
-    // begin once more
-    let mut tx = tx.begin().await?;
+        let (pid,): (i32,) = sqlx::query_as("SELECT pg_backend_pid()")
+            .fetch_one(&mut conn)
+            .await?;
 
-    // insert another user
-    sqlx::query("INSERT INTO _sqlx_users_2523 (id) VALUES ($1)")
-        .bind(10_i32)
-        .execute(&mut tx)
-        .await?;
+        conns.push(conn);
+        pids.push(pid);
+    }
 
-    // never mind, rollback
-    let mut tx = tx.rollback().await?;
+    pids.sort();
+    pids.dedup();
 
-    // did we really?
-    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM _sqlx_users_2523")
-        .fetch_one(&mut tx)
-        .await?;
+    assert_eq!(pids.len(), 3);
+    assert_eq!(pool.size(), 3);
 
-    assert_eq!(count, 1);
+    Ok(())
+}
 
-    // actually, commit
-    let mut conn = tx.commit().await?;
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn pool_builds_lazily() -> anyhow::Result<()> {
+    let url = dotenv::var("DATABASE_URL")?;
 
-    // did we really?
-    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM _sqlx_users_2523")
-        .fetch_one(&mut conn)
+    let pool = PgPool::builder().min_size(3).build_lazy(&url);
+    assert_eq!(pool.size(), 0);
+
+    let _conn = pool.acquire().await?;
+    assert_eq!(pool.size(), 1);
+
+    // a pool that can not reach its minimum size is only built if that is not required
+    let missing_db = format!("{}_sqlx_missing", url);
+
+    assert!(PgPool::builder()
+        .min_size(1)
+        .build(&missing_db)
+        .await
+        .is_err());
+
+    let pool = PgPool::builder()
+        .min_size(1)
+        .require_min_size(false)
+        .build(&missing_db)
         .await?;
 
-    assert_eq!(count, 1);
+    assert_eq!(pool.size(), 0);
+    assert!(pool.acquire().await.is_err());
 
     Ok(())
 }
 
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
-async fn it_can_rollback_nested_transactions() -> anyhow::Result<()> {
-    let mut conn = new::<Postgres>().await?;
-
-    conn.execute("CREATE TABLE IF NOT EXISTS _sqlx_users_512412 (id INTEGER PRIMARY KEY)")
+async fn pool_replaces_many_broken_connections() -> anyhow::Result<()> {
+    let pool = PgPool::builder()
+        .min_size(5)
+        .max_size(5)
+        .test_on_acquire(true)
+        // only failures to open a connection count towards the attempts
+        .reconnect_attempts(0)
+        .build(&dotenv::var("DATABASE_URL")?)
         .await?;
 
-    conn.execute("TRUNCATE _sqlx_users_512412").await?;
+    // more idle connections go stale than there are reconnect attempts, as after a failover
+    let mut held = Vec::new();
 
-    // begin
-    let mut tx = conn.begin().await?;
+    for _ in 0..5 {
+        let mut conn = pool.acquire().await?;
+        let (pid,): (i32,) = sqlx::query_as("SELECT pg_backend_pid()")
+            .fetch_one(&mut conn)
+            .await?;
 
-    // insert a user
-    sqlx::query("INSERT INTO _sqlx_users_512412 (id) VALUES ($1)")
-        .bind(50_i32)
-        .execute(&mut tx)
-        .await?;
+        held.push((conn, pid));
+    }
 
-    // begin once more
-    let mut tx = tx.begin().await?;
+    let mut killer = new::<Postgres>().await?;
 
-    // insert another user
-    sqlx::query("INSERT INTO _sqlx_users_512412 (id) VALUES ($1)")
-        .bind(10_i32)
-        .execute(&mut tx)
-        .await?;
+    for (_, pid) in &held {
+        sqlx::query("SELECT pg_terminate_backend($1)")
+            .bind(*pid)
+            .execute(&mut killer)
+            .await?;
+    }
 
-    // stop the phone, drop the entire transaction
-    tx.close().await?;
+    let old_pids: Vec<i32> = held.iter().map(|(_, pid)| *pid).collect();
 
-    // did we really?
-    let mut conn = new::<Postgres>().await?;
-    let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM _sqlx_users_512412")
-        .fetch_one(&mut conn)
-        .await?;
+    drop(held);
 
-    assert_eq!(count, 0);
+    // every acquire still succeeds with a fresh connection
+    let mut held = Vec::new();
+
+    for _ in 0..5 {
+        let mut conn = pool.acquire().await?;
+        let (pid,): (i32,) = sqlx::query_as("SELECT pg_backend_pid()")
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert!(!old_pids.contains(&pid));
+        held.push(conn);
+    }
+
+    assert_eq!(pool.size(), 5);
 
     Ok(())
 }
@@ -361,3 +1543,354 @@ async fn test_describe() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_gets_columns_by_index_and_name() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let mut cursor = conn.fetch("SELECT 1 AS id, 'alice' AS name");
+    let row = cursor.next().await?.unwrap();
+
+    let name = String::from("name");
+
+    assert_eq!(row.try_get::<String, _>(1)?, "alice");
+    assert_eq!(row.try_get::<String, _>("name")?, "alice");
+    assert_eq!(row.try_get::<String, _>(&name)?, "alice");
+    assert_eq!(row.try_get::<String, _>(name)?, "alice");
+
+    assert!(matches!(
+        row.try_get::<String, _>(2),
+        Err(sqlx::Error::ColumnIndexOutOfBounds { index: 2, len: 2 })
+    ));
+
+    assert!(matches!(
+        row.try_get::<String, _>("email"),
+        Err(sqlx::Error::ColumnNotFound(ref name)) if &**name == "email"
+    ));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_describes_the_columns_of_a_row() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let mut cursor = sqlx::query("SELECT 1::int4 AS id, $1::text AS name, true AS active")
+        .bind("alice")
+        .fetch(&mut conn);
+
+    let row = cursor.next().await?.unwrap();
+
+    let columns: Vec<_> = (0..row.len())
+        .map(|index| {
+            let column = row.column(index).unwrap();
+
+            (
+                column.ordinal(),
+                column.name().map(ToOwned::to_owned),
+                column.type_info().map(|info| info.name().to_owned()),
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        columns,
+        [
+            (0, Some("id".to_owned()), Some("INT4".to_owned())),
+            (1, Some("name".to_owned()), Some("TEXT".to_owned())),
+            (2, Some("active".to_owned()), Some("BOOL".to_owned())),
+        ]
+    );
+
+    assert!(row.column(3).is_none());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_decodes_rows_into_maps() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let map: HashMap<String, Option<String>> =
+        sqlx::query_as("SELECT 'alice' AS name, NULL::text AS email, 'admin' AS role")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(map.len(), 3);
+    assert_eq!(map["name"].as_deref(), Some("alice"));
+    assert_eq!(map["email"], None);
+    assert_eq!(map["role"].as_deref(), Some("admin"));
+
+    let mut cursor = conn.fetch("SELECT 'first' AS name, 'second' AS name");
+    let row = cursor.next().await?.unwrap();
+
+    let map: HashMap<String, String> = row.try_get_map(DuplicateColumns::LastWins)?;
+    assert_eq!(map.len(), 1);
+    assert_eq!(map["name"], "second");
+
+    assert!(row.try_get_map::<String>(DuplicateColumns::Error).is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_reports_the_position_of_an_error() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let error = match conn.execute("SELECT * FORM users").await {
+        Err(sqlx::Error::Database(error)) => error,
+        res => panic!("expected a database error; got {:?}", res),
+    };
+
+    assert_eq!(error.position(), Some(10));
+    assert_eq!(error.to_string(), "syntax error at or near \"FORM\"");
+
+    conn.include_query_in_errors(true);
+
+    let error = match sqlx::query("SELECT $1::int4 FORM users")
+        .bind(1_i32)
+        .execute(&mut conn)
+        .await
+    {
+        Err(sqlx::Error::Database(error)) => error,
+        res => panic!("expected a database error; got {:?}", res),
+    };
+
+    assert_eq!(error.position(), Some(22));
+    assert_eq!(
+        error.to_string(),
+        "syntax error at or near \"users\"\nLINE 1: SELECT $1::int4 FORM users\n                             ^"
+    );
+
+    Ok(())
+}
+
+#[cfg(all(feature = "serde", feature = "json"))]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_serializes_a_row() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let mut cursor = conn.fetch(
+        sqlx::query(
+            "SELECT $1::int4 AS id, 'alice'::text AS name, true AS active, 2.5::float8 AS score, \
+             '\\xdeadbeef'::bytea AS data, NULL::text AS note, '{\"a\": [1, 2]}'::jsonb AS meta, \
+             '1.50'::numeric AS price",
+        )
+        .bind(7_i32),
+    );
+
+    let row = cursor.next().await?.unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&row)?,
+        serde_json::json!({
+            "id": 7,
+            "name": "alice",
+            "active": true,
+            "score": 2.5,
+            "data": "3q2+7w==",
+            "note": null,
+            "meta": { "a": [1, 2] },
+            // a type without a mapping is serialized as its raw (binary) value
+            "price": "AAIAAAAAAAIAAROI",
+        })
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_pipelines_queries() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE pipelined (id INT PRIMARY KEY, name TEXT)")
+        .await?;
+
+    let results = conn
+        .pipeline()
+        .push(
+            sqlx::query("INSERT INTO pipelined VALUES ($1, $2)")
+                .bind(1)
+                .bind("a"),
+        )
+        .push(
+            sqlx::query("INSERT INTO pipelined VALUES ($1, $2)")
+                .bind(2)
+                .bind("b"),
+        )
+        .push(sqlx::query("INSERT INTO pipelined VALUES (3, 'c')"))
+        .execute()
+        .await?;
+
+    let rows: Vec<_> = results
+        .iter()
+        .map(|result| result.rows_affected())
+        .collect();
+    assert_eq!(rows, [1, 1, 1]);
+
+    // a failing query rolls back the whole pipeline
+    let err = conn
+        .pipeline()
+        .push(sqlx::query("INSERT INTO pipelined VALUES (4, 'd')"))
+        .push(sqlx::query("INSERT INTO pipelined VALUES (1, 'duplicate')"))
+        .push(sqlx::query("INSERT INTO pipelined VALUES (5, 'e')"))
+        .execute()
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("duplicate key"), "{}", err);
+
+    let names: Vec<(String,)> = sqlx::query_as("SELECT name FROM pipelined ORDER BY id")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(
+        names,
+        vec![("a".to_owned(),), ("b".to_owned(),), ("c".to_owned(),)]
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_reads_opaque_catalog_arrays_as_text() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    // `aclitem` has no binary output function, so `relacl` can only be received as text
+    let (acl,): (Vec<String>,) = sqlx::query_as(
+        "SELECT relacl FROM pg_class WHERE relacl IS NOT NULL AND relkind = $1 LIMIT 1",
+    )
+    .bind("r")
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert!(!acl.is_empty());
+    assert!(acl.iter().all(|item| item.contains('=')));
+
+    // the statement is cached with its result formats
+    let (acl_again,): (Vec<String>,) = sqlx::query_as(
+        "SELECT relacl FROM pg_class WHERE relacl IS NOT NULL AND relkind = $1 LIMIT 1",
+    )
+    .bind("r")
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(acl, acl_again);
+
+    let mut cursor = conn.fetch("SELECT relacl FROM pg_class WHERE relacl IS NOT NULL LIMIT 1");
+    let row = cursor.next().await?.unwrap();
+    let acl: Vec<String> = row.try_get(0)?;
+
+    assert!(acl.iter().all(|item| item.contains('=')));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_runs_a_simple_query() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let results = conn
+        .simple_query("SELECT 1; SELECT 'a' AS name, null")
+        .await?;
+
+    assert_eq!(results.len(), 2);
+
+    assert_eq!(results[0].command_tag(), "SELECT 1");
+    assert_eq!(results[0].rows().len(), 1);
+    assert_eq!(results[0].rows()[0].values(), &[Some("1".to_owned())]);
+
+    assert_eq!(
+        results[1].columns().collect::<Vec<_>>(),
+        vec![Some("name"), None]
+    );
+    assert_eq!(results[1].rows().len(), 1);
+    assert_eq!(results[1].rows()[0].get(0), Some("a"));
+    assert_eq!(results[1].rows()[0].get(1), None);
+
+    // the connection is usable after an error
+    assert!(conn.simple_query("SELECT 1; SELECT 1 / 0").await.is_err());
+
+    let results = conn.simple_query("").await?;
+
+    assert!(results.is_empty());
+
+    let (value,): (i32,) = sqlx::query_as("SELECT $1")
+        .bind(5_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 5);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_times_out_waiting_for_a_row_lock() -> anyhow::Result<()> {
+    let url = dotenv::var("DATABASE_URL")?;
+    let separator = if url.contains('?') { '&' } else { '?' };
+
+    let mut holder = new::<Postgres>().await?;
+
+    // the row is locked from another session, so this can not be a temporary table
+    holder
+        .execute(
+            r#"
+CREATE TABLE IF NOT EXISTS locked_accounts (id INTEGER PRIMARY KEY, balance INTEGER NOT NULL);
+INSERT INTO locked_accounts (id, balance) VALUES (1, 0) ON CONFLICT (id) DO NOTHING;
+            "#,
+        )
+        .await?;
+
+    let mut waiter =
+        PgConnection::connect(format!("{}{}lock_timeout=100ms", url, separator)).await?;
+
+    let (lock_timeout,): (String,) = sqlx::query_as("SHOW lock_timeout")
+        .fetch_one(&mut waiter)
+        .await?;
+
+    assert_eq!(lock_timeout, "100ms");
+
+    let mut tx = holder.begin().await?;
+
+    sqlx::query("SELECT balance FROM locked_accounts WHERE id = $1 FOR UPDATE")
+        .bind(1_i32)
+        .execute(&mut tx)
+        .await?;
+
+    let mut waiting = waiter.begin().await?;
+
+    let res = sqlx::query("UPDATE locked_accounts SET balance = balance + 1 WHERE id = $1")
+        .bind(1_i32)
+        .execute(&mut waiting)
+        .await;
+
+    let error = match res {
+        Err(sqlx::Error::LockTimeout(error)) => error,
+        res => panic!("expected a lock timeout; got {:?}", res),
+    };
+
+    assert_eq!(error.code(), Some("55P03"));
+
+    let mut waiter = waiting.rollback().await?;
+    tx.commit().await?;
+
+    // the lock has been released, and the statement that timed out can be run again
+    let done = sqlx::query("UPDATE locked_accounts SET balance = balance + 1 WHERE id = $1")
+        .bind(1_i32)
+        .execute(&mut waiter)
+        .await?;
+
+    assert_eq!(done.rows_affected(), 1);
+
+    Ok(())
+}
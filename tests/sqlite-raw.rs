@@ -2,6 +2,7 @@
 
 use sqlx::{Cursor, Executor, Row, Sqlite};
 use sqlx_test::new;
+use std::io::Read;
 
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
@@ -50,3 +51,35 @@ SELECT id, text FROM _sqlx_test;
 
     Ok(())
 }
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_read_large_text_in_chunks() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    // 1,000,000 characters of '0'
+    let mut cursor = conn.fetch("SELECT hex(zeroblob(500000)), 10");
+    let row = cursor.next().await?.unwrap();
+
+    let mut reader = row.try_get_raw(0)?.reader().unwrap();
+    let mut chunk = [0_u8; 65536];
+    let mut len = 0;
+
+    loop {
+        let n = reader.read(&mut chunk)?;
+
+        if n == 0 {
+            break;
+        }
+
+        assert!(chunk[..n].iter().all(|&b| b == b'0'));
+        len += n;
+    }
+
+    assert_eq!(len, 1_000_000);
+
+    // only TEXT and BLOB values can be read
+    assert!(row.try_get_raw(1)?.reader().is_none());
+
+    Ok(())
+}
@@ -1,5 +1,7 @@
-use sqlx::Sqlite;
-use sqlx_test::test_type;
+use std::num::NonZeroI64;
+
+use sqlx::{sqlite::SqliteQueryAs, Cursor, Executor, Row, Sqlite};
+use sqlx_test::{new, test_type};
 
 test_type!(null(
     Sqlite,
@@ -9,10 +11,63 @@ test_type!(null(
 
 test_type!(bool(Sqlite, bool, "FALSE" == false, "TRUE" == true));
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_bool_representations() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let (stored_as,): (String,) = sqlx::query_as("SELECT typeof(?)")
+        .bind(true)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(stored_as, "integer");
+
+    let mut cursor = conn.fetch("SELECT 0, 1, 2, -1, '0', '1', 'true', 'FALSE', 't', 'f', 'yes'");
+    let row = cursor.next().await?.unwrap();
+
+    let values = (0..10)
+        .map(|index| row.try_get::<bool, _>(index))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    assert_eq!(
+        values,
+        [false, true, true, true, false, true, true, false, true, false]
+    );
+
+    assert!(row.try_get::<bool, _>(10).is_err());
+
+    Ok(())
+}
+
 test_type!(i32(Sqlite, i32, "94101" == 94101_i32));
 
 test_type!(i64(Sqlite, i64, "9358295312" == 9358295312_i64));
 
+test_type!(non_zero_i64(
+    Sqlite,
+    NonZeroI64,
+    "9358295312" == NonZeroI64::new(9358295312).unwrap(),
+    "-1" == NonZeroI64::new(-1).unwrap()
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_zero_does_not_decode_as_non_zero() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let res: Result<(NonZeroI64,), _> = sqlx::query_as("SELECT ?")
+        .bind(0_i64)
+        .fetch_one(&mut conn)
+        .await;
+
+    let err = res.unwrap_err();
+    assert!(matches!(err, sqlx::Error::Decode(_)), "{:?}", err);
+    assert_eq!(err.to_string(), "zero cannot be decoded as NonZeroI64");
+
+    Ok(())
+}
+
 // NOTE: This behavior can be surprising. Floating-point parameters are widening to double which can
 //       result in strange rounding.
 test_type!(f32(
@@ -34,6 +89,28 @@ test_type!(string(
     "''" == ""
 ));
 
+test_type!(char(
+    Sqlite,
+    char,
+    "'a'" == 'a',
+    "'é'" == 'é',
+    "'漢'" == '漢'
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_char_from_longer_string() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let mut cursor = conn.fetch("SELECT 'ab'");
+    let row = cursor.next().await?.unwrap();
+
+    let err = row.try_get::<char, _>(0).unwrap_err().to_string();
+    assert!(err.contains("expected a single character"), "{}", err);
+
+    Ok(())
+}
+
 test_type!(bytes(
     Sqlite,
     Vec<u8>,
@@ -44,3 +121,44 @@ test_type!(bytes(
     "X'0000000052'"
         == vec![0_u8, 0, 0, 0, 0x52]
 ));
+
+#[cfg(feature = "bytes")]
+test_type!(bytes_bytes(
+    Sqlite,
+    sqlx::types::Bytes,
+    "X'DEADBEEF'" == sqlx::types::Bytes::from_static(&[0xDE, 0xAD, 0xBE, 0xEF]),
+    "X''" == sqlx::types::Bytes::new()
+));
+
+test_type!(boxed_str(
+    Sqlite,
+    Box<str>,
+    "'this is foo'" == Box::<str>::from("this is foo"),
+    "''" == Box::<str>::from("")
+));
+
+test_type!(boxed_bytes(
+    Sqlite,
+    Box<[u8]>,
+    "X'DEADBEEF'" == Box::<[u8]>::from(&[0xDE_u8, 0xAD, 0xBE, 0xEF][..])
+));
+
+test_type!(fixed_size_bytes(
+    Sqlite,
+    [u8; 4],
+    "X'DEADBEEF'" == [0xDE_u8, 0xAD, 0xBE, 0xEF]
+));
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn test_fixed_size_bytes_wrong_length() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let mut cursor = conn.fetch("SELECT X'DEADBEEF'");
+    let row = cursor.next().await?.unwrap();
+
+    assert!(row.try_get::<[u8; 3], _>(0).is_err());
+    assert!(row.try_get::<[u8; 5], _>(0).is_err());
+
+    Ok(())
+}
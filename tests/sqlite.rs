@@ -1,6 +1,11 @@
 use futures::TryStreamExt;
-use sqlx::{sqlite::SqliteQueryAs, Connect, Connection, Executor, Sqlite, SqliteConnection};
+use sqlx::types::TypeInfo;
+use sqlx::{
+    sqlite::SqliteQueryAs, Connect, Connection, Cursor, Executor, Row, Sqlite, SqliteConnection,
+    SqlitePool, Type,
+};
 use sqlx_test::new;
+use std::sync::Arc;
 
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
@@ -56,7 +61,7 @@ CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY)
             .execute(&mut conn)
             .await?;
 
-        assert_eq!(cnt, 1);
+        assert_eq!(cnt.rows_affected(), 1);
     }
 
     let sum: i32 = sqlx::query_as("SELECT id FROM users")
@@ -69,6 +74,231 @@ CREATE TEMPORARY TABLE users (id INTEGER PRIMARY KEY)
     Ok(())
 }
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_returns_affected_rows() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let affected = conn
+        .execute("CREATE TEMPORARY TABLE products (id INTEGER PRIMARY KEY, price INTEGER)")
+        .await?;
+
+    assert_eq!(affected.rows_affected(), 0);
+
+    let affected = conn
+        .execute("INSERT INTO products (id, price) VALUES (1, 10), (2, 20), (3, 30)")
+        .await?;
+
+    assert_eq!(affected.rows_affected(), 3);
+
+    let affected = sqlx::query("UPDATE products SET price = price + 1 WHERE id < ?")
+        .bind(3_i32)
+        .execute(&mut conn)
+        .await?;
+
+    assert_eq!(affected.rows_affected(), 2);
+
+    // DDL following DML must not report the count of the previous statement
+    let affected = conn
+        .execute("CREATE INDEX products_price ON products (price)")
+        .await?;
+
+    assert_eq!(affected.rows_affected(), 0);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_returns_the_last_insert_rowid() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE notes (id INTEGER PRIMARY KEY, body TEXT)")
+        .await?;
+
+    let result = conn
+        .execute("INSERT INTO notes (id, body) VALUES (41, 'a'), (42, 'b')")
+        .await?;
+
+    assert_eq!(result.rows_affected(), 2);
+    assert_eq!(result.last_insert_rowid(), Some(42));
+    assert_eq!(result.last_insert_id(), None);
+    assert_eq!(result.command_tag(), None);
+    assert_eq!(u64::from(result), 2);
+
+    let result = sqlx::query("INSERT INTO notes (body) VALUES (?)")
+        .bind("c")
+        .execute(&mut conn)
+        .await?;
+
+    assert_eq!(result.last_insert_rowid(), Some(43));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_caches_statements() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    for i in 0..2_i32 {
+        let (value,): (i32,) = sqlx::query_as("SELECT ?")
+            .bind(i)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(value, i);
+    }
+
+    let stats = conn.statement_cache_stats();
+
+    assert_eq!(stats.prepared, 1);
+    assert_eq!(stats.hits, 1);
+    assert_eq!(stats.size, 1);
+
+    // a statement that fails to execute is not kept around
+    let result = sqlx::query("INSERT INTO _sqlx_missing (id) VALUES (?)")
+        .bind(1_i32)
+        .execute(&mut conn)
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(conn.statement_cache_stats().size, 1);
+
+    // the least recently used statement is evicted once the cache is full
+    conn.statement_cache_capacity(1);
+
+    let _: (i32,) = sqlx::query_as("SELECT ? + 1")
+        .bind(1_i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    let stats = conn.statement_cache_stats();
+
+    assert_eq!(stats.size, 1);
+    assert_eq!(stats.hits, 1);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_binds_optional_values() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let name = String::from("Ferris");
+
+    let (a, b, c): (Option<String>, Option<String>, Option<i32>) = sqlx::query_as("SELECT ?, ?, ?")
+        .bind(Some(&*name))
+        .bind(None::<&str>)
+        .bind(Some(None::<i32>))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(a.as_deref(), Some("Ferris"));
+    assert_eq!(b, None);
+    assert_eq!(c, None);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_binds_and_decodes_optional_ints() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    // an `Option<T>` has the SQL type of `T`
+    assert!(
+        <Option<i32> as Type<Sqlite>>::type_info().compatible(&<i32 as Type<Sqlite>>::type_info())
+    );
+
+    conn.execute("CREATE TEMPORARY TABLE optional_ints (id INTEGER PRIMARY KEY, value INTEGER)")
+        .await?;
+
+    for (id, value) in vec![(1_i32, Some(10_i32)), (2, None)] {
+        sqlx::query("INSERT INTO optional_ints (id, value) VALUES (?, ?)")
+            .bind(id)
+            .bind(value)
+            .execute(&mut conn)
+            .await?;
+    }
+
+    let values: Vec<(Option<i32>,)> = sqlx::query_as("SELECT value FROM optional_ints ORDER BY id")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(values, vec![(Some(10),), (None,)]);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_binds_shared_and_boxed_values() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let name = Arc::new(String::from("Ferris"));
+
+    let (a, b): (String, i32) = sqlx::query_as("SELECT ?, ?")
+        .bind(Arc::clone(&name))
+        .bind(Box::new(42_i32))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(a, *name);
+    assert_eq!(b, 42);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_decodes_tuples_from_columns() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let (a,): (i32,) = sqlx::query_as("SELECT 1").fetch_one(&mut conn).await?;
+    assert_eq!(a, 1);
+
+    let (a, b): (i32, String) = sqlx::query_as("SELECT 1, 'two'")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!((a, &*b), (1, "two"));
+
+    let (a, b, c): (i32, String, f64) = sqlx::query_as("SELECT 1, 'two', 3.5")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!((a, &*b, c), (1, "two", 3.5));
+
+    let (a, b, c, d): (i32, String, f64, Option<i64>) =
+        sqlx::query_as("SELECT 1, 'two', 3.5, NULL")
+            .fetch_one(&mut conn)
+            .await?;
+    assert_eq!((a, &*b, c, d), (1, "two", 3.5, None));
+
+    // the number of columns must match the arity of the tuple
+    let res: Result<(i32, i32), _> = sqlx::query_as("SELECT 1, 2, 3").fetch_one(&mut conn).await;
+
+    assert!(matches!(
+        res,
+        Err(sqlx::Error::ColumnCountMismatch {
+            expected: 2,
+            found: 3
+        })
+    ));
+
+    let res: Result<(i32, i32), _> = sqlx::query_as("SELECT 1").fetch_one(&mut conn).await;
+
+    assert!(matches!(
+        res,
+        Err(sqlx::Error::ColumnCountMismatch {
+            expected: 2,
+            found: 1
+        })
+    ));
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
 async fn it_can_execute_multiple_statements() -> anyhow::Result<()> {
@@ -83,7 +313,7 @@ INSERT INTO users DEFAULT VALUES;
         )
         .await?;
 
-    assert_eq!(affected, 1);
+    assert_eq!(affected.rows_affected(), 1);
 
     for index in 2..5_i32 {
         let (id, other): (i32, i32) = sqlx::query_as(
@@ -103,6 +333,62 @@ SELECT id, other FROM users WHERE id = last_insert_rowid();
     Ok(())
 }
 
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_fetches_a_scalar() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let count: i64 = conn
+        .fetch_scalar("SELECT COUNT(*) FROM (SELECT 1 UNION ALL SELECT 2)")
+        .await?;
+
+    assert_eq!(count, 2);
+
+    let res = conn.fetch_scalar::<i64, _>("SELECT 1 WHERE 0").await;
+
+    assert!(matches!(res, Err(sqlx::Error::RowNotFound)));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_describes_the_columns_of_a_row() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let mut cursor = sqlx::query("SELECT 1 AS id, ? AS name, NULL AS score")
+        .bind("alice")
+        .fetch(&mut conn);
+
+    let row = cursor.next().await?.unwrap();
+
+    let columns: Vec<_> = (0..row.len())
+        .map(|index| {
+            let column = row.column(index).unwrap();
+
+            (
+                column.ordinal(),
+                column.name().map(ToOwned::to_owned),
+                column.type_info().map(|info| info.name().to_owned()),
+            )
+        })
+        .collect();
+
+    // the type is that of the value, so there is none for a NULL
+    assert_eq!(
+        columns,
+        [
+            (0, Some("id".to_owned()), Some("INTEGER".to_owned())),
+            (1, Some("name".to_owned()), Some("TEXT".to_owned())),
+            (2, Some("score".to_owned()), None),
+        ]
+    );
+
+    assert!(row.column(3).is_none());
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "runtime-async-std", async_std::test)]
 #[cfg_attr(feature = "runtime-tokio", tokio::test)]
 async fn it_describes() -> anyhow::Result<()> {
@@ -199,3 +485,460 @@ CREATE TEMPORARY TABLE describe_test (
 
     Ok(())
 }
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_reports_mismatched_value_types() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let res: Result<(i64,), _> = sqlx::query_as("SELECT 'hello'").fetch_one(&mut conn).await;
+
+    let err = res.unwrap_err().to_string();
+
+    assert!(err.contains("`i64` (as SQL type INTEGER)"), "{}", err);
+    assert!(err.contains("SQL type TEXT"), "{}", err);
+
+    let res: Result<(f64,), _> = sqlx::query_as("SELECT X'00'").fetch_one(&mut conn).await;
+
+    assert!(res.is_err());
+
+    let res: Result<(i32,), _> = sqlx::query_as("SELECT 1.5").fetch_one(&mut conn).await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_converts_value_types_unless_strict() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let (a, b, c, d): (i64, f64, String, i32) = sqlx::query_as("SELECT ' 42 ', '2.5', 10, 3.0")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!((a, b, &*c, d), (42, 2.5, "10", 3));
+
+    conn.strict_types(true);
+
+    let res: Result<(i64,), _> = sqlx::query_as("SELECT '42'").fetch_one(&mut conn).await;
+    let err = res.unwrap_err().to_string();
+
+    assert!(err.contains("strict mode"), "{}", err);
+
+    let res: Result<(String,), _> = sqlx::query_as("SELECT 10").fetch_one(&mut conn).await;
+
+    assert!(res.is_err());
+
+    // INTEGER values still decode as floats
+    let (a, b): (i64, f64) = sqlx::query_as("SELECT 42, 10").fetch_one(&mut conn).await?;
+
+    assert_eq!((a, b), (42, 10.0));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_detects_strict_tables() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    // a column without a declared type stores values as they are given
+    conn.execute("CREATE TEMPORARY TABLE loose (id INTEGER, value)")
+        .await?;
+
+    assert!(!conn.is_strict_table("loose").await?);
+    assert!(!conn.is_strict_table("missing").await?);
+
+    sqlx::query("INSERT INTO loose (id, value) VALUES (1, ?)")
+        .bind("42")
+        .execute(&mut conn)
+        .await?;
+
+    // the TEXT value is converted unless strict types are enabled, which they are not by
+    // detecting a STRICT table
+    let (value,): (i64,) = sqlx::query_as("SELECT value FROM loose")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 42);
+
+    conn.strict_types(true);
+
+    let res: Result<(i64,), _> = sqlx::query_as("SELECT value FROM loose")
+        .fetch_one(&mut conn)
+        .await;
+
+    let err = res.unwrap_err().to_string();
+
+    assert!(err.contains("strict mode"), "{}", err);
+
+    // STRICT tables need SQLite 3.37
+    let (version,): (String,) = sqlx::query_as("SELECT sqlite_version()")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let version: Vec<u32> = version
+        .split('.')
+        .map(|part| part.parse().unwrap())
+        .collect();
+
+    if version < vec![3, 37] {
+        return Ok(());
+    }
+
+    conn.execute("CREATE TEMPORARY TABLE strict_ints (id INTEGER) STRICT")
+        .await?;
+
+    assert!(conn.is_strict_table("strict_ints").await?);
+
+    let err = sqlx::query("INSERT INTO strict_ints (id) VALUES (?)")
+        .bind("not a number")
+        .execute(&mut conn)
+        .await
+        .unwrap_err();
+
+    assert!(
+        err.to_string().contains("cannot store TEXT value"),
+        "{}",
+        err
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_joins_across_attached_databases() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.attach(":memory:", "aux").await?;
+    assert_eq!(conn.attached(), ["aux"]);
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE accounts (id INTEGER PRIMARY KEY, name TEXT NOT NULL);
+CREATE TABLE aux.orders (account_id INTEGER NOT NULL, total REAL NOT NULL);
+
+INSERT INTO accounts (id, name) VALUES (1, 'Herp'), (2, 'Derp');
+INSERT INTO aux.orders (account_id, total) VALUES (1, 10.5), (2, 4.0), (1, 1.5);
+        "#,
+    )
+    .await?;
+
+    let rows: Vec<(String, f64)> = sqlx::query_as(
+        r#"
+SELECT a.name, SUM(o.total)
+FROM accounts a
+JOIN aux.orders o ON o.account_id = a.id
+GROUP BY a.name
+ORDER BY a.name
+        "#,
+    )
+    .fetch_all(&mut conn)
+    .await?;
+
+    assert_eq!(rows, [("Derp".to_owned(), 4.0), ("Herp".to_owned(), 12.0)]);
+
+    // columns of tables in attached databases are described by their declared type
+    let describe = conn
+        .describe("SELECT account_id, total FROM aux.orders")
+        .await?;
+
+    assert_eq!(
+        describe.result_columns[0]
+            .type_info
+            .as_ref()
+            .unwrap()
+            .to_string(),
+        "INTEGER"
+    );
+    assert_eq!(
+        describe.result_columns[1]
+            .type_info
+            .as_ref()
+            .unwrap()
+            .to_string(),
+        "DOUBLE"
+    );
+
+    conn.detach("aux").await?;
+    assert!(conn.attached().is_empty());
+
+    assert!(conn.execute("SELECT * FROM aux.orders").await.is_err());
+
+    // an alias that cannot be quoted is rejected before anything is attached
+    assert!(conn.attach(":memory:", "a\0b").await.is_err());
+    assert!(conn.attached().is_empty());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_executes_a_batch() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute_batch(
+        r#"
+-- a table; with a comment; containing semicolons
+CREATE TEMPORARY TABLE posts (id INTEGER PRIMARY KEY, title TEXT NOT NULL, edits INTEGER NOT NULL DEFAULT 0);
+
+/* a trigger, whose body has statements of its own */
+CREATE TEMPORARY TRIGGER posts_edited AFTER UPDATE OF title ON posts
+BEGIN
+    UPDATE posts SET edits = edits + 1 WHERE id = NEW.id;
+END;
+
+CREATE TEMPORARY VIEW edited_posts AS SELECT title FROM posts WHERE edits > 0
+        "#,
+    )
+    .await?;
+
+    conn.execute(
+        "INSERT INTO posts (title) VALUES ('a'), ('b'); UPDATE posts SET title = 'c' WHERE id = 2",
+    )
+    .await?;
+
+    let titles: Vec<(String,)> = sqlx::query_as("SELECT title FROM edited_posts")
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(titles, [("c".to_owned(),)]);
+
+    let res = conn
+        .execute_batch("INSERT INTO posts (title) VALUES ('d'); INSERT INTO posts (id) VALUES (3)")
+        .await;
+
+    match res {
+        Err(sqlx::Error::Database(err)) => {
+            assert!(err.message().contains("posts.title"), "{}", err.message());
+
+            // the error names the statement that failed and where it starts
+            assert!(
+                err.to_string()
+                    .ends_with("(in statement 2 of the batch: INSERT INTO posts (id) VALUES (3))"),
+                "{}",
+                err
+            );
+            assert_eq!(err.position(), Some(41));
+        }
+
+        res => panic!("expected a database error, got {:?}", res),
+    }
+
+    // the statements before the failing one remain executed
+    let count: i64 = conn.fetch_scalar("SELECT COUNT(*) FROM posts").await?;
+
+    assert_eq!(count, 3);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_gets_columns_by_index_and_name() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let mut cursor = conn.fetch("SELECT 1 AS id, 'alice' AS name");
+    let row = cursor.next().await?.unwrap();
+
+    let name = String::from("name");
+
+    assert_eq!(row.try_get::<String, _>(1)?, "alice");
+    assert_eq!(row.try_get::<String, _>("name")?, "alice");
+    assert_eq!(row.try_get::<String, _>(&name)?, "alice");
+    assert_eq!(row.try_get::<String, _>(name)?, "alice");
+
+    assert!(matches!(
+        row.try_get::<String, _>(2),
+        Err(sqlx::Error::ColumnIndexOutOfBounds { index: 2, len: 2 })
+    ));
+
+    assert!(matches!(
+        row.try_get::<String, _>("email"),
+        Err(sqlx::Error::ColumnNotFound(ref name)) if &**name == "email"
+    ));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_calls_a_scalar_function() -> anyhow::Result<()> {
+    use sqlx::decode::Decode;
+
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.create_scalar_function("double", 1, |args| {
+        let x = <i64 as Decode<Sqlite>>::decode(args[0])?;
+
+        Ok(x * 2)
+    })?;
+
+    let (value,): (i64,) = sqlx::query_as("SELECT double(?)")
+        .bind(21_i64)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 42);
+
+    let err = sqlx::query("SELECT double('two')")
+        .execute(&mut conn)
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("invalid digit"), "{}", err);
+
+    Ok(())
+}
+
+#[cfg(feature = "regexp")]
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_matches_a_regexp() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute_batch(
+        "CREATE TEMPORARY TABLE words (word TEXT); \
+         INSERT INTO words VALUES ('abuzz'), ('adz'), ('buzz'), ('azure'), (NULL)",
+    )
+    .await?;
+
+    let words: Vec<(String,)> =
+        sqlx::query_as("SELECT word FROM words WHERE word REGEXP '^a.*z$' ORDER BY word")
+            .fetch_all(&mut conn)
+            .await?;
+
+    assert_eq!(words, vec![("abuzz".to_owned(),), ("adz".to_owned(),)]);
+
+    let err = sqlx::query("SELECT 'a' REGEXP '('")
+        .execute(&mut conn)
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("regex parse error"), "{}", err);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn it_calls_the_update_hook() -> anyhow::Result<()> {
+    use sqlx::sqlite::SqliteOperation;
+    use std::sync::Mutex;
+
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE hooked (id INTEGER PRIMARY KEY, name TEXT)")
+        .await?;
+
+    let changes = Arc::new(Mutex::new(Vec::new()));
+
+    conn.set_update_hook({
+        let changes = changes.clone();
+
+        move |operation, database, table, rowid| {
+            changes
+                .lock()
+                .unwrap()
+                .push((operation, database.to_owned(), table.to_owned(), rowid));
+        }
+    });
+
+    sqlx::query("INSERT INTO hooked (id, name) VALUES (?, ?)")
+        .bind(7_i32)
+        .bind("seven")
+        .execute(&mut conn)
+        .await?;
+
+    conn.execute("UPDATE hooked SET name = 'SEVEN' WHERE id = 7")
+        .await?;
+    conn.execute("DELETE FROM hooked WHERE id = 7").await?;
+
+    assert_eq!(
+        *changes.lock().unwrap(),
+        vec![
+            (
+                SqliteOperation::Insert,
+                "temp".to_owned(),
+                "hooked".to_owned(),
+                7
+            ),
+            (
+                SqliteOperation::Update,
+                "temp".to_owned(),
+                "hooked".to_owned(),
+                7
+            ),
+            (
+                SqliteOperation::Delete,
+                "temp".to_owned(),
+                "hooked".to_owned(),
+                7
+            ),
+        ]
+    );
+
+    conn.clear_update_hook();
+
+    conn.execute("INSERT INTO hooked (id, name) VALUES (8, 'eight')")
+        .await?;
+
+    assert_eq!(changes.lock().unwrap().len(), 3);
+
+    // the hook is dropped once cleared
+    assert_eq!(Arc::strong_count(&changes), 1);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "runtime-async-std", async_std::test)]
+#[cfg_attr(feature = "runtime-tokio", tokio::test)]
+async fn pool_resets_connection_with_a_query_on_release() -> anyhow::Result<()> {
+    let pool = SqlitePool::builder()
+        .max_size(1)
+        .reset_query("DROP TABLE IF EXISTS temp.scratch")
+        .build("sqlite::memory:")
+        .await?;
+
+    let mut conn = pool.acquire().await?;
+
+    conn.execute("CREATE TABLE kept (id INTEGER)").await?;
+    conn.execute("CREATE TEMPORARY TABLE scratch (id INTEGER)")
+        .await?;
+
+    // leave a transaction open
+    conn.execute("BEGIN").await?;
+    conn.execute("INSERT INTO kept (id) VALUES (1)").await?;
+
+    drop(conn);
+
+    let mut conn = pool.acquire().await?;
+
+    // the same in-memory database was handed out again, with the transaction rolled back
+    // and the temporary table dropped by the reset query
+    let (kept, scratch): (i32, i32) = sqlx::query_as(
+        "SELECT (SELECT COUNT(*) FROM kept), \
+         (SELECT COUNT(*) FROM sqlite_temp_master WHERE name = 'scratch')",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!((kept, scratch), (0, 0));
+
+    // without a transaction to roll back, the reset query is all that runs
+    drop(conn);
+
+    let mut conn = pool.acquire().await?;
+
+    let (kept,): (i32,) = sqlx::query_as("SELECT COUNT(*) FROM kept")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(kept, 0);
+    assert_eq!(pool.size(), 1);
+
+    Ok(())
+}